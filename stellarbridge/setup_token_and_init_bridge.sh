@@ -102,7 +102,7 @@ echo "  soroban keys generate owner >/dev/null 2>&1 || true"
 echo "  OWNER=\$(soroban keys address owner); curl -s \"https://friendbot.stellar.org/?addr=\$OWNER\" >/dev/null || true"
 echo "  AMOUNTS='[25,15,10]'; DEADLINES='[1730851200,1731456000,1732060800]'"
 echo "  soroban contract invoke --id $CONTRACT_ID --source owner --network testnet --fn create_project \\"
-echo "    --arg address:\$OWNER --arg i128:50 --arg vec:i128:\$AMOUNTS --arg vec:u64:\$DEADLINES"
+echo "    --arg address:\$OWNER --arg address:$USDC_TOKEN --arg i128:50 --arg vec:i128:\$AMOUNTS --arg vec:u64:\$DEADLINES"
 echo
 echo "  # Invest 5 dUSD (investor signs)"
 echo "  soroban contract invoke --id $CONTRACT_ID --source investor --network testnet --fn invest \\"
@@ -0,0 +1,1140 @@
+// Admin and verifier set-up: contract-wide settings, token and category
+// policy and verifier approval
+
+#[cfg(feature = "committee")]
+use soroban_sdk::crypto::bls12_381::G1Affine;
+#[cfg(any(
+    feature = "committee",
+    feature = "evm-attestation",
+    feature = "share-token",
+    feature = "verifier-signatures"
+))]
+use soroban_sdk::BytesN;
+#[cfg(any(
+    feature = "fees",
+    feature = "listing-review",
+    feature = "verifier-pool",
+    feature = "verifier-signatures"
+))]
+use soroban_sdk::Symbol;
+use soroban_sdk::{log, Address, Env, Vec};
+
+use crate::config_history::record_config_change;
+#[cfg(any(feature = "fees", feature = "multi-token", feature = "yield-pool"))]
+use crate::errors;
+#[cfg(feature = "hooks")]
+use crate::hooks;
+use crate::storage;
+use crate::types::{
+    BlsCommittee, Config, ConfigSetting, ConfigValue, DataKey, InsurancePolicy, LatePolicy,
+    VerificationFee,
+};
+#[cfg(feature = "archive")]
+use crate::types::ArchiveKey;
+#[cfg(feature = "evm-attestation")]
+use crate::types::AttestationKey;
+#[cfg(feature = "audits")]
+use crate::types::AuditKey;
+#[cfg(feature = "co-verification")]
+use crate::types::CoVerificationKey;
+#[cfg(feature = "committee")]
+use crate::types::CommitteeKey;
+#[cfg(feature = "disputes")]
+use crate::types::DisputeKey;
+#[cfg(feature = "escalation")]
+use crate::types::EscalationKey;
+#[cfg(feature = "fees")]
+use crate::types::{FeeKey, FeeStrategy};
+#[cfg(feature = "goal-bounds")]
+use crate::types::{GoalBounds, GoalBoundsKey};
+#[cfg(feature = "hooks")]
+use crate::types::HookKey;
+#[cfg(feature = "insurance")]
+use crate::types::InsuranceKey;
+#[cfg(feature = "late-policy")]
+use crate::types::LatePolicyKey;
+#[cfg(feature = "oracle-milestones")]
+use crate::types::OracleKey;
+#[cfg(feature = "yield-pool")]
+use crate::types::PoolKey;
+#[cfg(feature = "refund-grace")]
+use crate::types::RefundGraceKey;
+#[cfg(feature = "resubmissions")]
+use crate::types::ResubmissionKey;
+#[cfg(feature = "listing-review")]
+use crate::types::ReviewKey;
+#[cfg(feature = "verifier-signatures")]
+use crate::types::SignatureKey;
+#[cfg(feature = "share-token")]
+use crate::types::ShareTokenKey;
+#[cfg(feature = "staking")]
+use crate::types::StakingKey;
+#[cfg(feature = "stats")]
+use crate::types::{ContractStats, StatsKey};
+#[cfg(feature = "treasury")]
+use crate::types::TreasuryKey;
+#[cfg(feature = "verifier-pool")]
+use crate::types::VerifierPoolKey;
+#[cfg(feature = "yield-pool")]
+use crate::types::YieldPool;
+use crate::verifiers;
+#[cfg(feature = "fees")]
+use crate::fee;
+#[cfg(any(
+    feature = "fees",
+    feature = "insurance",
+    feature = "late-policy",
+    feature = "yield-pool"
+))]
+use crate::splitter;
+use crate::DEFAULT_ARCHIVE_TTL;
+#[cfg(feature = "verifier-signatures")]
+use crate::StellarBridgeContract;
+#[cfg(feature = "verifier-pool")]
+use crate::MAX_VERIFIER_POOL;
+#[cfg(feature = "committee")]
+use crate::MAX_COMMITTEE_SIZE;
+#[cfg(feature = "hooks")]
+use crate::MAX_HOOKS;
+
+pub fn initialize(env: Env, verifier: Address, token: Address) {
+    if env.storage().instance().has(&DataKey::Verifier) {
+        panic!("Contract already initialized");
+    }
+    
+    verifier.require_auth();
+    
+    env.storage().instance().set(&DataKey::Verifier, &verifier);
+    env.storage().instance().set(&DataKey::Token, &token);
+    env.storage().instance().set(&DataKey::AllowedTokens, &Vec::from_array(&env, [token.clone()]));
+    env.storage().instance().set(&DataKey::Admin, &verifier);
+    env.storage().instance().set(&DataKey::ProjectCounter, &0u32);
+    
+    log!(&env, "Contract initialized with verifier: {}", verifier);
+}
+
+pub fn set_admin(env: Env, new_admin: Address) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    env.storage().instance().set(&DataKey::Admin, &new_admin);
+    record_config_change(
+        &env,
+        ConfigSetting::Admin,
+        ConfigValue::Address(admin.clone()),
+        ConfigValue::Address(new_admin.clone()),
+        &admin,
+    );
+    
+    log!(&env, "Admin changed to {}", new_admin);
+}
+
+#[cfg(feature = "share-token")]
+pub fn set_share_token_wasm(env: Env, wasm_hash: BytesN<32>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<BytesN<32>> = env.storage().instance().get(&ShareTokenKey::ShareTokenWasm);
+    env.storage().instance().set(&ShareTokenKey::ShareTokenWasm, &wasm_hash);
+    record_config_change(
+        &env,
+        ConfigSetting::ShareTokenWasm,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Hash),
+        ConfigValue::Hash(wasm_hash),
+        &admin,
+    );
+    
+    log!(&env, "Share token wasm updated");
+}
+
+#[cfg(feature = "multi-token")]
+pub fn add_allowed_token(env: Env, token: Address) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let mut allowed: Vec<Address> = env.storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(&env));
+    
+    if allowed.contains(&token) {
+        panic!("Token already allowed");
+    }
+    
+    allowed.push_back(token.clone());
+    env.storage().instance().set(&DataKey::AllowedTokens, &allowed);
+    record_config_change(
+        &env,
+        ConfigSetting::AllowedToken(token.clone()),
+        ConfigValue::Flag(false),
+        ConfigValue::Flag(true),
+        &admin,
+    );
+    
+    log!(&env, "Token {} allowed", token);
+}
+
+#[cfg(feature = "multi-token")]
+pub fn remove_allowed_token(env: Env, token: Address) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let mut allowed: Vec<Address> = env.storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(&env));
+    
+    let index = allowed.first_index_of(&token).expect(errors::TOKEN_NOT_ALLOWED);
+    allowed.remove(index);
+    env.storage().instance().set(&DataKey::AllowedTokens, &allowed);
+    record_config_change(
+        &env,
+        ConfigSetting::AllowedToken(token.clone()),
+        ConfigValue::Flag(true),
+        ConfigValue::Flag(false),
+        &admin,
+    );
+    
+    log!(&env, "Token {} removed from allowlist", token);
+}
+
+#[cfg(feature = "goal-bounds")]
+pub fn set_goal_bounds(env: Env, token: Address, min: Option<i128>, max: Option<i128>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if min.is_some_and(|min| min <= 0) || max.is_some_and(|max| max <= 0) {
+        panic!("Goal bounds must be positive");
+    }
+    
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            panic!("Minimum goal exceeds maximum goal");
+        }
+    }
+    
+    let key = GoalBoundsKey::GoalBounds(token.clone());
+    let previous: Option<GoalBounds> = storage::get(&env, &key);
+    let bounds = if min.is_none() && max.is_none() {
+        storage::remove(&env, &key);
+        None
+    } else {
+        let bounds = GoalBounds { min, max };
+        storage::set(&env, &key, &bounds);
+        Some(bounds)
+    };
+    record_config_change(
+        &env,
+        ConfigSetting::GoalBounds(token.clone()),
+        previous.map_or(ConfigValue::Unset, ConfigValue::GoalBounds),
+        bounds.map_or(ConfigValue::Unset, ConfigValue::GoalBounds),
+        &admin,
+    );
+    
+    log!(&env, "Goal bounds updated for token {}", token);
+}
+
+#[cfg(feature = "listing-review")]
+pub fn set_category_review(env: Env, category: Symbol, required: bool) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let key = ReviewKey::CategoryReview(category.clone());
+    let previous = storage::has(&env, &key);
+    if required {
+        storage::set(&env, &key, &true);
+    } else {
+        storage::remove(&env, &key);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::CategoryReview(category.clone()),
+        ConfigValue::Flag(previous),
+        ConfigValue::Flag(required),
+        &admin,
+    );
+    
+    log!(&env, "Listing review for category {} set to {}", category, required);
+}
+
+#[cfg(feature = "resubmissions")]
+pub fn set_max_resubmissions(env: Env, max_resubmissions: u32) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: u32 = env.storage()
+        .instance()
+        .get(&ResubmissionKey::MaxResubmissions)
+        .unwrap_or(0);
+    env.storage().instance().set(&ResubmissionKey::MaxResubmissions, &max_resubmissions);
+    record_config_change(
+        &env,
+        ConfigSetting::MaxResubmissions,
+        ConfigValue::Count(previous),
+        ConfigValue::Count(max_resubmissions),
+        &admin,
+    );
+    
+    log!(&env, "Max resubmissions set to {}", max_resubmissions);
+}
+
+#[cfg(feature = "escalation")]
+pub fn set_verification_window(env: Env, window: Option<u64>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<u64> = env.storage().instance().get(&EscalationKey::VerificationWindow);
+    match window {
+        Some(0) => panic!("Verification window must be positive"),
+        Some(window) => env.storage().instance().set(&EscalationKey::VerificationWindow, &window),
+        None => env.storage().instance().remove(&EscalationKey::VerificationWindow),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::VerificationWindow,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        window.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        &admin,
+    );
+    
+    log!(&env, "Verification window set to {} seconds", window.unwrap_or(0));
+}
+
+#[cfg(feature = "staking")]
+pub fn set_required_stake(env: Env, amount: i128) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if amount < 0 {
+        panic!("Required stake cannot be negative");
+    }
+    
+    let previous: i128 = env.storage().instance().get(&StakingKey::RequiredStake).unwrap_or(0);
+    env.storage().instance().set(&StakingKey::RequiredStake, &amount);
+    record_config_change(
+        &env,
+        ConfigSetting::RequiredStake,
+        ConfigValue::Amount(previous),
+        ConfigValue::Amount(amount),
+        &admin,
+    );
+    
+    log!(&env, "Required verifier stake set to {}", amount);
+}
+
+#[cfg(feature = "treasury")]
+pub fn set_treasury(env: Env, treasury: Address) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<Address> = env.storage().instance().get(&TreasuryKey::Treasury);
+    env.storage().instance().set(&TreasuryKey::Treasury, &treasury);
+    record_config_change(
+        &env,
+        ConfigSetting::Treasury,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Address),
+        ConfigValue::Address(treasury.clone()),
+        &admin,
+    );
+    
+    log!(&env, "Treasury set to {}", treasury);
+}
+
+#[cfg(feature = "disputes")]
+pub fn set_arbiter(env: Env, arbiter: Address) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<Address> = env.storage().instance().get(&DisputeKey::Arbiter);
+    env.storage().instance().set(&DisputeKey::Arbiter, &arbiter);
+    record_config_change(
+        &env,
+        ConfigSetting::Arbiter,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Address),
+        ConfigValue::Address(arbiter.clone()),
+        &admin,
+    );
+    
+    log!(&env, "Arbiter set to {}", arbiter);
+}
+
+#[cfg(feature = "disputes")]
+pub fn set_dispute_window(env: Env, window: Option<u64>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<u64> = env.storage().instance().get(&DisputeKey::DisputeWindow);
+    match window {
+        Some(0) => panic!("Dispute window must be positive"),
+        Some(window) => env.storage().instance().set(&DisputeKey::DisputeWindow, &window),
+        None => env.storage().instance().remove(&DisputeKey::DisputeWindow),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::DisputeWindow,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        window.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        &admin,
+    );
+    
+    log!(&env, "Dispute window set to {} seconds", window.unwrap_or(0));
+}
+
+#[cfg(feature = "disputes")]
+pub fn set_appeal_window(env: Env, window: Option<u64>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<u64> = env.storage().instance().get(&DisputeKey::AppealWindow);
+    match window {
+        Some(0) => panic!("Appeal window must be positive"),
+        Some(window) => env.storage().instance().set(&DisputeKey::AppealWindow, &window),
+        None => env.storage().instance().remove(&DisputeKey::AppealWindow),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::AppealWindow,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        window.map_or(ConfigValue::Unset, ConfigValue::Seconds),
+        &admin,
+    );
+    
+    log!(&env, "Appeal window set to {} seconds", window.unwrap_or(0));
+}
+
+#[cfg(feature = "co-verification")]
+pub fn set_co_verification_threshold(env: Env, threshold: Option<i128>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<i128> = env.storage().instance().get(&CoVerificationKey::CoVerificationThreshold);
+    match threshold {
+        Some(threshold) if threshold <= 0 => panic!("Co-verification threshold must be positive"),
+        Some(threshold) => env.storage().instance().set(&CoVerificationKey::CoVerificationThreshold, &threshold),
+        None => env.storage().instance().remove(&CoVerificationKey::CoVerificationThreshold),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::CoVerificationThreshold,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Amount),
+        threshold.map_or(ConfigValue::Unset, ConfigValue::Amount),
+        &admin,
+    );
+    
+    log!(&env, "Co-verification threshold set to {}", threshold.unwrap_or(0));
+}
+
+#[cfg(feature = "escalation")]
+pub fn set_auto_approval(env: Env, threshold: Option<i128>, challenge_window: u64) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if threshold.is_some_and(|threshold| threshold <= 0) {
+        panic!("Auto-approval threshold must be positive");
+    }
+    
+    let previous: Option<i128> = env.storage().instance().get(&EscalationKey::AutoApproveThreshold);
+    let previous_window: u64 = env.storage()
+        .instance()
+        .get(&EscalationKey::AutoApproveWindow)
+        .unwrap_or(0);
+    match threshold {
+        Some(threshold) => env.storage().instance().set(&EscalationKey::AutoApproveThreshold, &threshold),
+        None => env.storage().instance().remove(&EscalationKey::AutoApproveThreshold),
+    }
+    env.storage().instance().set(&EscalationKey::AutoApproveWindow, &challenge_window);
+    record_config_change(
+        &env,
+        ConfigSetting::AutoApproveThreshold,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Amount),
+        threshold.map_or(ConfigValue::Unset, ConfigValue::Amount),
+        &admin,
+    );
+    record_config_change(
+        &env,
+        ConfigSetting::AutoApproveWindow,
+        ConfigValue::Seconds(previous_window),
+        ConfigValue::Seconds(challenge_window),
+        &admin,
+    );
+    
+    log!(&env, "Auto-approval below {} after {} seconds", threshold.unwrap_or(0), challenge_window);
+}
+
+#[cfg(feature = "insurance")]
+pub fn set_insurance_policy(env: Env, premium_bps: u32, coverage_bps: u32, max_coverage: i128) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if premium_bps > splitter::BASIS_POINTS {
+        panic!("Fee cannot exceed 10000 basis points");
+    }
+    
+    if coverage_bps > splitter::BASIS_POINTS {
+        panic!("Coverage cannot exceed 10000 basis points");
+    }
+    
+    if max_coverage < 0 {
+        panic!("Coverage cap must not be negative");
+    }
+    
+    let policy = InsurancePolicy { premium_bps, coverage_bps, max_coverage };
+    let previous: InsurancePolicy = env.storage()
+        .instance()
+        .get(&InsuranceKey::InsurancePolicy)
+        .unwrap_or(InsurancePolicy { premium_bps: 0, coverage_bps: 0, max_coverage: 0 });
+    env.storage().instance().set(&InsuranceKey::InsurancePolicy, &policy);
+    record_config_change(
+        &env,
+        ConfigSetting::InsurancePolicy,
+        ConfigValue::Insurance(previous),
+        ConfigValue::Insurance(policy),
+        &admin,
+    );
+    
+    log!(&env, "Insurance set to a {} bps premium covering {} bps", premium_bps, coverage_bps);
+}
+
+#[cfg(feature = "fees")]
+pub fn set_verification_fee(env: Env, fee: VerificationFee) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    match fee {
+        VerificationFee::Flat(amount) if amount < 0 => panic!("Fee cannot be negative"),
+        VerificationFee::BasisPoints(bps) if bps > splitter::BASIS_POINTS => {
+            panic!("Fee cannot exceed 10000 basis points")
+        }
+        _ => {}
+    }
+    
+    let previous: VerificationFee = env.storage()
+        .instance()
+        .get(&FeeKey::VerificationFee)
+        .unwrap_or(VerificationFee::Free);
+    env.storage().instance().set(&FeeKey::VerificationFee, &fee);
+    record_config_change(
+        &env,
+        ConfigSetting::VerificationFee,
+        ConfigValue::Fee(previous),
+        ConfigValue::Fee(fee),
+        &admin,
+    );
+    
+    log!(&env, "Verification fee updated");
+}
+
+#[cfg(feature = "fees")]
+pub fn set_category_fee(env: Env, category: Symbol, strategy: FeeStrategy) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    fee::validate(&strategy);
+    if strategy != FeeStrategy::Free && !env.storage().instance().has(&TreasuryKey::Treasury) {
+        panic!("{}", errors::TREASURY_NOT_SET);
+    }
+    
+    let key = FeeKey::CategoryFee(category.clone());
+    let previous: FeeStrategy = storage::get(&env, &key).unwrap_or(FeeStrategy::Free);
+    if strategy == FeeStrategy::Free {
+        storage::remove(&env, &key);
+    } else {
+        storage::set(&env, &key, &strategy);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::CategoryFee(category.clone()),
+        ConfigValue::FeeStrategy(previous),
+        ConfigValue::FeeStrategy(strategy),
+        &admin,
+    );
+    
+    log!(&env, "Platform fee for category {} updated", category);
+}
+
+#[cfg(feature = "late-policy")]
+pub fn set_late_policy(env: Env, grace: u64, max_fee_bps: u32) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if max_fee_bps > splitter::BASIS_POINTS {
+        panic!("Fee cannot exceed 10000 basis points");
+    }
+    
+    let policy = LatePolicy { grace, max_fee_bps };
+    let previous: LatePolicy = env.storage()
+        .instance()
+        .get(&LatePolicyKey::LatePolicy)
+        .unwrap_or(LatePolicy { grace: 0, max_fee_bps: 0 });
+    env.storage().instance().set(&LatePolicyKey::LatePolicy, &policy);
+    record_config_change(
+        &env,
+        ConfigSetting::LatePolicy,
+        ConfigValue::LatePolicy(previous),
+        ConfigValue::LatePolicy(policy),
+        &admin,
+    );
+    
+    log!(&env, "Late policy set to {} seconds grace, up to {} bps", grace, max_fee_bps);
+}
+
+#[cfg(feature = "verifier-pool")]
+pub fn set_verifier_approval(env: Env, verifier: Address, approved: bool) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let key = VerifierPoolKey::ApprovedVerifier(verifier.clone());
+    let previous = storage::has(&env, &key);
+    if approved {
+        storage::set(&env, &key, &true);
+    } else {
+        storage::remove(&env, &key);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::ApprovedVerifier(verifier.clone()),
+        ConfigValue::Flag(previous),
+        ConfigValue::Flag(approved),
+        &admin,
+    );
+    
+    log!(&env, "Verifier {} approval set to {}", verifier, approved);
+}
+
+#[cfg(feature = "verifier-pool")]
+pub fn set_verifier_registry(env: Env, registry: Option<Address>, domain: Symbol) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<Address> = env.storage().instance().get(&VerifierPoolKey::VerifierRegistry);
+    let previous_domain: Option<Symbol> = env.storage().instance().get(&VerifierPoolKey::VerifierDomain);
+    let domain = registry.as_ref().map(|_| domain);
+    match (&registry, &domain) {
+        (Some(registry), Some(domain)) => {
+            env.storage().instance().set(&VerifierPoolKey::VerifierRegistry, registry);
+            env.storage().instance().set(&VerifierPoolKey::VerifierDomain, domain);
+        }
+        _ => {
+            env.storage().instance().remove(&VerifierPoolKey::VerifierRegistry);
+            env.storage().instance().remove(&VerifierPoolKey::VerifierDomain);
+        }
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::VerifierRegistry,
+        previous.map_or(ConfigValue::Unset, ConfigValue::Address),
+        registry.clone().map_or(ConfigValue::Unset, ConfigValue::Address),
+        &admin,
+    );
+    record_config_change(
+        &env,
+        ConfigSetting::VerifierDomain,
+        previous_domain.map_or(ConfigValue::Unset, ConfigValue::Symbol),
+        domain.map_or(ConfigValue::Unset, ConfigValue::Symbol),
+        &admin,
+    );
+    
+    log!(&env, "Verifier registry updated");
+}
+
+#[cfg(feature = "verifier-pool")]
+pub fn set_verifier_pool(env: Env, verifiers: Vec<Address>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if verifiers.len() > MAX_VERIFIER_POOL {
+        panic!("Too many verifiers in the pool");
+    }
+    
+    for verifier in verifiers.iter() {
+        if !verifiers::is_approved_verifier(&env, &verifier) {
+            panic!("Verifier not approved");
+        }
+    }
+    
+    let previous: Vec<Address> = env.storage()
+        .instance()
+        .get(&VerifierPoolKey::VerifierPool)
+        .unwrap_or(Vec::new(&env));
+    if verifiers.is_empty() {
+        env.storage().instance().remove(&VerifierPoolKey::VerifierPool);
+    } else {
+        env.storage().instance().set(&VerifierPoolKey::VerifierPool, &verifiers);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::VerifierPool,
+        ConfigValue::Addresses(previous),
+        ConfigValue::Addresses(verifiers.clone()),
+        &admin,
+    );
+    
+    log!(&env, "Verifier pool set to {} verifiers", verifiers.len());
+}
+
+#[cfg(feature = "evm-attestation")]
+pub fn set_evm_attester(env: Env, public_key: Option<BytesN<65>>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: Option<BytesN<65>> = env.storage().instance().get(&AttestationKey::EvmAttester);
+    match &public_key {
+        Some(public_key) => env.storage().instance().set(&AttestationKey::EvmAttester, public_key),
+        None => env.storage().instance().remove(&AttestationKey::EvmAttester),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::EvmAttester,
+        previous.map_or(ConfigValue::Unset, ConfigValue::PublicKey),
+        public_key.map_or(ConfigValue::Unset, ConfigValue::PublicKey),
+        &admin,
+    );
+    
+    log!(&env, "EVM attester updated");
+}
+
+#[cfg(feature = "committee")]
+pub fn set_bls_committee(env: Env, keys: Vec<BytesN<96>>, threshold: u32) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if keys.len() > MAX_COMMITTEE_SIZE {
+        panic!("Committee too large");
+    }
+    
+    if !keys.is_empty() && (threshold == 0 || threshold > keys.len()) {
+        panic!("Invalid committee threshold");
+    }
+    
+    let bls = env.crypto().bls12_381();
+    for key in keys.iter() {
+        if !bls.g1_is_in_subgroup(&G1Affine::from_bytes(key)) {
+            panic!("Invalid committee key");
+        }
+    }
+    
+    let previous = committee(&env);
+    let committee = BlsCommittee { keys, threshold };
+    if committee.keys.is_empty() {
+        env.storage().instance().remove(&CommitteeKey::BlsCommittee);
+    } else {
+        env.storage().instance().set(&CommitteeKey::BlsCommittee, &committee);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::BlsCommittee,
+        ConfigValue::Committee(previous),
+        ConfigValue::Committee(committee.clone()),
+        &admin,
+    );
+    
+    log!(&env, "BLS committee set to {} members, threshold {}", committee.keys.len(), committee.threshold);
+}
+
+#[cfg(feature = "committee")]
+pub fn committee(env: &Env) -> BlsCommittee {
+    env.storage()
+        .instance()
+        .get(&CommitteeKey::BlsCommittee)
+        .unwrap_or(BlsCommittee { keys: Vec::new(env), threshold: 0 })
+}
+
+#[cfg(feature = "hooks")]
+pub fn set_hooks(env: Env, hooks: Vec<Address>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if hooks.len() > MAX_HOOKS {
+        panic!("Too many hooks");
+    }
+    
+    let previous = hooks::hooks(&env);
+    if hooks.is_empty() {
+        env.storage().instance().remove(&HookKey::Hooks);
+    } else {
+        env.storage().instance().set(&HookKey::Hooks, &hooks);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::Hooks,
+        ConfigValue::Addresses(previous),
+        ConfigValue::Addresses(hooks.clone()),
+        &admin,
+    );
+    
+    log!(&env, "Observer hooks set to {} contracts", hooks.len());
+}
+
+#[cfg(feature = "audits")]
+pub fn set_auditor_approval(env: Env, auditor: Address, approved: bool) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let key = AuditKey::Auditor(auditor.clone());
+    let previous = storage::has(&env, &key);
+    if approved {
+        storage::set(&env, &key, &true);
+    } else {
+        storage::remove(&env, &key);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::ApprovedAuditor(auditor.clone()),
+        ConfigValue::Flag(previous),
+        ConfigValue::Flag(approved),
+        &admin,
+    );
+    
+    log!(&env, "Auditor {} approval set to {}", auditor, approved);
+}
+
+#[cfg(feature = "oracle-milestones")]
+pub fn set_oracle_approval(env: Env, oracle: Address, approved: bool) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let key = OracleKey::Oracle(oracle.clone());
+    let previous = storage::has(&env, &key);
+    if approved {
+        storage::set(&env, &key, &true);
+    } else {
+        storage::remove(&env, &key);
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::ApprovedOracle(oracle.clone()),
+        ConfigValue::Flag(previous),
+        ConfigValue::Flag(approved),
+        &admin,
+    );
+    
+    log!(&env, "Oracle {} approval set to {}", oracle, approved);
+}
+
+#[cfg(feature = "archive")]
+pub fn set_archive_ttl(env: Env, ledgers: u32) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if ledgers == 0 {
+        panic!("Archive TTL must be positive");
+    }
+    
+    let previous: u32 = env.storage()
+        .instance()
+        .get(&ArchiveKey::ArchiveTtl)
+        .unwrap_or(DEFAULT_ARCHIVE_TTL);
+    env.storage().instance().set(&ArchiveKey::ArchiveTtl, &ledgers);
+    record_config_change(
+        &env,
+        ConfigSetting::ArchiveTtl,
+        ConfigValue::Count(previous),
+        ConfigValue::Count(ledgers),
+        &admin,
+    );
+    
+    log!(&env, "Archive TTL set to {} ledgers", ledgers);
+}
+
+#[cfg(feature = "refund-grace")]
+pub fn set_refund_grace(env: Env, grace: u64) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let previous: u64 = env.storage()
+        .instance()
+        .get(&RefundGraceKey::RefundGrace)
+        .unwrap_or(0);
+    env.storage().instance().set(&RefundGraceKey::RefundGrace, &grace);
+    record_config_change(
+        &env,
+        ConfigSetting::RefundGrace,
+        ConfigValue::Seconds(previous),
+        ConfigValue::Seconds(grace),
+        &admin,
+    );
+    
+    log!(&env, "Refund grace set to {} seconds", grace);
+}
+
+#[cfg(feature = "yield-pool")]
+pub fn set_yield_pool(env: Env, token: Address, pool: Option<YieldPool>) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    if let Some(pool) = &pool {
+        if pool.investor_bps > splitter::BASIS_POINTS {
+            panic!("Investor share cannot exceed 10000 basis points");
+        }
+        if pool.investor_bps < splitter::BASIS_POINTS && !env.storage().instance().has(&TreasuryKey::Treasury) {
+            panic!("{}", errors::TREASURY_NOT_SET);
+        }
+    }
+    
+    let key = PoolKey::YieldPool(token.clone());
+    let previous: Option<YieldPool> = storage::get(&env, &key);
+    match &pool {
+        Some(pool) => storage::set(&env, &key, pool),
+        None => storage::remove(&env, &key),
+    }
+    record_config_change(
+        &env,
+        ConfigSetting::YieldPool(token.clone()),
+        previous.map_or(ConfigValue::Unset, ConfigValue::YieldPool),
+        pool.map_or(ConfigValue::Unset, ConfigValue::YieldPool),
+        &admin,
+    );
+    
+    log!(&env, "Lending pool updated for token {}", token);
+}
+
+#[cfg(feature = "verifier-signatures")]
+pub fn set_verifier_key(env: Env, verifier: Address, public_key: Option<BytesN<32>>) {
+    verifier.require_auth();
+    
+    if !StellarBridgeContract::is_verifier(env.clone(), verifier.clone()) {
+        panic!("Not a verifier");
+    }
+    
+    let key = SignatureKey::VerifierKey(verifier.clone());
+    match &public_key {
+        Some(public_key) => storage::set(&env, &key, public_key),
+        None => storage::remove(&env, &key),
+    }
+    
+    env.events().publish(
+        (Symbol::new(&env, "verifier_key_set"), verifier.clone()),
+        public_key,
+    );
+}
+
+#[cfg(feature = "fees")]
+pub fn get_category_fee(env: Env, category: Symbol) -> FeeStrategy {
+    env.storage()
+        .instance()
+        .get(&FeeKey::CategoryFee(category))
+        .unwrap_or(FeeStrategy::Free)
+}
+
+#[cfg(feature = "goal-bounds")]
+pub fn get_goal_bounds(env: Env, token: Address) -> Option<GoalBounds> {
+    env.storage()
+        .instance()
+        .get(&GoalBoundsKey::GoalBounds(token))
+}
+
+#[cfg(feature = "treasury")]
+pub fn get_treasury(env: Env) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&TreasuryKey::Treasury)
+}
+
+pub fn is_verifier(env: Env, verifier: Address) -> bool {
+    let global: Option<Address> = env.storage().instance().get(&DataKey::Verifier);
+    global == Some(verifier.clone()) || verifiers::is_approved_verifier(&env, &verifier)
+}
+
+#[cfg(feature = "verifier-signatures")]
+pub fn get_verifier_key(env: Env, verifier: Address) -> Option<BytesN<32>> {
+    storage::get(&env, &SignatureKey::VerifierKey(verifier))
+}
+
+#[cfg(feature = "verifier-signatures")]
+pub fn get_verifier_nonce(env: Env, verifier: Address) -> u64 {
+    storage::get(&env, &SignatureKey::VerifierNonce(verifier)).unwrap_or(0)
+}
+
+#[cfg(feature = "evm-attestation")]
+pub fn get_evm_attester_nonce(env: Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&AttestationKey::EvmAttesterNonce)
+        .unwrap_or(0)
+}
+
+#[cfg(feature = "audits")]
+pub fn is_auditor(env: Env, auditor: Address) -> bool {
+    storage::has(&env, &AuditKey::Auditor(auditor))
+}
+
+pub fn is_token_allowed(env: Env, token: Address) -> bool {
+    let allowed: Vec<Address> = env.storage()
+        .instance()
+        .get(&DataKey::AllowedTokens)
+        .unwrap_or(Vec::new(&env));
+    allowed.contains(&token)
+}
+
+#[cfg(feature = "staking")]
+pub fn get_required_stake(env: Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&StakingKey::RequiredStake)
+        .unwrap_or(0)
+}
+
+pub fn get_config(env: Env) -> Config {
+    let instance = env.storage().instance();
+    // Settings of the subsystems a build leaves out read as never configured
+    let config = Config {
+        admin: storage::admin(&env),
+        verifier: storage::verifier(&env),
+        token: storage::token(&env),
+        allowed_tokens: instance.get(&DataKey::AllowedTokens).unwrap_or(Vec::new(&env)),
+        share_token_wasm: None,
+        max_resubmissions: 0,
+        verification_window: None,
+        required_stake: 0,
+        treasury: None,
+        verification_fee: VerificationFee::Free,
+        late_policy: LatePolicy { grace: 0, max_fee_bps: 0 },
+        archive_ttl: DEFAULT_ARCHIVE_TTL,
+        refund_grace: 0,
+        arbiter: None,
+        dispute_window: None,
+        appeal_window: None,
+        insurance: InsurancePolicy { premium_bps: 0, coverage_bps: 0, max_coverage: 0 },
+        co_verification_threshold: None,
+        auto_approve_threshold: None,
+        auto_approve_window: 0,
+        verifier_registry: None,
+        verifier_domain: None,
+        verifier_pool: Vec::new(&env),
+        evm_attester: None,
+        bls_committee: BlsCommittee { keys: Vec::new(&env), threshold: 0 },
+        hooks: Vec::new(&env),
+    };
+    #[cfg(feature = "archive")]
+    let config = Config {
+        archive_ttl: instance.get(&ArchiveKey::ArchiveTtl).unwrap_or(DEFAULT_ARCHIVE_TTL),
+        ..config
+    };
+    #[cfg(feature = "co-verification")]
+    let config = Config {
+        co_verification_threshold: instance.get(&CoVerificationKey::CoVerificationThreshold),
+        ..config
+    };
+    #[cfg(feature = "committee")]
+    let config = Config {
+        bls_committee: instance.get(&CommitteeKey::BlsCommittee).unwrap_or(config.bls_committee),
+        ..config
+    };
+    #[cfg(feature = "disputes")]
+    let config = Config {
+        arbiter: instance.get(&DisputeKey::Arbiter),
+        dispute_window: instance.get(&DisputeKey::DisputeWindow),
+        appeal_window: instance.get(&DisputeKey::AppealWindow),
+        ..config
+    };
+    #[cfg(feature = "escalation")]
+    let config = Config {
+        verification_window: instance.get(&EscalationKey::VerificationWindow),
+        auto_approve_threshold: instance.get(&EscalationKey::AutoApproveThreshold),
+        auto_approve_window: instance.get(&EscalationKey::AutoApproveWindow).unwrap_or(0),
+        ..config
+    };
+    #[cfg(feature = "evm-attestation")]
+    let config = Config {
+        evm_attester: instance.get(&AttestationKey::EvmAttester),
+        ..config
+    };
+    #[cfg(feature = "fees")]
+    let config = Config {
+        verification_fee: instance.get(&FeeKey::VerificationFee).unwrap_or(VerificationFee::Free),
+        ..config
+    };
+    #[cfg(feature = "hooks")]
+    let config = Config {
+        hooks: instance.get(&HookKey::Hooks).unwrap_or(config.hooks),
+        ..config
+    };
+    #[cfg(feature = "insurance")]
+    let config = Config {
+        insurance: instance.get(&InsuranceKey::InsurancePolicy).unwrap_or(config.insurance),
+        ..config
+    };
+    #[cfg(feature = "late-policy")]
+    let config = Config {
+        late_policy: instance.get(&LatePolicyKey::LatePolicy).unwrap_or(config.late_policy),
+        ..config
+    };
+    #[cfg(feature = "refund-grace")]
+    let config = Config {
+        refund_grace: instance.get(&RefundGraceKey::RefundGrace).unwrap_or(0),
+        ..config
+    };
+    #[cfg(feature = "resubmissions")]
+    let config = Config {
+        max_resubmissions: instance.get(&ResubmissionKey::MaxResubmissions).unwrap_or(0),
+        ..config
+    };
+    #[cfg(feature = "share-token")]
+    let config = Config {
+        share_token_wasm: instance.get(&ShareTokenKey::ShareTokenWasm),
+        ..config
+    };
+    #[cfg(feature = "staking")]
+    let config = Config {
+        required_stake: instance.get(&StakingKey::RequiredStake).unwrap_or(0),
+        ..config
+    };
+    #[cfg(feature = "treasury")]
+    let config = Config {
+        treasury: instance.get(&TreasuryKey::Treasury),
+        ..config
+    };
+    #[cfg(feature = "verifier-pool")]
+    let config = Config {
+        verifier_registry: instance.get(&VerifierPoolKey::VerifierRegistry),
+        verifier_domain: instance.get(&VerifierPoolKey::VerifierDomain),
+        verifier_pool: instance.get(&VerifierPoolKey::VerifierPool).unwrap_or(config.verifier_pool),
+        ..config
+    };
+    config
+}
+
+#[cfg(feature = "stats")]
+pub fn get_stats(env: Env) -> ContractStats {
+    env.storage()
+        .instance()
+        .get(&StatsKey::Stats)
+        .unwrap_or(ContractStats {
+            value_locked: 0,
+            total_raised: 0,
+            total_released: 0,
+            total_refunded: 0,
+            active_projects: 0,
+            investors: 0,
+        })
+}
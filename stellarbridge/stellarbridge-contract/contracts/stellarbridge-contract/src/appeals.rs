@@ -0,0 +1,155 @@
+// Owner appeals against final rejections. With an appeal window
+// configured, a rejection that leaves a milestone no resubmissions is held
+// open that long before its funds settle; meanwhile the owner can appeal
+// once with new evidence, and the arbiter either restores the milestone to
+// review on that evidence or fails it for good. A rejection left unappealed
+// is settled by anyone once the window has passed
+
+use soroban_sdk::{log, BytesN, Env, Symbol};
+
+use crate::errors::{self, ContractError};
+use crate::milestones::{fail_milestone, transition, update_status};
+use crate::pool;
+use crate::storage;
+use crate::types::{Appeal, DisputeKey, Milestone, MilestoneStatus, Project};
+
+fn appealable(project: &Project, milestone_index: u32) -> (Milestone, u64) {
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let milestone = project.milestones.get(milestone_index).unwrap();
+    match milestone.appealable_until {
+        Some(appealable_until) => (milestone, appealable_until),
+        None => panic!("Milestone rejection is not open to appeal"),
+    }
+}
+
+// Hold a rejection that leaves the milestone no resubmissions open for the
+// appeal window, if one is configured and the milestone has not been
+// appealed already
+pub fn hold_rejection(env: &Env, project: &Project, milestone_index: u32, milestone: &mut Milestone) {
+    let window: Option<u64> = env.storage().instance().get(&DisputeKey::AppealWindow);
+    let Some(window) = window else {
+        return;
+    };
+    
+    let appealed = storage::has(env, &DisputeKey::Appeal(project.id, milestone_index));
+    if milestone.resubmissions >= project.max_resubmissions && !appealed {
+        milestone.appealable_until = Some(env.ledger().timestamp().saturating_add(window));
+    }
+}
+
+// Settle the held rejection and bring the project's status up to date
+fn fail(env: &Env, mut project: Project, milestone_index: u32, mut milestone: Milestone) -> Result<(), ContractError> {
+    pool::withdraw(env, &mut project)?;
+    
+    milestone.appealable_until = None;
+    fail_milestone(env, &mut project, milestone_index, &mut milestone)?;
+    project.milestones.set(milestone_index, milestone);
+    
+    update_status(env, &mut project)?;
+    storage::set_project(env, &project);
+    pool::deposit_idle(env, &project)?;
+    
+    Ok(())
+}
+
+pub fn appeal_rejection(env: Env, project_id: u32, milestone_index: u32, appeal_evidence_hash: BytesN<32>) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    let (_, appealable_until) = appealable(&project, milestone_index);
+    
+    if env.ledger().timestamp() >= appealable_until {
+        panic!("Appeal window has closed");
+    }
+    
+    let appeal_key = DisputeKey::Appeal(project_id, milestone_index);
+    if storage::has(&env, &appeal_key) {
+        panic!("Milestone has already been appealed");
+    }
+    
+    storage::set(&env, &appeal_key, &Appeal {
+        evidence_hash: appeal_evidence_hash.clone(),
+        filed_at: env.ledger().timestamp(),
+        upheld: None,
+    });
+    storage::extend_instance(&env);
+    
+    env.events().publish(
+        (Symbol::new(&env, "milestone_appealed"), project_id),
+        (milestone_index, appeal_evidence_hash),
+    );
+}
+
+pub fn resolve_appeal(env: Env, project_id: u32, milestone_index: u32, upheld: bool) -> Result<(), ContractError> {
+    let arbiter = storage::arbiter(&env);
+    
+    arbiter.require_auth();
+    
+    let appeal_key = DisputeKey::Appeal(project_id, milestone_index);
+    let appeal: Option<Appeal> = storage::get(&env, &appeal_key);
+    let mut appeal = appeal
+        .filter(|appeal| appeal.upheld.is_none())
+        .expect("No open appeal");
+    
+    let mut project = storage::project(&env, project_id);
+    let (mut milestone, _) = appealable(&project, milestone_index);
+    
+    if upheld {
+        // Back to review on the appeal's evidence, with the version bump
+        // pinning the verifier's next decision to it
+        milestone.appealable_until = None;
+        milestone.evidence_hash = Some(appeal.evidence_hash.clone());
+        transition(&mut project, &mut milestone, MilestoneStatus::EvidenceSubmitted);
+        milestone.submitted_at = Some(env.ledger().timestamp());
+        project.milestones.set(milestone_index, milestone);
+        storage::set_project(&env, &project);
+    } else {
+        fail(&env, project, milestone_index, milestone)?;
+    }
+    
+    appeal.upheld = Some(upheld);
+    storage::set(&env, &appeal_key, &appeal);
+    
+    env.events().publish(
+        (Symbol::new(&env, "appeal_resolved"), project_id),
+        (milestone_index, upheld),
+    );
+    
+    log!(&env, "Appeal on milestone {} of project {} resolved, upheld: {}", milestone_index, project_id, upheld);
+    
+    Ok(())
+}
+
+pub fn finalize_rejection(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    let (milestone, appealable_until) = appealable(&project, milestone_index);
+    
+    if env.ledger().timestamp() < appealable_until {
+        panic!("Appeal window still open");
+    }
+    
+    if storage::has(&env, &DisputeKey::Appeal(project_id, milestone_index)) {
+        panic!("Milestone is under appeal");
+    }
+    
+    fail(&env, project, milestone_index, milestone)?;
+    
+    env.events().publish(
+        (Symbol::new(&env, "rejection_finalized"), project_id),
+        milestone_index,
+    );
+    
+    Ok(())
+}
+
+pub fn get_appeal(env: Env, project_id: u32, milestone_index: u32) -> Option<Appeal> {
+    storage::get(&env, &DisputeKey::Appeal(project_id, milestone_index))
+}
@@ -0,0 +1,163 @@
+// Archival of finished projects. The admin can archive a project once it is
+// finished, dropping its investment, decision and update histories from
+// storage and keeping a summary with a Merkle root over them for a while.
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{log, Bytes, BytesN, Env, Vec};
+
+use crate::commitments;
+use crate::errors::ContractError;
+use crate::escalation;
+use crate::milestones::{ended, milestone_done};
+use crate::storage;
+use crate::types::{
+    ArchiveKey, DataKey, Investment, MilestoneStatus, ProjectArchive, ProjectOutcome,
+};
+#[cfg(feature = "decision-log")]
+use crate::types::{ListKey, VerificationDecision};
+#[cfg(feature = "revenue")]
+use crate::types::RevenueKey;
+#[cfg(feature = "updates")]
+use crate::types::{ProjectUpdate, UpdateKey};
+use crate::{math, DEFAULT_ARCHIVE_TTL};
+
+// SHA-256 of an entry's XDR, used as a history leaf
+pub fn history_leaf<T: ToXdr>(env: &Env, entry: T) -> BytesN<32> {
+    env.crypto().sha256(&entry.to_xdr(env)).to_bytes()
+}
+
+// Merkle root of `leaves`, hashing neighbours pairwise level by level and
+// carrying an odd last node up unchanged; all zeroes when empty
+pub fn merkle_root(env: &Env, leaves: Vec<BytesN<32>>) -> BytesN<32> {
+    if leaves.is_empty() {
+        return BytesN::from_array(env, &[0u8; 32]);
+    }
+    
+    let mut level = leaves;
+    while level.len() > 1 {
+        let mut next: Vec<BytesN<32>> = Vec::new(env);
+        let mut i = 0;
+        while i < level.len() {
+            if i + 1 < level.len() {
+                let mut pair: Bytes = level.get(i).unwrap().into();
+                pair.append(&level.get(i + 1).unwrap().into());
+                next.push_back(env.crypto().sha256(&pair).to_bytes());
+            } else {
+                next.push_back(level.get(i).unwrap());
+            }
+            i += 2;
+        }
+        level = next;
+    }
+    
+    level.get(0).unwrap()
+}
+
+pub fn archive_project(env: Env, project_id: u32) -> Result<(), ContractError> {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    if project.archived {
+        panic!("Project already archived");
+    }
+    
+    let mut released: i128 = 0;
+    let mut milestones_verified: u32 = 0;
+    let mut finished = true;
+    for m in project.milestones.iter() {
+        if m.status == MilestoneStatus::Verified {
+            released = math::add(released, m.amount)?;
+            milestones_verified += 1;
+        } else if !milestone_done(&project, &m) {
+            finished = false;
+        }
+    }
+    
+    if project.active && !finished {
+        panic!("Project is not finished");
+    }
+    
+    if storage::has(&env, &DataKey::RefundProgress(project_id))
+        || escalation::votes_open(&env, project_id)
+        || commitments::pending(&env, project_id)
+    {
+        panic!("Project has pending work");
+    }
+    
+    let investments_key = DataKey::ProjectInvestments(project_id);
+    #[cfg(feature = "decision-log")]
+    let decision_count = ListKey::DecisionCount(project_id);
+    #[cfg(feature = "decision-log")]
+    let decision = |index| ListKey::Decision(project_id, index);
+    #[cfg(feature = "updates")]
+    let update_count = UpdateKey::UpdateCount(project_id);
+    #[cfg(feature = "updates")]
+    let update = |index| UpdateKey::Update(project_id, index);
+    
+    let mut leaves: Vec<BytesN<32>> = Vec::new(&env);
+    let investments: Vec<Investment> = storage::get(&env, &investments_key).unwrap_or(Vec::new(&env));
+    for entry in investments.iter() {
+        leaves.push_back(history_leaf(&env, entry));
+    }
+    #[cfg(feature = "decision-log")]
+    for index in 0..storage::list_len(&env, &decision_count) {
+        let entry: VerificationDecision = storage::get(&env, &decision(index)).unwrap();
+        leaves.push_back(history_leaf(&env, entry));
+    }
+    #[cfg(feature = "updates")]
+    for index in 0..storage::list_len(&env, &update_count) {
+        let entry: ProjectUpdate = storage::get(&env, &update(index)).unwrap();
+        leaves.push_back(history_leaf(&env, entry));
+    }
+    
+    let archive = ProjectArchive {
+        owner: project.owner.clone(),
+        token: project.token.clone(),
+        goal_amount: project.goal_amount,
+        raised: project.raised,
+        released,
+        milestones: project.milestones.len(),
+        milestones_verified,
+        outcome: if project.active { ProjectOutcome::Completed } else { ProjectOutcome::WoundDown },
+        history_root: merkle_root(&env, leaves.clone()),
+        history_len: leaves.len(),
+        archived_at: env.ledger().timestamp(),
+    };
+    
+    let ttl: u32 = env.storage()
+        .instance()
+        .get(&ArchiveKey::ArchiveTtl)
+        .unwrap_or(DEFAULT_ARCHIVE_TTL);
+    let ttl = ttl.min(env.storage().max_ttl());
+    let archive_key = ArchiveKey::ProjectArchive(project_id);
+    env.storage().temporary().set(&archive_key, &archive);
+    env.storage().temporary().extend_ttl(&archive_key, ttl, ttl);
+    
+    storage::remove(&env, &investments_key);
+    #[cfg(feature = "decision-log")]
+    storage::list_clear(&env, &decision_count, decision);
+    #[cfg(feature = "updates")]
+    storage::list_clear(&env, &update_count, update);
+    #[cfg(feature = "revenue")]
+    storage::remove(&env, &RevenueKey::RevenueRounds(project_id));
+    
+    if project.active && !ended(&project) {
+        storage::end_active_project(&env);
+    }
+    project.active = false;
+    project.archived = true;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Project {} archived", project_id);
+    
+    Ok(())
+}
+
+pub fn get_project_archive(env: Env, project_id: u32) -> Option<ProjectArchive> {
+    env.storage()
+        .temporary()
+        .get(&ArchiveKey::ProjectArchive(project_id))
+}
@@ -0,0 +1,69 @@
+// Independent audits: auditors approved by the admin post attestations on
+// a project or one of its milestones. Only the report's hash and the
+// verdict are kept on chain, as a record for frontends to surface next to
+// the verifier's decisions; audits do not move funds
+
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+use crate::errors;
+use crate::milestones::project_verifier;
+use crate::storage;
+use crate::types::{Audit, AuditKey, AuditVerdict};
+use crate::{MAX_AUDITS, MAX_PAGE_SIZE};
+
+pub fn post_audit(
+    env: Env,
+    auditor: Address,
+    project_id: u32,
+    milestone_index: Option<u32>,
+    report_hash: BytesN<32>,
+    verdict: AuditVerdict,
+) {
+    auditor.require_auth();
+    
+    if !storage::has(&env, &AuditKey::Auditor(auditor.clone())) {
+        panic!("Not an approved auditor");
+    }
+    
+    let project = storage::project(&env, project_id);
+    
+    if auditor == project.owner || auditor == project_verifier(&env, &project) {
+        panic!("Auditor must be independent of the project");
+    }
+    
+    if let Some(index) = milestone_index {
+        if index >= project.milestones.len() {
+            panic!("{}", errors::INVALID_MILESTONE);
+        }
+    }
+    
+    let audits_key = AuditKey::Audits(project_id);
+    let mut audits: Vec<Audit> = storage::get(&env, &audits_key).unwrap_or(Vec::new(&env));
+    
+    if audits.len() >= MAX_AUDITS {
+        panic!("Too many audits for this project");
+    }
+    
+    audits.push_back(Audit {
+        auditor: auditor.clone(),
+        milestone_index,
+        report_hash: report_hash.clone(),
+        verdict,
+        timestamp: env.ledger().timestamp(),
+    });
+    storage::set(&env, &audits_key, &audits);
+    storage::extend_instance(&env);
+    
+    env.events().publish(
+        (Symbol::new(&env, "audit_posted"), project_id),
+        (auditor, milestone_index, report_hash, verdict),
+    );
+}
+
+pub fn get_audits(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<Audit> {
+    let audits: Vec<Audit> = storage::get(&env, &AuditKey::Audits(project_id)).unwrap_or(Vec::new(&env));
+    
+    let start = start.min(audits.len());
+    let end = audits.len().min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+    audits.slice(start..end)
+}
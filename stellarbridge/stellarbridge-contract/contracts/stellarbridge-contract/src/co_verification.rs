@@ -0,0 +1,101 @@
+// Co-verification of large milestones. With a threshold configured, approving
+// a milestone above it takes the admin's sign-off as well as the verifier's,
+// in either order; a rejection still needs the verifier alone.
+
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::errors::ContractError;
+use crate::milestones::{check_version, decide_milestone};
+use crate::storage;
+use crate::types::{CoVerification, CoVerificationKey, MilestoneStatus, Project};
+
+// Whether approving the milestone takes the admin's sign-off as well as the
+// verifier's
+fn needs_co_verification(env: &Env, project: &Project, milestone_index: u32) -> bool {
+    let threshold: Option<i128> = env.storage().instance().get(&CoVerificationKey::CoVerificationThreshold);
+    match (threshold, project.milestones.get(milestone_index)) {
+        (Some(threshold), Some(milestone)) => milestone.amount > threshold,
+        _ => false,
+    }
+}
+
+// Sign-offs so far on the milestone's approval; those given at an older
+// project version no longer count
+fn co_verification(env: &Env, project: &Project, milestone_index: u32) -> CoVerification {
+    if project.milestones.get(milestone_index).unwrap().status != MilestoneStatus::EvidenceSubmitted {
+        panic!("No evidence submitted for this milestone");
+    }
+    
+    let record: Option<CoVerification> = storage::get(env, &CoVerificationKey::CoVerification(project.id, milestone_index));
+    record.filter(|record| record.version == project.version).unwrap_or(CoVerification {
+        verifier: None,
+        delegated: false,
+        admin_approved: false,
+        version: project.version,
+    })
+}
+
+// Approve the milestone once both sides have signed off, or keep the
+// sign-offs until the other side does
+fn settle_co_verification(env: &Env, project_id: u32, milestone_index: u32, record: CoVerification) -> Result<(), ContractError> {
+    let key = CoVerificationKey::CoVerification(project_id, milestone_index);
+    match record.verifier.clone() {
+        Some(verifier) if record.admin_approved => {
+            if verifier == storage::admin(env) {
+                panic!("Co-verification needs a second signer");
+            }
+            storage::remove(env, &key);
+            decide_milestone(env, project_id, milestone_index, true, None, &verifier, record.delegated)?;
+        }
+        _ => {
+            storage::set(env, &key, &record);
+            env.events().publish(
+                (Symbol::new(env, "co_verification_pending"), project_id),
+                (milestone_index, record.verifier.is_some(), record.admin_approved),
+            );
+        }
+    }
+    
+    Ok(())
+}
+
+// Count an approval above the threshold as the verifier's sign-off only,
+// deciding the milestone once the admin's is in as well; returns whether it
+// needed co-verification
+pub fn hold_approval(env: &Env, project_id: u32, milestone_index: u32, verifier: &Address, delegated: bool) -> Result<bool, ContractError> {
+    let project = storage::project(env, project_id);
+    if !needs_co_verification(env, &project, milestone_index) {
+        return Ok(false);
+    }
+    
+    let mut record = co_verification(env, &project, milestone_index);
+    record.verifier = Some(verifier.clone());
+    record.delegated = delegated;
+    settle_co_verification(env, project_id, milestone_index, record)?;
+    
+    Ok(true)
+}
+
+pub fn co_verify_milestone(env: Env, project_id: u32, milestone_index: u32, expected_version: u32) -> Result<(), ContractError> {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    check_version(&project, expected_version)?;
+    
+    if !needs_co_verification(&env, &project, milestone_index) {
+        panic!("Milestone does not need co-verification");
+    }
+    
+    let mut record = co_verification(&env, &project, milestone_index);
+    record.admin_approved = true;
+    settle_co_verification(&env, project_id, milestone_index, record)?;
+    
+    Ok(())
+}
+
+pub fn get_co_verification(env: Env, project_id: u32, milestone_index: u32) -> Option<CoVerification> {
+    storage::get(&env, &CoVerificationKey::CoVerification(project_id, milestone_index))
+}
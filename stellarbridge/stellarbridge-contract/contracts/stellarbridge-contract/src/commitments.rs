@@ -0,0 +1,274 @@
+// Conditional and recurring investments. A commitment escrows an investment
+// until the project has raised a set minimum, executing once it has and
+// returned if it expires first; a recurring commitment pulls a fixed amount
+// every interval out of the allowance the investor gave the contract.
+
+use soroban_sdk::{log, token, Address, Env, Symbol, Vec};
+
+use crate::errors::ContractError;
+use crate::investments::{check_investment, investment_refusal, record_investment, within_investor_maximum};
+use crate::storage;
+use crate::types::{Commitment, CommitmentKey, Project, RecurringCommitment};
+use crate::whitelist;
+use crate::{StellarBridgeContract, MAX_COMMITMENTS};
+
+// Whether the project still holds conditional commitments
+#[cfg(any(feature = "archive", feature = "preflight"))]
+pub fn pending(env: &Env, project_id: u32) -> bool {
+    storage::has(env, &CommitmentKey::Commitments(project_id))
+}
+
+// Execute every unexpired commitment whose condition the raise now meets,
+// rescanning after each since it raises the total further. A commitment
+// the investor's maximum or the whitelist no longer allows stays pending
+// until it is returned.
+pub fn execute_commitments(env: &Env, project: &mut Project) -> Result<(), ContractError> {
+    let commitments_key = CommitmentKey::Commitments(project.id);
+    let mut commitments: Vec<Commitment> = match storage::get(env, &commitments_key) {
+        Some(commitments) => commitments,
+        None => return Ok(()),
+    };
+    
+    let now = env.ledger().timestamp();
+    let mut executed = true;
+    while executed {
+        executed = false;
+        for i in 0..commitments.len() {
+            let commitment = commitments.get(i).unwrap();
+            let allowed = whitelist::allows(env, project, &commitment.investor);
+            if now <= commitment.expires_at
+                && project.raised >= commitment.min_raised
+                && allowed
+                && within_investor_maximum(env, project, &commitment.investor, commitment.amount)
+            {
+                commitments.remove(i);
+                record_investment(env, project, &commitment.investor, commitment.amount)?;
+                executed = true;
+                break;
+            }
+        }
+    }
+    
+    if commitments.is_empty() {
+        storage::remove(env, &commitments_key);
+    } else {
+        storage::set(env, &commitments_key, &commitments);
+    }
+    
+    Ok(())
+}
+
+pub fn commit_investment(
+    env: Env,
+    project_id: u32,
+    investor: Address,
+    amount: i128,
+    min_raised: i128,
+    expires_at: u64,
+) -> Result<(), ContractError> {
+    investor.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    check_investment(&env, &project, &investor, amount);
+    
+    if expires_at <= env.ledger().timestamp() {
+        panic!("Commitment expiry must be in the future");
+    }
+    
+    let commitments_key = CommitmentKey::Commitments(project_id);
+    let mut commitments: Vec<Commitment> = storage::get(&env, &commitments_key).unwrap_or(Vec::new(&env));
+    
+    if commitments.iter().any(|c| c.investor == investor) {
+        panic!("Investor already has a pending commitment");
+    }
+    
+    if commitments.len() >= MAX_COMMITMENTS {
+        panic!("Too many pending commitments");
+    }
+    
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&investor, &env.current_contract_address(), &amount);
+    
+    commitments.push_back(Commitment {
+        investor: investor.clone(),
+        amount,
+        min_raised,
+        expires_at,
+    });
+    storage::set(&env, &commitments_key, &commitments);
+    
+    log!(&env, "Commitment of {} to project {} from {}", amount, project_id, investor);
+    
+    // A condition the project already meets executes straight away
+    execute_commitments(&env, &mut project)?;
+    
+    Ok(())
+}
+
+pub fn settle_commitments(env: Env, project_id: u32) -> u32 {
+    let project = storage::project(&env, project_id);
+    
+    let commitments_key = CommitmentKey::Commitments(project_id);
+    let commitments: Vec<Commitment> = storage::get(&env, &commitments_key).unwrap_or(Vec::new(&env));
+    
+    let now = env.ledger().timestamp();
+    let token_client = token::Client::new(&env, &project.token);
+    let mut pending: Vec<Commitment> = Vec::new(&env);
+    let mut returned: u32 = 0;
+    for commitment in commitments.iter() {
+        if project.active && now <= commitment.expires_at {
+            pending.push_back(commitment);
+            continue;
+        }
+        token_client.transfer(&env.current_contract_address(), &commitment.investor, &commitment.amount);
+        returned += 1;
+    }
+    
+    if pending.is_empty() {
+        storage::remove(&env, &commitments_key);
+    } else {
+        storage::set(&env, &commitments_key, &pending);
+    }
+    
+    log!(&env, "Returned {} commitments for project {}", returned, project_id);
+    
+    returned
+}
+
+pub fn commit_recurring(env: Env, project_id: u32, investor: Address, amount: i128, interval: u64) {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    check_investment(&env, &project, &investor, amount);
+    
+    if interval == 0 {
+        panic!("Interval must be positive");
+    }
+    
+    let key = CommitmentKey::RecurringCommitments(project_id);
+    let mut commitments: Vec<RecurringCommitment> = storage::get(&env, &key).unwrap_or(Vec::new(&env));
+    
+    if commitments.iter().any(|c| c.investor == investor) {
+        panic!("Investor already has a recurring commitment");
+    }
+    
+    if commitments.len() >= MAX_COMMITMENTS {
+        panic!("Too many recurring commitments");
+    }
+    
+    // The first pull is due straight away
+    commitments.push_back(RecurringCommitment {
+        investor: investor.clone(),
+        amount,
+        interval,
+        next_due: env.ledger().timestamp(),
+    });
+    storage::set(&env, &key, &commitments);
+    
+    log!(&env, "Recurring commitment of {} every {}s to project {} from {}", amount, interval, project_id, investor);
+}
+
+pub fn cancel_recurring(env: Env, project_id: u32, investor: Address) {
+    investor.require_auth();
+    
+    let key = CommitmentKey::RecurringCommitments(project_id);
+    let mut commitments: Vec<RecurringCommitment> = storage::get(&env, &key).unwrap_or(Vec::new(&env));
+    
+    let index = commitments
+        .iter()
+        .position(|c| c.investor == investor)
+        .expect("No recurring commitment");
+    commitments.remove(index as u32);
+    
+    if commitments.is_empty() {
+        storage::remove(&env, &key);
+    } else {
+        storage::set(&env, &key, &commitments);
+    }
+    
+    log!(&env, "Recurring commitment to project {} from {} cancelled", project_id, investor);
+}
+
+pub fn execute_due_commitments(env: Env, project_id: u32) -> Result<u32, ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    let key = CommitmentKey::RecurringCommitments(project_id);
+    let commitments: Vec<RecurringCommitment> = match storage::get(&env, &key) {
+        Some(commitments) => commitments,
+        None => return Ok(0),
+    };
+    
+    let now = env.ledger().timestamp();
+    let token_client = token::Client::new(&env, &project.token);
+    let contract = env.current_contract_address();
+    let mut pending: Vec<RecurringCommitment> = Vec::new(&env);
+    let mut executed: u32 = 0;
+    for mut commitment in commitments.iter() {
+        if commitment.next_due > now {
+            pending.push_back(commitment);
+            continue;
+        }
+        
+        // A pull the project refuses or the allowance no longer covers
+        // ends the commitment rather than blocking everyone else's
+        let refused = investment_refusal(&env, &project, &commitment.investor, commitment.amount).is_some();
+        if refused
+            || token_client
+                .try_transfer_from(&contract, &commitment.investor, &contract, &commitment.amount)
+                .is_err()
+        {
+            env.events().publish(
+                (Symbol::new(&env, "recurring_lapsed"), project_id),
+                commitment.investor,
+            );
+            continue;
+        }
+        
+        record_investment(&env, &mut project, &commitment.investor, commitment.amount)?;
+        commitment.next_due = commitment.next_due.saturating_add(commitment.interval);
+        pending.push_back(commitment);
+        executed += 1;
+    }
+    
+    if pending.is_empty() {
+        storage::remove(&env, &key);
+    } else {
+        storage::set(&env, &key, &pending);
+    }
+    
+    execute_commitments(&env, &mut project)?;
+    
+    log!(&env, "Executed {} recurring commitments for project {}", executed, project_id);
+    
+    Ok(executed)
+}
+
+pub fn get_commitments(env: Env, project_id: u32) -> Vec<Commitment> {
+    let project = storage::project(&env, project_id);
+    let commitments: Vec<Commitment> = storage::get(&env, &CommitmentKey::Commitments(project_id))
+        .unwrap_or(Vec::new(&env));
+    
+    let mut active = Vec::new(&env);
+    if project.active {
+        let now = env.ledger().timestamp();
+        for commitment in commitments.iter() {
+            if now <= commitment.expires_at {
+                active.push_back(commitment);
+            }
+        }
+    }
+    active
+}
+
+pub fn get_committed_amount(env: Env, project_id: u32) -> i128 {
+    StellarBridgeContract::get_commitments(env, project_id)
+        .iter()
+        .map(|commitment| commitment.amount)
+        .sum()
+}
+
+pub fn get_recurring_commitments(env: Env, project_id: u32) -> Vec<RecurringCommitment> {
+    storage::get(&env, &CommitmentKey::RecurringCommitments(project_id)).unwrap_or(Vec::new(&env))
+}
@@ -0,0 +1,102 @@
+// BLS committee approvals. The admin registers a committee of BLS12-381
+// public keys and a threshold; members sign a milestone's approval message
+// off-chain and anyone submits the aggregate of their signatures with a
+// bitmap of who signed, approving the milestone in one call. Verification
+// follows the basic min-pk scheme: keys in G1, signatures in G2, and the
+// participants' keys summed so one pairing check covers them all. Summing
+// keys is only sound against rogue keys because members prove possession
+// of theirs before the admin registers them
+
+use soroban_sdk::crypto::bls12_381::{G1Affine, G2Affine};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{log, vec, Bytes, BytesN, Env, Symbol};
+
+use crate::admin::committee;
+use crate::errors::{self, ContractError};
+use crate::milestones::decide_or_co_verify;
+use crate::storage;
+use crate::types::MilestoneStatus;
+
+// The standard generator of G1, uncompressed
+const G1_GENERATOR: [u8; 96] = [
+    0x17, 0xf1, 0xd3, 0xa7, 0x31, 0x97, 0xd7, 0x94, 0x26, 0x95, 0x63, 0x8c,
+    0x4f, 0xa9, 0xac, 0x0f, 0xc3, 0x68, 0x8c, 0x4f, 0x97, 0x74, 0xb9, 0x05,
+    0xa1, 0x4e, 0x3a, 0x3f, 0x17, 0x1b, 0xac, 0x58, 0x6c, 0x55, 0xe8, 0x3f,
+    0xf9, 0x7a, 0x1a, 0xef, 0xfb, 0x3a, 0xf0, 0x0a, 0xdb, 0x22, 0xc6, 0xbb,
+    0x08, 0xb3, 0xf4, 0x81, 0xe3, 0xaa, 0xa0, 0xf1, 0xa0, 0x9e, 0x30, 0xed,
+    0x74, 0x1d, 0x8a, 0xe4, 0xfc, 0xf5, 0xe0, 0x95, 0xd5, 0xd0, 0x0a, 0xf6,
+    0x00, 0xdb, 0x18, 0xcb, 0x2c, 0x04, 0xb3, 0xed, 0xd0, 0x3c, 0xc7, 0x44,
+    0xa2, 0x88, 0x8a, 0xe4, 0x0c, 0xaa, 0x23, 0x29, 0x46, 0xc5, 0xe7, 0xe1,
+];
+
+// Domain separation tag of the basic scheme with signatures in G2
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+// Approvals are bound to this deployment and to the project version, so a
+// signature covers only the evidence under review when it was given
+pub fn committee_message(env: Env, project_id: u32, milestone_index: u32) -> Bytes {
+    let project = storage::project(&env, project_id);
+    (env.current_contract_address(), project_id, milestone_index, project.version).to_xdr(&env)
+}
+
+pub fn approve_by_committee(env: Env, project_id: u32, milestone_index: u32, participants: u64, signature: BytesN<192>) -> Result<(), ContractError> {
+    let committee = committee(&env);
+    
+    if committee.keys.is_empty() {
+        panic!("No BLS committee registered");
+    }
+    
+    if committee.keys.len() < u64::BITS && participants >> committee.keys.len() != 0 {
+        panic!("Invalid participation bitmap");
+    }
+    
+    if participants.count_ones() < committee.threshold {
+        panic!("Not enough committee signatures");
+    }
+    
+    let project = storage::project(&env, project_id);
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let milestone = project.milestones.get(milestone_index).unwrap();
+    if milestone.status != MilestoneStatus::EvidenceSubmitted {
+        panic!("No evidence submitted for this milestone");
+    }
+    
+    let bls = env.crypto().bls12_381();
+    let mut aggregate: Option<G1Affine> = None;
+    for (index, key) in committee.keys.iter().enumerate() {
+        if participants & (1 << index) != 0 {
+            let key = G1Affine::from_bytes(key);
+            aggregate = Some(match aggregate {
+                Some(sum) => bls.g1_add(&sum, &key),
+                None => key,
+            });
+        }
+    }
+    
+    // e(aggregate key, H(message)) == e(generator, signature)
+    let message = committee_message(env.clone(), project_id, milestone_index);
+    let hashed = bls.hash_to_g2(&message, &Bytes::from_slice(&env, DST));
+    let generator = G1Affine::from_array(&env, &G1_GENERATOR);
+    let valid = bls.pairing_check(
+        vec![&env, aggregate.unwrap(), -generator],
+        vec![&env, hashed, G2Affine::from_bytes(signature)],
+    );
+    if !valid {
+        panic!("Invalid committee signature");
+    }
+    
+    env.events().publish(
+        (Symbol::new(&env, "committee_approved"), project_id),
+        (milestone_index, participants),
+    );
+    
+    decide_or_co_verify(&env, project_id, milestone_index, true, None, &env.current_contract_address(), false)?;
+    
+    log!(&env, "Committee approved milestone {} of project {}", milestone_index, project_id);
+    
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Append-only history of admin setting changes, recording each setting's
+// value before and after, who changed it and when.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::storage;
+use crate::types::{ConfigChange, ConfigHistoryKey, ConfigSetting, ConfigValue};
+
+// Append an admin setting change to the config history
+pub fn record_config_change(
+    env: &Env,
+    setting: ConfigSetting,
+    old: ConfigValue,
+    new: ConfigValue,
+    actor: &Address,
+) {
+    let change = ConfigChange {
+        setting,
+        old,
+        new,
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::list_push(env, &ConfigHistoryKey::ConfigChangeCount, ConfigHistoryKey::ConfigChange, &change);
+}
+
+pub fn get_config_history(env: Env, start: u32, limit: u32) -> Vec<ConfigChange> {
+    storage::list_page(&env, &ConfigHistoryKey::ConfigChangeCount, ConfigHistoryKey::ConfigChange, start, limit)
+}
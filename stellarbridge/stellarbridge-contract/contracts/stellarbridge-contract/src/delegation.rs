@@ -0,0 +1,92 @@
+// Delegated verification. A project's verifier can hand its rights over the
+// whole project or a single milestone to a sub-verifier until an expiry;
+// decisions the delegate takes are marked as delegated in the audit trail.
+
+use soroban_sdk::{log, Address, BytesN, Env};
+
+use crate::errors::{self, ContractError};
+use crate::milestones::{check_version, decide_or_co_verify, project_verifier};
+use crate::storage;
+use crate::types::{Delegation, DelegationKey};
+
+// Whether `delegate` currently holds an unexpired delegation for the
+// milestone, either for it specifically or for the whole project
+pub fn is_delegate(env: &Env, project_id: u32, milestone_index: u32, delegate: &Address) -> bool {
+    let now = env.ledger().timestamp();
+    for scope in [Some(milestone_index), None] {
+        let delegation: Option<Delegation> = storage::get(env, &DelegationKey::Delegation(project_id, scope));
+        if let Some(delegation) = delegation {
+            if delegation.delegate == *delegate && now < delegation.expires_at {
+                return true;
+            }
+        }
+    }
+    
+    false
+}
+
+pub fn delegate_verification(
+    env: Env,
+    project_id: u32,
+    milestone_index: Option<u32>,
+    delegate: Address,
+    expires_at: u64,
+) {
+    let project = storage::project(&env, project_id);
+    
+    project_verifier(&env, &project).require_auth();
+    
+    if milestone_index.is_some_and(|index| index >= project.milestones.len()) {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    if expires_at <= env.ledger().timestamp() {
+        panic!("Delegation expiry must be in the future");
+    }
+    
+    storage::set(&env, 
+        &DelegationKey::Delegation(project_id, milestone_index),
+        &Delegation { delegate: delegate.clone(), expires_at },
+    );
+    
+    log!(&env, "Verification of project {} delegated to {}", project_id, delegate);
+}
+
+pub fn revoke_delegation(env: Env, project_id: u32, milestone_index: Option<u32>) {
+    let project = storage::project(&env, project_id);
+    
+    project_verifier(&env, &project).require_auth();
+    
+    let key = DelegationKey::Delegation(project_id, milestone_index);
+    if !storage::has(&env, &key) {
+        panic!("No delegation for this scope");
+    }
+    
+    storage::remove(&env, &key);
+    
+    log!(&env, "Verification delegation for project {} revoked", project_id);
+}
+
+pub fn verify_as_delegate(
+    env: Env,
+    delegate: Address,
+    project_id: u32,
+    milestone_index: u32,
+    approved: bool,
+    reason_hash: Option<BytesN<32>>,
+    expected_version: u32,
+) -> Result<(), ContractError> {
+    delegate.require_auth();
+    
+    if !is_delegate(&env, project_id, milestone_index, &delegate) {
+        panic!("Not a delegate for this milestone");
+    }
+    
+    let project = storage::project(&env, project_id);
+    
+    check_version(&project, expected_version)?;
+    
+    decide_or_co_verify(&env, project_id, milestone_index, approved, reason_hash, &delegate, true)?;
+    
+    Ok(())
+}
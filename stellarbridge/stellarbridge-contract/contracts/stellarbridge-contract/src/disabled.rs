@@ -0,0 +1,310 @@
+// Stand-ins for the optional subsystems a build leaves out. The core calls
+// into them at every point they apply, from listing through verification to
+// releases and refunds; without the feature nothing can configure them, so
+// each call does what it would with nothing configured.
+
+#[cfg(not(feature = "disputes"))]
+pub mod appeals {
+    use soroban_sdk::Env;
+    
+    use crate::types::{Milestone, Project};
+    
+    // No appeal window, so a rejection settles as soon as it is final
+    pub fn hold_rejection(_env: &Env, _project: &Project, _milestone_index: u32, _milestone: &mut Milestone) {}
+}
+
+#[cfg(not(feature = "co-verification"))]
+pub mod co_verification {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::errors::ContractError;
+    
+    // No threshold, so the verifier's approval alone decides
+    pub fn hold_approval(_env: &Env, _project_id: u32, _milestone_index: u32, _verifier: &Address, _delegated: bool) -> Result<bool, ContractError> {
+        Ok(false)
+    }
+}
+
+#[cfg(not(feature = "commitments"))]
+pub mod commitments {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No commitments, so none are ever pending or due
+    #[cfg(any(feature = "archive", feature = "preflight"))]
+    pub fn pending(_env: &Env, _project_id: u32) -> bool {
+        false
+    }
+    
+    pub fn execute_commitments(_env: &Env, _project: &mut Project) -> Result<(), ContractError> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "config-history"))]
+pub mod config_history {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::types::{ConfigSetting, ConfigValue};
+    
+    // No history, so a change is not recorded anywhere
+    pub fn record_config_change(_env: &Env, _setting: ConfigSetting, _old: ConfigValue, _new: ConfigValue, _actor: &Address) {}
+}
+
+#[cfg(all(feature = "preflight", not(feature = "delegation")))]
+pub mod delegation {
+    use soroban_sdk::{Address, Env};
+    
+    // Nothing is ever delegated
+    pub fn is_delegate(_env: &Env, _project_id: u32, _milestone_index: u32, _delegate: &Address) -> bool {
+        false
+    }
+}
+
+#[cfg(not(feature = "disputes"))]
+pub mod disputes {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No dispute window, so releases go out on approval and none is ever held
+    pub fn hold_release(_env: &Env, _project_id: u32, _milestone_index: u32, _release: i128) -> bool {
+        false
+    }
+    
+    pub fn withdraw_held_releases(_env: &Env, _project: &Project) -> Result<i128, ContractError> {
+        Ok(0)
+    }
+}
+
+#[cfg(not(feature = "escalation"))]
+pub mod escalation {
+    use soroban_sdk::Env;
+    
+    // Nothing escalates, so projects have no verification window and no
+    // milestone is ever put to a vote
+    pub fn verification_window(_env: &Env) -> Option<u64> {
+        None
+    }
+    
+    pub fn under_vote(_env: &Env, _project_id: u32, _milestone_index: u32) -> bool {
+        false
+    }
+    
+    #[cfg(any(feature = "archive", feature = "position-transfers", feature = "preflight"))]
+    pub fn votes_open(_env: &Env, _project_id: u32) -> bool {
+        false
+    }
+}
+
+#[cfg(not(feature = "hooks"))]
+pub mod hooks {
+    use soroban_sdk::{Address, Env};
+    
+    pub fn on_invest(_env: &Env, _project_id: u32, _investor: &Address, _amount: i128) {}
+    
+    pub fn on_release(_env: &Env, _project_id: u32, _amount: i128) {}
+    
+    pub fn on_refund(_env: &Env, _project_id: u32, _investor: &Address, _amount: i128) {}
+}
+
+#[cfg(not(feature = "insurance"))]
+pub mod insurance {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No policy, so no premium
+    pub fn collect_premium(_env: &Env, _project: &Project, _amount: i128) -> Result<i128, ContractError> {
+        Ok(0)
+    }
+}
+
+#[cfg(not(feature = "matching"))]
+pub mod matching {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    pub fn record_contribution(_env: &Env, _project_id: u32, _investor: &Address, _amount: i128) -> Result<(), ContractError> {
+        Ok(())
+    }
+    
+    pub fn apply_sponsor_match(_env: &Env, _project: &mut Project, _investor: &Address, _amount: i128) -> Result<(), ContractError> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "referrals"))]
+pub mod referrals {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No program, so the referrer is owed nothing
+    pub fn accrue(_env: &Env, _project: &Project, _investor: &Address, _referrer: &Address, _amount: i128) -> Result<(), ContractError> {
+        Ok(())
+    }
+    
+    #[cfg(feature = "fees")]
+    pub fn fund_from_fee(_env: &Env, _project: &Project, _platform_fee: i128) -> Result<i128, ContractError> {
+        Ok(0)
+    }
+}
+
+#[cfg(not(feature = "auto-reinvest"))]
+pub mod reinvest {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // Nobody has a reinvest target, so every claim is paid out
+    pub fn reinvest(_env: &Env, _source: &Project, _investor: &Address, _amount: i128) -> Result<bool, ContractError> {
+        Ok(false)
+    }
+}
+
+#[cfg(not(feature = "revenue"))]
+pub mod revenue {
+    use soroban_sdk::{Address, Env};
+    
+    // No revenue rounds, so there is no expiry clock to restart
+    pub fn mark_revenue_activity(_env: &Env, _project_id: u32, _investor: &Address) {}
+}
+
+#[cfg(not(feature = "listing-review"))]
+pub mod review {
+    use soroban_sdk::Env;
+    
+    use crate::types::ProjectMetadata;
+    
+    // No review, so every listing takes investments right away
+    pub fn pending_at_listing(_env: &Env, _metadata: Option<&ProjectMetadata>) -> bool {
+        false
+    }
+    
+    #[cfg(feature = "project-edits")]
+    pub fn needs_review(_env: &Env, _metadata: &ProjectMetadata) -> bool {
+        false
+    }
+}
+
+#[cfg(not(feature = "rewards"))]
+pub mod rewards {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::errors::ContractError;
+    
+    // No reward tiers or badges, so an investment earns nothing beyond its
+    // position
+    pub fn reward(_env: &Env, _project_id: u32, _investor: &Address, _amount: i128) -> Result<(), ContractError> {
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "share-token"))]
+pub mod shares {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::types::Project;
+    
+    // No share token wasm, so projects are listed without a share token and
+    // positions are tracked by the contract alone
+    pub fn deploy_share_token(_env: &Env, _project_id: u32, _project_token: &Address) -> Option<Address> {
+        None
+    }
+    
+    pub fn mint_shares(_env: &Env, _project: &Project, _investor: &Address, _amount: i128) {}
+    
+    #[cfg(feature = "position-transfers")]
+    pub fn move_shares(_env: &Env, _project: &Project, _from: &Address, _to: &Address, _amount: i128) {}
+    
+    pub fn retire_shares(_env: &Env, _project: &Project, _investor: &Address) {}
+}
+
+#[cfg(not(feature = "staking"))]
+pub mod staking {
+    use soroban_sdk::{Address, Env};
+    
+    // No stake can be required, so every verifier has enough
+    pub fn stake_sufficient(_env: &Env, _verifier: &Address) -> bool {
+        true
+    }
+}
+
+#[cfg(not(feature = "usd-goals"))]
+pub mod usd_goals {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No USD goal, so only the raise in the project's token funds it
+    pub fn record_usd(_env: &Env, _project: &Project, _amount: i128) -> Result<bool, ContractError> {
+        Ok(false)
+    }
+}
+
+#[cfg(not(feature = "verifier-pool"))]
+pub mod verifiers {
+    use soroban_sdk::{Address, Env};
+    
+    // Only the global verifier verifies, so nobody else is approved and new
+    // projects keep it
+    pub fn is_approved_verifier(_env: &Env, _verifier: &Address) -> bool {
+        false
+    }
+    
+    pub fn assign_verifier(_env: &Env, _owner: &Address) -> Option<Address> {
+        None
+    }
+}
+
+#[cfg(not(feature = "vesting"))]
+pub mod vesting {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // Nothing vests, so releases are paid out as soon as they are approved
+    pub fn vest(_env: &Env, _project: &Project, _release: i128) -> Result<bool, ContractError> {
+        Ok(false)
+    }
+}
+
+#[cfg(not(feature = "whitelist"))]
+pub mod whitelist {
+    use soroban_sdk::{Address, Env};
+    
+    use crate::types::Project;
+    
+    // No whitelists, so every project is open to anyone
+    pub fn allows(_env: &Env, _project: &Project, _investor: &Address) -> bool {
+        true
+    }
+}
+
+#[cfg(not(feature = "yield-pool"))]
+pub mod pool {
+    use soroban_sdk::Env;
+    
+    use crate::errors::ContractError;
+    use crate::types::Project;
+    
+    // No pool, so escrow stays in the contract and never earns anything
+    pub fn deposit_idle(_env: &Env, _project: &Project) -> Result<(), ContractError> {
+        Ok(())
+    }
+    
+    pub fn withdraw(_env: &Env, _project: &mut Project) -> Result<i128, ContractError> {
+        Ok(0)
+    }
+}
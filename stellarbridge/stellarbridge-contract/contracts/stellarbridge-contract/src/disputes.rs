@@ -0,0 +1,204 @@
+// Disputes over verified milestones. With a dispute window configured, an
+// approved milestone's release is held until the window passes; in that
+// time an investor can challenge it with a bond, which freezes the release
+// until the arbiter rules. The arbiter can be a single account or a
+// committee's multisig account. An upheld dispute fails the milestone and
+// returns the held release to investors along with the challenger's bond;
+// a dismissed one slashes the bond to the project owner. A refund returns
+// every held release to investors and every open dispute's bond.
+
+use soroban_sdk::{log, token, Address, Env, Symbol};
+
+use crate::errors::{self, ContractError};
+use crate::escrow::give_back;
+use crate::math;
+use crate::milestones::{pay_release, transition, update_status};
+use crate::pool;
+use crate::storage::{self, try_update_stats, update_reputation};
+use crate::types::{DataKey, Dispute, DisputeKey, MilestoneStatus, Payout, PendingRelease, Project};
+
+fn pending_release(env: &Env, project_id: u32, milestone_index: u32) -> PendingRelease {
+    storage::get(env, &DisputeKey::PendingRelease(project_id, milestone_index)).expect("No pending release")
+}
+
+// Hold an approved milestone's release for the owner until the dispute
+// window has passed, if one is configured; returns whether it was held
+pub fn hold_release(env: &Env, project_id: u32, milestone_index: u32, release: i128) -> bool {
+    let window: Option<u64> = env.storage().instance().get(&DisputeKey::DisputeWindow);
+    let Some(window) = window else {
+        return false;
+    };
+    
+    let pending = PendingRelease {
+        amount: release,
+        releasable_at: env.ledger().timestamp().saturating_add(window),
+    };
+    storage::set(env, &DisputeKey::PendingRelease(project_id, milestone_index), &pending);
+    
+    true
+}
+
+// Take back every release a refunded project still holds for its owner,
+// returning the bond of any dispute that was open against one; the total
+// is refunded with the rest of the escrow
+pub fn withdraw_held_releases(env: &Env, project: &Project) -> Result<i128, ContractError> {
+    let mut held: i128 = 0;
+    for milestone_index in 0..project.milestones.len() {
+        let pending_key = DisputeKey::PendingRelease(project.id, milestone_index);
+        let Some(pending) = storage::get::<_, PendingRelease>(env, &pending_key) else {
+            continue;
+        };
+        storage::remove(env, &pending_key);
+        held = math::add(held, pending.amount)?;
+        
+        let dispute_key = DisputeKey::Dispute(project.id, milestone_index);
+        if let Some(dispute) = storage::get::<_, Dispute>(env, &dispute_key) {
+            storage::remove(env, &dispute_key);
+            let token_client = token::Client::new(env, &project.token);
+            token_client.transfer(&env.current_contract_address(), &dispute.challenger, &dispute.bond);
+        }
+    }
+    
+    if held > 0 {
+        try_update_stats(env, |stats| {
+            stats.total_released = math::sub(stats.total_released, held)?;
+            stats.total_refunded = math::add(stats.total_refunded, held)?;
+            Ok(())
+        })?;
+    }
+    Ok(held)
+}
+
+pub fn open_dispute(env: Env, project_id: u32, milestone_index: u32, challenger: Address, bond: i128) {
+    challenger.require_auth();
+    
+    // Nobody could rule on the dispute, so the bond would be stuck
+    if !env.storage().instance().has(&DisputeKey::Arbiter) {
+        panic!("{}", errors::ARBITER_NOT_SET);
+    }
+    
+    let project = storage::project(&env, project_id);
+    let pending = pending_release(&env, project_id, milestone_index);
+    
+    if env.ledger().timestamp() >= pending.releasable_at {
+        panic!("Dispute window has closed");
+    }
+    
+    let invested: i128 = storage::get(&env, &DataKey::InvestorAmount(project_id, challenger.clone()))
+        .unwrap_or(0);
+    if invested == 0 {
+        panic!("Only investors can open a dispute");
+    }
+    
+    if bond <= 0 {
+        panic!("Dispute bond must be positive");
+    }
+    
+    let dispute_key = DisputeKey::Dispute(project_id, milestone_index);
+    if storage::has(&env, &dispute_key) {
+        panic!("Milestone is already disputed");
+    }
+    
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&challenger, &env.current_contract_address(), &bond);
+    
+    storage::set(&env, &dispute_key, &Dispute {
+        challenger: challenger.clone(),
+        bond,
+        opened_at: env.ledger().timestamp(),
+    });
+    storage::extend_instance(&env);
+    
+    env.events().publish(
+        (Symbol::new(&env, "dispute_opened"), project_id),
+        (milestone_index, challenger, bond),
+    );
+}
+
+pub fn resolve_dispute(env: Env, project_id: u32, milestone_index: u32, upheld: bool) -> Result<(), ContractError> {
+    let arbiter = storage::arbiter(&env);
+    
+    arbiter.require_auth();
+    
+    let dispute_key = DisputeKey::Dispute(project_id, milestone_index);
+    let dispute: Dispute = storage::get(&env, &dispute_key).expect("No open dispute");
+    storage::remove(&env, &dispute_key);
+    
+    let mut project = storage::project(&env, project_id);
+    let token_client = token::Client::new(&env, &project.token);
+    
+    if upheld {
+        // The milestone fails outright and what was held for the owner goes
+        // back as if the milestone had been rejected
+        pool::withdraw(&env, &mut project)?;
+        
+        let pending = pending_release(&env, project_id, milestone_index);
+        storage::remove(&env, &DisputeKey::PendingRelease(project_id, milestone_index));
+        
+        let mut milestone = project.milestones.get(milestone_index).unwrap();
+        transition(&mut project, &mut milestone, MilestoneStatus::Rejected);
+        milestone.resubmissions = project.max_resubmissions;
+        project.milestones.set(milestone_index, milestone);
+        
+        give_back(&env, Payout::Refund, &project, pending.amount)?;
+        if project.refundable {
+            try_update_stats(&env, |stats| {
+                stats.total_released = math::sub(stats.total_released, pending.amount)?;
+                stats.total_refunded = math::add(stats.total_refunded, pending.amount)?;
+                Ok(())
+            })?;
+        }
+        update_reputation(&env, &project.owner, |reputation| {
+            reputation.milestones_verified = reputation.milestones_verified.saturating_sub(1);
+            reputation.milestones_failed += 1;
+        });
+        
+        update_status(&env, &mut project)?;
+        storage::set_project(&env, &project);
+        pool::deposit_idle(&env, &project)?;
+        
+        token_client.transfer(&env.current_contract_address(), &dispute.challenger, &dispute.bond);
+    } else {
+        token_client.transfer(&env.current_contract_address(), &project.owner, &dispute.bond);
+    }
+    
+    env.events().publish(
+        (Symbol::new(&env, "dispute_resolved"), project_id),
+        (milestone_index, upheld),
+    );
+    
+    log!(&env, "Dispute on milestone {} of project {} resolved, upheld: {}", milestone_index, project_id, upheld);
+    
+    Ok(())
+}
+
+pub fn release_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    let pending = pending_release(&env, project_id, milestone_index);
+    
+    if env.ledger().timestamp() < pending.releasable_at {
+        panic!("Dispute window still open");
+    }
+    
+    if storage::has(&env, &DisputeKey::Dispute(project_id, milestone_index)) {
+        panic!("Milestone is under dispute");
+    }
+    
+    storage::remove(&env, &DisputeKey::PendingRelease(project_id, milestone_index));
+    pay_release(&env, &project, pending.amount)?;
+    
+    env.events().publish(
+        (Symbol::new(&env, "milestone_released"), project_id),
+        (milestone_index, pending.amount),
+    );
+    
+    Ok(())
+}
+
+pub fn get_pending_release(env: Env, project_id: u32, milestone_index: u32) -> Option<PendingRelease> {
+    storage::get(&env, &DisputeKey::PendingRelease(project_id, milestone_index))
+}
+
+pub fn get_dispute(env: Env, project_id: u32, milestone_index: u32) -> Option<Dispute> {
+    storage::get(&env, &DisputeKey::Dispute(project_id, milestone_index))
+}
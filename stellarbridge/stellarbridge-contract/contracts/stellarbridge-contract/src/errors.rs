@@ -0,0 +1,52 @@
+use soroban_sdk::contracterror;
+
+use crate::math::MathError;
+
+// Failures returned to callers as typed contract errors. Codes are part of
+// the interface, so a variant keeps its code once released
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractError {
+    Overflow = 1,
+    DivisionByZero = 2,
+    VersionMismatch = 3,
+}
+
+impl From<MathError> for ContractError {
+    fn from(error: MathError) -> Self {
+        match error {
+            MathError::Overflow => ContractError::Overflow,
+            MathError::DivisionByZero => ContractError::DivisionByZero,
+        }
+    }
+}
+
+// Panic messages raised from more than one place. Callers and tests match
+// on the text, so it never changes once released
+
+pub const PROJECT_NOT_FOUND: &str = "Project not found";
+pub const ADMIN_NOT_SET: &str = "Admin not set";
+pub const TOKEN_NOT_SET: &str = "Token not set";
+#[cfg(any(feature = "fees", feature = "revenue", feature = "yield-pool"))]
+pub const TREASURY_NOT_SET: &str = "Treasury not set";
+pub const VERIFIER_NOT_SET: &str = "Verifier not set";
+#[cfg(feature = "disputes")]
+pub const ARBITER_NOT_SET: &str = "Arbiter not set";
+pub const INVALID_MILESTONE: &str = "Invalid milestone index";
+pub const PROJECT_NOT_ACTIVE: &str = "Project is not active";
+#[cfg(any(
+    feature = "donations",
+    feature = "escalation",
+    feature = "oracle-milestones",
+    feature = "project-edits",
+    feature = "revenue",
+    feature = "rewards",
+    feature = "sequential",
+    feature = "usd-goals",
+    feature = "verifier-pool",
+    feature = "vesting"
+))]
+pub const HAS_INVESTMENTS: &str = "Project already has investments";
+pub const NOT_REFUNDABLE: &str = "Donations are not refundable";
+pub const TOKEN_NOT_ALLOWED: &str = "Token not allowed";
@@ -0,0 +1,212 @@
+// Milestones the verifier leaves unreviewed. A project created while a
+// verification window is configured can have submitted evidence escalated
+// once the window lapses: approved, refunded, or put to a vote of its
+// investors weighted by their positions, as the project chose. Small
+// milestones can instead be auto-approved once a challenge window passes.
+
+use soroban_sdk::{log, Address, Env, Symbol, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::milestones::{decide_milestone, decide_or_co_verify};
+use crate::storage;
+use crate::types::{
+    DataKey, Escalation, EscalationKey, Milestone, MilestoneStatus, MilestoneVote, Project,
+};
+use crate::StellarBridgeContract;
+
+// The verification window new projects are listed with
+pub fn verification_window(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&EscalationKey::VerificationWindow)
+}
+
+// Whether submitted evidence has gone unreviewed for the project's whole
+// verification window; never true when the project has no window
+pub fn escalation_due(env: &Env, project: &Project, milestone: &Milestone) -> bool {
+    if milestone.status != MilestoneStatus::EvidenceSubmitted {
+        return false;
+    }
+    
+    match (project.verification_window, milestone.submitted_at) {
+        (Some(window), Some(submitted_at)) => env.ledger().timestamp() >= submitted_at.saturating_add(window),
+        _ => false,
+    }
+}
+
+// Whether an investor vote is open on the milestone
+pub fn under_vote(env: &Env, project_id: u32, milestone_index: u32) -> bool {
+    storage::has(env, &EscalationKey::MilestoneVote(project_id, milestone_index))
+}
+
+// Whether any investor vote is open on the project
+#[cfg(any(feature = "archive", feature = "position-transfers", feature = "preflight"))]
+pub fn votes_open(env: &Env, project_id: u32) -> bool {
+    storage::has(env, &EscalationKey::OpenVotes(project_id))
+}
+
+// Add the investor's position to one side of an open milestone vote
+pub fn cast_vote(env: &Env, project_id: u32, milestone_index: u32, investor: &Address, approve: bool) {
+    let vote_key = EscalationKey::MilestoneVote(project_id, milestone_index);
+    let mut vote: MilestoneVote = storage::get(env, &vote_key).expect("No vote open for this milestone");
+    
+    if env.ledger().timestamp() >= vote.ends_at {
+        panic!("Vote has ended");
+    }
+    
+    // Marked with the vote's end so a later vote on the same milestone
+    // starts clean
+    let voted_key = EscalationKey::Voted(project_id, milestone_index, investor.clone());
+    if storage::get(env, &voted_key) == Some(vote.ends_at) {
+        panic!("Already voted");
+    }
+    
+    let weight: i128 = storage::get(env, &DataKey::InvestorAmount(project_id, investor.clone())).unwrap_or(0);
+    if weight <= 0 {
+        panic!("No position to vote with");
+    }
+    
+    if approve {
+        vote.approve += weight;
+    } else {
+        vote.reject += weight;
+    }
+    
+    storage::set(env, &vote_key, &vote);
+    storage::set(env, &voted_key, &vote.ends_at);
+    
+    log!(env, "{} voted on milestone {} of project {}", investor, milestone_index, project_id);
+}
+
+pub fn auto_approve_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let milestone = project.milestones.get(milestone_index).unwrap();
+    
+    let threshold: Option<i128> = env.storage().instance().get(&EscalationKey::AutoApproveThreshold);
+    if threshold.is_none_or(|threshold| milestone.amount >= threshold) {
+        panic!("Milestone is not eligible for auto-approval");
+    }
+    
+    let Some(submitted_at) = milestone.submitted_at.filter(|_| milestone.status == MilestoneStatus::EvidenceSubmitted) else {
+        panic!("No evidence submitted for this milestone");
+    };
+    
+    // The verifier can still reject until the window has passed
+    let window: u64 = env.storage()
+        .instance()
+        .get(&EscalationKey::AutoApproveWindow)
+        .unwrap_or(0);
+    if env.ledger().timestamp() < submitted_at.saturating_add(window) {
+        panic!("Challenge window still open");
+    }
+    
+    decide_or_co_verify(&env, project_id, milestone_index, true, None, &env.current_contract_address(), false)?;
+    
+    Ok(())
+}
+
+pub fn escalate_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    // Only a project with a verification window can have one lapse
+    let window = match project.verification_window {
+        Some(window) if escalation_due(&env, &project, &milestone) => window,
+        _ => panic!("Verification window has not lapsed"),
+    };
+    
+    let vote_key = EscalationKey::MilestoneVote(project_id, milestone_index);
+    if under_vote(&env, project_id, milestone_index) {
+        panic!("Milestone is under investor vote");
+    }
+    
+    let contract = env.current_contract_address();
+    match project.escalation {
+        Escalation::AutoApprove => {
+            decide_or_co_verify(&env, project_id, milestone_index, true, None, &contract, false)?;
+        }
+        Escalation::Refund => {
+            // Use up the resubmissions so the rejection is final and
+            // the milestone's funds become refundable
+            milestone.resubmissions = project.max_resubmissions;
+            project.milestones.set(milestone_index, milestone);
+            storage::set_project(&env, &project);
+            decide_milestone(&env, project_id, milestone_index, false, None, &contract, false)?;
+        }
+        Escalation::InvestorVote => {
+            let ends_at = env.ledger().timestamp().saturating_add(window);
+            storage::set(&env, &vote_key, &MilestoneVote { ends_at, approve: 0, reject: 0 });
+            
+            let open_key = EscalationKey::OpenVotes(project_id);
+            let open: u32 = storage::get(&env, &open_key).unwrap_or(0);
+            storage::set(&env, &open_key, &(open + 1));
+            
+            env.events().publish(
+                (Symbol::new(&env, "milestone_vote_opened"), project_id),
+                (milestone_index, ends_at),
+            );
+        }
+    }
+    
+    log!(&env, "Milestone {} of project {} escalated", milestone_index, project_id);
+    
+    Ok(())
+}
+
+pub fn vote_on_milestone(env: Env, project_id: u32, milestone_index: u32, investor: Address, approve: bool) {
+    investor.require_auth();
+    
+    cast_vote(&env, project_id, milestone_index, &investor, approve);
+}
+
+pub fn invest_and_vote(env: Env, project_id: u32, investor: Address, amount: i128, votes: Vec<(u32, bool)>) -> Result<(), ContractError> {
+    StellarBridgeContract::invest(env.clone(), project_id, investor.clone(), amount, None)?;
+    
+    for (milestone_index, approve) in votes.iter() {
+        cast_vote(&env, project_id, milestone_index, &investor, approve);
+    }
+    
+    Ok(())
+}
+
+pub fn finalize_milestone_vote(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let vote_key = EscalationKey::MilestoneVote(project_id, milestone_index);
+    let vote: MilestoneVote = storage::get(&env, &vote_key).expect("No vote open for this milestone");
+    
+    if env.ledger().timestamp() < vote.ends_at {
+        panic!("Vote has not ended");
+    }
+    
+    storage::remove(&env, &vote_key);
+    
+    let open_key = EscalationKey::OpenVotes(project_id);
+    let open: u32 = storage::get(&env, &open_key).unwrap_or(0);
+    if open <= 1 {
+        storage::remove(&env, &open_key);
+    } else {
+        storage::set(&env, &open_key, &(open - 1));
+    }
+    
+    let approved = vote.approve > vote.reject;
+    decide_or_co_verify(&env, project_id, milestone_index, approved, None, &env.current_contract_address(), false)?;
+    
+    Ok(())
+}
+
+pub fn get_milestone_vote(env: Env, project_id: u32, milestone_index: u32) -> Option<MilestoneVote> {
+    storage::get(&env, &EscalationKey::MilestoneVote(project_id, milestone_index))
+}
@@ -0,0 +1,170 @@
+// Funds held for projects: milestone releases and the pro-rata
+// payout streams (revenue, refunds, surplus, yield) credited to investors
+
+use soroban_sdk::{log, token, Address, Env};
+
+use crate::errors::ContractError;
+use crate::reinvest::reinvest;
+use crate::storage::{self, try_update_stats};
+use crate::types::{DataKey, Payout, Project};
+#[cfg(feature = "payout-splits")]
+use crate::splitter;
+use crate::{math, PAYOUT_SCALE};
+
+// Scaled payout accrued by an investor since their last checkpoint,
+// plus whatever was already settled for them
+pub fn accrued_payout(env: &Env, kind: Payout, project_id: u32, investor: &Address) -> Result<i128, ContractError> {
+    let per_unit: i128 = storage::get(env, &DataKey::PayoutPerUnit(kind, project_id)).unwrap_or(0);
+    let position: i128 = storage::get(env, &DataKey::InvestorAmount(project_id, investor.clone()))
+        .unwrap_or(0);
+    let debt: i128 = storage::get(env, &DataKey::PayoutDebt(kind, project_id, investor.clone())).unwrap_or(0);
+    let owed: i128 = storage::get(env, &DataKey::PayoutOwed(kind, project_id, investor.clone())).unwrap_or(0);
+    
+    Ok(math::add(owed, math::sub(math::mul(position, per_unit)?, debt)?)?)
+}
+
+// Move accrued payouts into the investor's owed balance. Must run before
+// their position changes so earlier credits are counted at the old size.
+pub fn settle_payout(env: &Env, kind: Payout, project_id: u32, investor: &Address) -> Result<(), ContractError> {
+    if !storage::has(env, &DataKey::PayoutPerUnit(kind, project_id)) {
+        return Ok(());
+    }
+    
+    let owed = accrued_payout(env, kind, project_id, investor)?;
+    storage::set(env, &DataKey::PayoutOwed(kind, project_id, investor.clone()), &owed);
+    checkpoint_payout(env, kind, project_id, investor)?;
+    
+    Ok(())
+}
+
+// Mark all payouts so far as accounted for at the investor's current position
+pub fn checkpoint_payout(env: &Env, kind: Payout, project_id: u32, investor: &Address) -> Result<(), ContractError> {
+    let per_unit: i128 = match storage::get(env, &DataKey::PayoutPerUnit(kind, project_id)) {
+        Some(per_unit) => per_unit,
+        None => return Ok(()),
+    };
+    let position: i128 = storage::get(env, &DataKey::InvestorAmount(project_id, investor.clone()))
+        .unwrap_or(0);
+    
+    storage::set(env, &DataKey::PayoutDebt(kind, project_id, investor.clone()), &math::mul(position, per_unit)?);
+    
+    Ok(())
+}
+
+pub fn settle_payouts(env: &Env, project_id: u32, investor: &Address) -> Result<(), ContractError> {
+    #[cfg(feature = "revenue")]
+    settle_payout(env, Payout::Revenue, project_id, investor)?;
+    settle_payout(env, Payout::Refund, project_id, investor)?;
+    settle_payout(env, Payout::Surplus, project_id, investor)?;
+    #[cfg(feature = "yield-pool")]
+    settle_payout(env, Payout::Yield, project_id, investor)?;
+    
+    Ok(())
+}
+
+pub fn checkpoint_payouts(env: &Env, project_id: u32, investor: &Address) -> Result<(), ContractError> {
+    #[cfg(feature = "revenue")]
+    checkpoint_payout(env, Payout::Revenue, project_id, investor)?;
+    checkpoint_payout(env, Payout::Refund, project_id, investor)?;
+    checkpoint_payout(env, Payout::Surplus, project_id, investor)?;
+    #[cfg(feature = "yield-pool")]
+    checkpoint_payout(env, Payout::Yield, project_id, investor)?;
+    
+    Ok(())
+}
+
+// Send a milestone release to the project's recipients by share, or to
+// the owner when no split is set
+pub fn release_funds(env: &Env, project: &Project, amount: i128) -> Result<(), ContractError> {
+    #[cfg(feature = "payout-splits")]
+    if !project.recipients.is_empty() {
+        splitter::distribute(env, &project.token, &project.recipients, amount)?;
+        return Ok(());
+    }
+    
+    let token_client = token::Client::new(env, &project.token);
+    token_client.transfer(&env.current_contract_address(), &project.owner, &amount);
+    
+    Ok(())
+}
+
+// Spread `amount` over all invested units; returns the new per-unit total
+pub fn credit_payout(env: &Env, kind: Payout, project: &Project, amount: i128) -> Result<i128, ContractError> {
+    let per_unit_key = DataKey::PayoutPerUnit(kind, project.id);
+    let per_unit: i128 = storage::get(env, &per_unit_key).unwrap_or(0);
+    let per_unit = math::add(per_unit, math::mul_div(amount, PAYOUT_SCALE, project.raised)?)?;
+    storage::set(env, &per_unit_key, &per_unit);
+    Ok(per_unit)
+}
+
+// Give `amount` back to the project's investors as `kind`; in donation mode
+// nothing goes back to donors, so it goes to the beneficiary instead
+pub fn give_back(env: &Env, kind: Payout, project: &Project, amount: i128) -> Result<(), ContractError> {
+    match &project.beneficiary {
+        #[cfg(feature = "donations")]
+        Some(beneficiary) if !project.refundable => {
+            let token_client = token::Client::new(env, &project.token);
+            token_client.transfer(&env.current_contract_address(), beneficiary, &amount);
+        }
+        _ => {
+            credit_payout(env, kind, project, amount)?;
+        }
+    }
+    
+    Ok(())
+}
+
+// Once a project is done with its milestones, make what it raised over its
+// goal claimable pro-rata; runs at most once per project and never releases
+// more than the project still holds
+pub fn release_surplus(env: &Env, project: &mut Project) -> Result<(), ContractError> {
+    if storage::has(env, &DataKey::PayoutPerUnit(Payout::Surplus, project.id)) {
+        return Ok(());
+    }
+    
+    let surplus = math::sub(project.raised, project.goal_amount)?.min(project.escrow_balance);
+    if surplus <= 0 {
+        return Ok(());
+    }
+    
+    project.escrow_balance = math::sub(project.escrow_balance, surplus)?;
+    give_back(env, Payout::Surplus, project, surplus)?;
+    let refundable = project.refundable;
+    try_update_stats(env, |stats| {
+        if refundable {
+            stats.total_refunded = math::add(stats.total_refunded, surplus)?;
+        } else {
+            stats.total_released = math::add(stats.total_released, surplus)?;
+        }
+        stats.value_locked = math::sub(stats.value_locked, surplus)?;
+        Ok(())
+    })?;
+    
+    log!(env, "Surplus of {} claimable in project {}", surplus, project.id);
+    
+    Ok(())
+}
+
+// Pay out everything owed to the investor and return the amount sent
+pub fn claim_payout(env: &Env, kind: Payout, project: &Project, investor: &Address) -> Result<i128, ContractError> {
+    settle_payout(env, kind, project.id, investor)?;
+    
+    let owed_key = DataKey::PayoutOwed(kind, project.id, investor.clone());
+    let owed: i128 = storage::get(env, &owed_key).unwrap_or(0);
+    let payout = owed / PAYOUT_SCALE;
+    
+    if payout <= 0 {
+        return Ok(0);
+    }
+    
+    // Keep the sub-unit remainder so rounding dust is not lost
+    storage::set(env, &owed_key, &(owed % PAYOUT_SCALE));
+    
+    if !reinvest(env, project, investor, payout)? {
+        let token_client = token::Client::new(env, &project.token);
+        token_client.transfer(&env.current_contract_address(), investor, &payout);
+    }
+    
+    Ok(payout)
+}
+
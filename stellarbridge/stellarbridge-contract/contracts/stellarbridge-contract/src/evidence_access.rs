@@ -0,0 +1,64 @@
+// Access to encrypted milestone evidence. The owner can hand a reviewer the
+// evidence decryption key, wrapped for that reviewer, until a given time.
+
+use soroban_sdk::{log, Address, Bytes, Env};
+
+use crate::errors;
+use crate::storage;
+use crate::types::{EvidenceAccessKey, EvidenceGrant};
+use crate::MAX_ENVELOPE_LEN;
+
+pub fn grant_evidence_access(
+    env: Env,
+    project_id: u32,
+    milestone_index: u32,
+    grantee: Address,
+    key_envelope: Bytes,
+    expires_at: u64,
+) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    if key_envelope.is_empty() || key_envelope.len() > MAX_ENVELOPE_LEN {
+        panic!("Invalid key envelope");
+    }
+    
+    if expires_at <= env.ledger().timestamp() {
+        panic!("Grant expiry must be in the future");
+    }
+    
+    storage::set(&env, 
+        &EvidenceAccessKey::EvidenceGrant(project_id, milestone_index, grantee.clone()),
+        &EvidenceGrant { key_envelope, expires_at },
+    );
+    
+    log!(&env, "Evidence access for project {} milestone {} granted to {}", project_id, milestone_index, grantee);
+}
+
+pub fn revoke_evidence_access(env: Env, project_id: u32, milestone_index: u32, grantee: Address) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    let key = EvidenceAccessKey::EvidenceGrant(project_id, milestone_index, grantee.clone());
+    if !storage::has(&env, &key) {
+        panic!("No evidence grant for this address");
+    }
+    
+    storage::remove(&env, &key);
+    
+    log!(&env, "Evidence access for project {} milestone {} revoked from {}", project_id, milestone_index, grantee);
+}
+
+pub fn get_evidence_access(env: Env, project_id: u32, milestone_index: u32, grantee: Address) -> Option<Bytes> {
+    let grant: Option<EvidenceGrant> = storage::get(&env, &EvidenceAccessKey::EvidenceGrant(project_id, milestone_index, grantee));
+    
+    grant
+        .filter(|grant| env.ledger().timestamp() < grant.expires_at)
+        .map(|grant| grant.key_envelope)
+}
@@ -0,0 +1,84 @@
+// Platform fee strategies: the admin picks one per project category, it is
+// resolved to a basis-point rate when a project is listed, and that rate is
+// charged on every milestone the project releases.
+
+use crate::math::{self, MathError};
+use crate::splitter::BASIS_POINTS;
+#[cfg(feature = "fees")]
+use crate::{DynamicFee, FeeStrategy};
+
+// Most tiers a tiered strategy may define
+#[cfg(feature = "fees")]
+pub const MAX_FEE_TIERS: u32 = 10;
+
+// Panic unless `strategy` is a usable strategy
+#[cfg(feature = "fees")]
+pub fn validate(strategy: &FeeStrategy) {
+    match strategy {
+        FeeStrategy::Free => {}
+        FeeStrategy::Flat(bps) => {
+            if *bps > BASIS_POINTS {
+                panic!("Fee cannot exceed 10000 basis points");
+            }
+        }
+        FeeStrategy::Tiered(tiers) => {
+            if tiers.is_empty() {
+                panic!("Fee strategy has no tiers");
+            }
+
+            if tiers.len() > MAX_FEE_TIERS {
+                panic!("Too many fee tiers");
+            }
+
+            let mut floor: i128 = -1;
+            for tier in tiers.iter() {
+                if tier.min_goal <= floor {
+                    panic!("Fee tiers must have ascending non-negative goals");
+                }
+                if tier.bps > BASIS_POINTS {
+                    panic!("Fee cannot exceed 10000 basis points");
+                }
+                floor = tier.min_goal;
+            }
+        }
+        FeeStrategy::Dynamic(DynamicFee { base_bps, max_bps, .. }) => {
+            if *max_bps > BASIS_POINTS {
+                panic!("Fee cannot exceed 10000 basis points");
+            }
+            if base_bps > max_bps {
+                panic!("Base fee cannot exceed maximum fee");
+            }
+        }
+    }
+}
+
+// Rate in basis points for a project raising `goal_amount`, listed after
+// `listed` other projects in its category
+#[cfg(feature = "fees")]
+pub fn rate(strategy: &FeeStrategy, goal_amount: i128, listed: u32) -> u32 {
+    match strategy {
+        FeeStrategy::Free => 0,
+        FeeStrategy::Flat(bps) => *bps,
+        // The highest tier the goal reaches applies; goals below the first
+        // tier pay nothing
+        FeeStrategy::Tiered(tiers) => {
+            let mut bps = 0;
+            for tier in tiers.iter() {
+                if goal_amount < tier.min_goal {
+                    break;
+                }
+                bps = tier.bps;
+            }
+            bps
+        }
+        FeeStrategy::Dynamic(fee) => {
+            let bps = fee.base_bps.saturating_add(fee.step_bps.saturating_mul(listed));
+            bps.min(fee.max_bps)
+        }
+    }
+}
+
+// Fee at `bps` on `amount`, rounded down
+pub fn charge(bps: u32, amount: i128) -> Result<i128, MathError> {
+    math::mul_div(amount, bps as i128, BASIS_POINTS as i128)
+}
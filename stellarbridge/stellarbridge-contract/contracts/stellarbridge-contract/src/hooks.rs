@@ -0,0 +1,50 @@
+// Observer hooks: contracts the admin registered to be told of
+// investments, milestone releases and refunds as they happen. Hooks are
+// called in turn and one that fails is skipped with a `hook_failed` event,
+// so no hook can block the operation it is told of or undo its effects. The
+// host refuses hooks calling back into this contract as re-entry
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::types::{HookClient, HookKey};
+
+pub fn hooks(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&HookKey::Hooks)
+        .unwrap_or(Vec::new(env))
+}
+
+fn failed(env: &Env, hook: &Address, callback: &str) {
+    env.events().publish(
+        (Symbol::new(env, "hook_failed"), hook.clone()),
+        Symbol::new(env, callback),
+    );
+}
+
+pub fn on_invest(env: &Env, project_id: u32, investor: &Address, amount: i128) {
+    for hook in hooks(env).iter() {
+        let result = HookClient::new(env, &hook).try_on_invest(&project_id, investor, &amount);
+        if !matches!(result, Ok(Ok(()))) {
+            failed(env, &hook, "on_invest");
+        }
+    }
+}
+
+pub fn on_release(env: &Env, project_id: u32, amount: i128) {
+    for hook in hooks(env).iter() {
+        let result = HookClient::new(env, &hook).try_on_release(&project_id, &amount);
+        if !matches!(result, Ok(Ok(()))) {
+            failed(env, &hook, "on_release");
+        }
+    }
+}
+
+pub fn on_refund(env: &Env, project_id: u32, investor: &Address, amount: i128) {
+    for hook in hooks(env).iter() {
+        let result = HookClient::new(env, &hook).try_on_refund(&project_id, investor, &amount);
+        if !matches!(result, Ok(Ok(()))) {
+            failed(env, &hook, "on_refund");
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Platform insurance: while a policy is set, a premium off every investment
+// goes to a pool kept per token. Investors in a project that ends refunded
+// have lost what it released before failing; the policy's share of that,
+// capped per project, is reserved from the pool by the first claim and paid
+// out pro-rata to positions, so a short pool pays everyone the same share
+
+use soroban_sdk::{token, Address, Env, Symbol};
+
+use crate::errors::ContractError;
+use crate::storage;
+use crate::types::{
+    DataKey, InsuranceClaim, InsuranceKey, InsurancePolicy, MilestoneStatus, Project, ProjectStatus,
+};
+use crate::{fee, math, splitter};
+
+fn pool_balance(env: &Env, token: &Address) -> i128 {
+    storage::get(env, &InsuranceKey::InsurancePool(token.clone())).unwrap_or(0)
+}
+
+fn set_pool_balance(env: &Env, token: &Address, balance: i128) {
+    storage::set(env, &InsuranceKey::InsurancePool(token.clone()), &balance);
+}
+
+// Move the premium on an investment the contract holds into the pool;
+// returns the premium, which is not booked as invested
+pub fn collect_premium(env: &Env, project: &Project, amount: i128) -> Result<i128, ContractError> {
+    let policy: Option<InsurancePolicy> = env.storage().instance().get(&InsuranceKey::InsurancePolicy);
+    let premium = match policy {
+        Some(policy) => fee::charge(policy.premium_bps, amount)?,
+        None => 0,
+    };
+    if premium > 0 {
+        set_pool_balance(env, &project.token, math::add(pool_balance(env, &project.token), premium)?);
+    }
+    Ok(premium)
+}
+
+// The project's compensation, reserved from the pool on first use
+fn claim(env: &Env, project: &Project) -> Result<InsuranceClaim, ContractError> {
+    let key = InsuranceKey::InsuranceClaim(project.id);
+    if let Some(claim) = storage::get(env, &key) {
+        return Ok(claim);
+    }
+    
+    let policy: InsurancePolicy = env.storage()
+        .instance()
+        .get(&InsuranceKey::InsurancePolicy)
+        .expect("Insurance is not enabled");
+    
+    let mut released: i128 = 0;
+    for m in project.milestones.iter() {
+        if m.status == MilestoneStatus::Verified {
+            released = math::add(released, m.amount)?;
+        }
+    }
+    let loss = released.min(project.raised);
+    let covered = math::mul_div(loss, policy.coverage_bps as i128, splitter::BASIS_POINTS as i128)?
+        .min(policy.max_coverage);
+    
+    let pool = pool_balance(env, &project.token);
+    let reserved = covered.min(pool);
+    set_pool_balance(env, &project.token, math::sub(pool, reserved)?);
+    
+    let claim = InsuranceClaim { reserved, paid: 0 };
+    storage::set(env, &key, &claim);
+    
+    env.events().publish(
+        (Symbol::new(env, "insurance_reserved"), project.id),
+        (loss, reserved),
+    );
+    
+    Ok(claim)
+}
+
+pub fn claim_insurance(env: Env, investor: Address, project_id: u32) -> Result<i128, ContractError> {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    if project.status != ProjectStatus::Refunded {
+        panic!("Project did not end in a refund");
+    }
+    
+    let paid_key = InsuranceKey::InsurancePaid(project_id, investor.clone());
+    if storage::has(&env, &paid_key) {
+        panic!("Insurance already claimed");
+    }
+    
+    let position: i128 = storage::get(&env, &DataKey::InvestorAmount(project_id, investor.clone()))
+        .unwrap_or(0);
+    
+    let mut claim = claim(&env, &project)?;
+    let payout = if project.raised > 0 {
+        math::mul_div(position, claim.reserved, project.raised)?
+    } else {
+        0
+    };
+    
+    if payout <= 0 {
+        panic!("No insurance compensation to claim");
+    }
+    
+    claim.paid = math::add(claim.paid, payout)?;
+    storage::set(&env, &InsuranceKey::InsuranceClaim(project_id), &claim);
+    storage::set(&env, &paid_key, &true);
+    storage::extend_instance(&env);
+    
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&env.current_contract_address(), &investor, &payout);
+    
+    env.events().publish(
+        (Symbol::new(&env, "insurance_claimed"), project_id),
+        (investor, payout),
+    );
+    
+    Ok(payout)
+}
+
+pub fn get_insurance_pool(env: Env, token: Address) -> i128 {
+    pool_balance(&env, &token)
+}
+
+pub fn get_insurance_claim(env: Env, project_id: u32) -> Option<InsuranceClaim> {
+    storage::get(&env, &InsuranceKey::InsuranceClaim(project_id))
+}
@@ -0,0 +1,63 @@
+// Investments relayed from signed intents: an investor registers an
+// ed25519 key and signs intents off-chain, and anyone can submit one to
+// invest on the investor's behalf out of the allowance they gave the
+// contract, each intent carrying the investor's next nonce
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{log, token, Address, BytesN, Env};
+
+use crate::errors::ContractError;
+use crate::commitments::execute_commitments;
+use crate::investments::{check_investment, record_investment};
+use crate::storage;
+use crate::types::{IntentsKey, InvestmentIntent};
+
+pub fn set_intent_key(env: Env, investor: Address, public_key: Option<BytesN<32>>) {
+    investor.require_auth();
+    
+    let key = IntentsKey::IntentKey(investor.clone());
+    match &public_key {
+        Some(public_key) => storage::set(&env, &key, public_key),
+        None => storage::remove(&env, &key),
+    }
+    
+    log!(&env, "Intent key for {} updated", investor);
+}
+
+pub fn invest_with_intent(env: Env, intent: InvestmentIntent, signature: BytesN<64>) -> Result<(), ContractError> {
+    if env.ledger().timestamp() > intent.expiry {
+        panic!("Investment intent has expired");
+    }
+    
+    let public_key: BytesN<32> = storage::get(&env, &IntentsKey::IntentKey(intent.investor.clone()))
+        .expect("Investor has no intent key");
+    
+    let nonce_key = IntentsKey::IntentNonce(intent.investor.clone());
+    let nonce: u64 = storage::get(&env, &nonce_key).unwrap_or(0);
+    if intent.nonce != nonce {
+        panic!("Invalid intent nonce");
+    }
+    
+    let message = (env.current_contract_address(), intent.clone()).to_xdr(&env);
+    env.crypto().ed25519_verify(&public_key, &message, &signature);
+    storage::set(&env, &nonce_key, &(nonce + 1));
+    
+    let mut project = storage::project(&env, intent.project_id);
+    
+    check_investment(&env, &project, &intent.investor, intent.amount);
+    
+    // Pull the investor's tokens under the allowance they gave the contract,
+    // so the relayer pays the fees without handling the funds
+    let contract = env.current_contract_address();
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer_from(&contract, &intent.investor, &contract, &intent.amount);
+    
+    record_investment(&env, &mut project, &intent.investor, intent.amount)?;
+    execute_commitments(&env, &mut project)?;
+    
+    Ok(())
+}
+
+pub fn get_intent_nonce(env: Env, investor: Address) -> u64 {
+    storage::get(&env, &IntentsKey::IntentNonce(investor)).unwrap_or(0)
+}
@@ -0,0 +1,273 @@
+// Investments into projects: direct, batched and allowance-funded
+// investments and each investor's portfolio
+
+use soroban_sdk::{log, token, Address, Env, Vec};
+
+use crate::commitments;
+use crate::errors::ContractError;
+use crate::escrow::{checkpoint_payouts, settle_payouts};
+use crate::hooks;
+use crate::insurance;
+use crate::matching;
+use crate::pool;
+use crate::referrals;
+use crate::revenue::mark_revenue_activity;
+use crate::rewards;
+use crate::shares;
+use crate::storage::{self, try_update_stats};
+#[cfg(feature = "listings")]
+use crate::storage::update_stats;
+use crate::types::{DataKey, Investment, Project, ProjectStatus};
+#[cfg(feature = "listings")]
+use crate::types::ListingKey;
+use crate::usd_goals;
+use crate::whitelist;
+use crate::math;
+#[cfg(feature = "listings")]
+use crate::StellarBridgeContract;
+#[cfg(feature = "batch-invest")]
+use crate::MAX_BATCH_INVESTMENTS;
+
+// Why `investor` may not invest `amount` in the project right now, if
+// anything stops them
+pub fn investment_refusal(env: &Env, project: &Project, investor: &Address, amount: i128) -> Option<&'static str> {
+    if !project.active {
+        return Some("Project is not active");
+    }
+    
+    if project.pending_approval {
+        return Some("Project is pending listing approval");
+    }
+    
+    // Money arriving after the last milestone was decided could never be
+    // released
+    if matches!(project.status, ProjectStatus::Completed | ProjectStatus::Refunded) {
+        return Some("Project has finished");
+    }
+    
+    if amount <= 0 {
+        return Some("Investment amount must be positive");
+    }
+    
+    if !whitelist::allows(env, project, investor) {
+        return Some("Investor not whitelisted");
+    }
+    
+    #[cfg(feature = "investment-limits")]
+    if project.min_investment.is_some_and(|min_investment| amount < min_investment) {
+        return Some("Investment below project minimum");
+    }
+    
+    #[cfg(feature = "investment-limits")]
+    if !within_investor_maximum(env, project, investor, amount) {
+        return Some("Investment exceeds per-investor maximum");
+    }
+    
+    None
+}
+
+// Panic unless `investor` may invest `amount` in the project right now
+pub fn check_investment(env: &Env, project: &Project, investor: &Address, amount: i128) {
+    if let Some(refusal) = investment_refusal(env, project, investor, amount) {
+        panic!("{}", refusal);
+    }
+}
+
+// Whether another `amount` keeps the investor within the project's maximum;
+// a total too large to represent is over any maximum
+#[cfg(any(feature = "commitments", feature = "investment-limits"))]
+pub fn within_investor_maximum(env: &Env, project: &Project, investor: &Address, amount: i128) -> bool {
+    let current: i128 = storage::get(env, &DataKey::InvestorAmount(project.id, investor.clone()))
+        .unwrap_or(0);
+    project.max_per_investor
+        .is_none_or(|max| math::add(current, amount).is_ok_and(|total| total <= max))
+}
+
+// Book an investment whose tokens the contract already holds
+pub fn record_investment(env: &Env, project: &mut Project, investor: &Address, amount: i128) -> Result<(), ContractError> {
+    book_investment(env, project, investor, amount, false)?;
+    
+    Ok(())
+}
+
+// Book a sponsor's match of someone else's investment; it is a position
+// like any other, but not a contribution to a quadratic funding round
+#[cfg(feature = "matching")]
+pub fn record_match(env: &Env, project: &mut Project, sponsor: &Address, amount: i128) -> Result<(), ContractError> {
+    book_investment(env, project, sponsor, amount, true)?;
+    
+    Ok(())
+}
+
+fn book_investment(env: &Env, project: &mut Project, investor: &Address, amount: i128, matched: bool) -> Result<(), ContractError> {
+    let project_id = project.id;
+    
+    // The insurance premium comes off the top of what is booked
+    let amount = math::sub(amount, insurance::collect_premium(env, project, amount)?)?;
+    
+    // Update project raised amount, a USD goal being met at the prices
+    // invested at
+    let usd_goal_reached = usd_goals::record_usd(env, project, amount)?;
+    project.raised = math::add(project.raised, amount)?;
+    project.escrow_balance = math::add(project.escrow_balance, amount)?;
+    let goal_reached = usd_goal_reached || project.raised >= project.goal_amount;
+    if project.status == ProjectStatus::Funding && goal_reached {
+        project.status = ProjectStatus::InProgress;
+    }
+    storage::set_project(env, project);
+    try_update_stats(env, |stats| {
+        stats.total_raised = math::add(stats.total_raised, amount)?;
+        stats.value_locked = math::add(stats.value_locked, amount)?;
+        Ok(())
+    })?;
+    
+    // Track investor contribution
+    let investor_key = DataKey::InvestorAmount(project_id, investor.clone());
+    let current: i128 = storage::get(env, &investor_key).unwrap_or(0);
+    settle_payouts(env, project_id, investor)?;
+    mark_revenue_activity(env, project_id, investor);
+    storage::set(env, &investor_key, &math::add(current, amount)?);
+    checkpoint_payouts(env, project_id, investor)?;
+    #[cfg(feature = "listings")]
+    index_position(env, investor, project_id);
+    rewards::reward(env, project_id, investor, amount)?;
+    if !matched {
+        matching::record_contribution(env, project_id, investor, amount)?;
+    }
+    
+    // Add to investments list
+    let investments_key = DataKey::ProjectInvestments(project_id);
+    let mut investments: Vec<Investment> = storage::get(env, &investments_key).unwrap_or(Vec::new(env));
+    
+    investments.push_back(Investment {
+        investor: investor.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+        from: None,
+    });
+    
+    storage::set(env, &investments_key, &investments);
+    
+    shares::mint_shares(env, project, investor, amount);
+    
+    pool::deposit_idle(env, project)?;
+    
+    log!(env, "Investment of {} in project {} from {}", amount, project_id, investor);
+    
+    hooks::on_invest(env, project_id, investor, amount);
+    matching::apply_sponsor_match(env, project, investor, amount)?;
+    
+    Ok(())
+}
+
+// Add a project to the investor's portfolio index unless already listed
+#[cfg(feature = "listings")]
+pub fn index_position(env: &Env, investor: &Address, project_id: u32) {
+    let listed_key = ListingKey::InvestorProjectListed(investor.clone(), project_id);
+    if storage::has(env, &listed_key) {
+        return;
+    }
+    
+    let count_key = ListingKey::InvestorProjectCount(investor.clone());
+    if !storage::has(env, &count_key) {
+        update_stats(env, |stats| stats.investors += 1);
+    }
+    storage::list_push(
+        env,
+        &count_key,
+        |index| ListingKey::InvestorProject(investor.clone(), index),
+        &project_id,
+    );
+    storage::set(env, &listed_key, &true);
+}
+
+pub fn invest(env: Env, project_id: u32, investor: Address, amount: i128, referrer: Option<Address>) -> Result<(), ContractError> {
+    investor.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    check_investment(&env, &project, &investor, amount);
+    
+    // Transfer tokens to contract
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&investor, &env.current_contract_address(), &amount);
+    
+    record_investment(&env, &mut project, &investor, amount)?;
+    if let Some(referrer) = referrer {
+        referrals::accrue(&env, &project, &investor, &referrer, amount)?;
+    }
+    commitments::execute_commitments(&env, &mut project)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "batch-invest")]
+pub fn invest_batch(env: Env, investor: Address, investments: Vec<(u32, i128)>) -> Result<(), ContractError> {
+    investor.require_auth();
+    
+    if investments.is_empty() {
+        panic!("Batch is empty");
+    }
+    
+    if investments.len() > MAX_BATCH_INVESTMENTS {
+        panic!("Too many investments in batch");
+    }
+    
+    // Each leg is checked and booked in turn; a failing leg panics and
+    // rolls back the ones before it
+    for (project_id, amount) in investments.iter() {
+        let mut project = storage::project(&env, project_id);
+        
+        check_investment(&env, &project, &investor, amount);
+        
+        let token_client = token::Client::new(&env, &project.token);
+        token_client.transfer(&investor, &env.current_contract_address(), &amount);
+        
+        record_investment(&env, &mut project, &investor, amount)?;
+        commitments::execute_commitments(&env, &mut project)?;
+    }
+    
+    Ok(())
+}
+
+#[cfg(feature = "invest-from")]
+pub fn invest_from(env: Env, project_id: u32, spender: Address, investor: Address, amount: i128) -> Result<(), ContractError> {
+    spender.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    check_investment(&env, &project, &investor, amount);
+    
+    // Pull the investor's tokens under the allowance they gave the spender
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer_from(&spender, &investor, &env.current_contract_address(), &amount);
+    
+    record_investment(&env, &mut project, &investor, amount)?;
+    commitments::execute_commitments(&env, &mut project)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "listings")]
+pub fn get_investor_portfolio(env: Env, investor: Address, start: u32, limit: u32) -> Vec<(u32, i128)> {
+    let projects: Vec<u32> = storage::list_page(
+        &env,
+        &ListingKey::InvestorProjectCount(investor.clone()),
+        |index| ListingKey::InvestorProject(investor.clone(), index),
+        start,
+        limit,
+    );
+    
+    let mut portfolio = Vec::new(&env);
+    for project_id in projects.iter() {
+        let amount = StellarBridgeContract::get_investor_amount(env.clone(), project_id, investor.clone());
+        if amount > 0 {
+            portfolio.push_back((project_id, amount));
+        }
+    }
+    portfolio
+}
+
+pub fn get_investor_amount(env: Env, project_id: u32, investor: Address) -> i128 {
+    storage::get(&env, &DataKey::InvestorAmount(project_id, investor)).unwrap_or(0)
+}
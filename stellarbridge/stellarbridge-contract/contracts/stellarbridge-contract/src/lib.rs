@@ -1,185 +1,241 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, Map, BytesN, token, log};
-
-// Milestone status
-#[contracttype]
-#[derive(Clone, PartialEq)]
-pub enum MilestoneStatus {
-    Pending,
-    EvidenceSubmitted,
-    Verified,
-    Rejected,
-}
-
-// Individual milestone
-#[contracttype]
-#[derive(Clone)]
-pub struct Milestone {
-    pub amount: i128,
-    pub deadline: u64,
-    pub status: MilestoneStatus,
-    pub evidence_hash: Option<BytesN<32>>,
-}
-
-// Project structure
-#[contracttype]
-#[derive(Clone)]
-pub struct Project {
-    pub id: u32,
-    pub owner: Address,
-    pub goal_amount: i128,
-    pub raised: i128,
-    pub milestones: Vec<Milestone>,
-    pub active: bool,
-}
-
-// Investment record
-#[contracttype]
-#[derive(Clone)]
-pub struct Investment {
-    pub investor: Address,
-    pub amount: i128,
-    pub timestamp: u64,
-}
-
-// Storage keys
-#[contracttype]
-pub enum DataKey {
-    ProjectCounter,
-    Project(u32),
-    ProjectInvestments(u32),
-    InvestorAmount(u32, Address),
-    Verifier,
-    Token,
-}
+#[cfg(any(feature = "committee", feature = "evidence-access"))]
+use soroban_sdk::Bytes;
+#[cfg(any(
+    feature = "fees",
+    feature = "listing-review",
+    feature = "listings",
+    feature = "verifier-pool"
+))]
+use soroban_sdk::Symbol;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+#[cfg(any(
+    feature = "fees",
+    feature = "insurance",
+    feature = "late-policy",
+    feature = "matching",
+    feature = "payout-splits",
+    feature = "yield-pool"
+))]
+use stellarbridge_core::splitter;
+use stellarbridge_core::{math, schedule};
+
+mod admin;
+#[cfg(feature = "disputes")]
+mod appeals;
+#[cfg(feature = "archive")]
+mod archive;
+#[cfg(feature = "audits")]
+mod audits;
+#[cfg(feature = "co-verification")]
+mod co_verification;
+#[cfg(feature = "committee")]
+mod committee;
+#[cfg(feature = "commitments")]
+mod commitments;
+#[cfg(feature = "config-history")]
+mod config_history;
+#[cfg(feature = "delegation")]
+mod delegation;
+mod disabled;
+#[cfg(feature = "disputes")]
+mod disputes;
+mod errors;
+#[cfg(feature = "escalation")]
+mod escalation;
+mod escrow;
+#[cfg(feature = "evidence-access")]
+mod evidence_access;
+#[cfg(any(feature = "fees", feature = "insurance"))]
+mod fee;
+#[cfg(feature = "hooks")]
+mod hooks;
+#[cfg(feature = "insurance")]
+mod insurance;
+#[cfg(feature = "intents")]
+mod intents;
+mod investments;
+#[cfg(feature = "matching")]
+mod matching;
+mod milestones;
+#[cfg(feature = "yield-pool")]
+mod pool;
+#[cfg(feature = "preflight")]
+mod preflight;
+mod projects;
+#[cfg(feature = "referrals")]
+mod referrals;
+mod refunds;
+#[cfg(feature = "auto-reinvest")]
+mod reinvest;
+#[cfg(feature = "revenue")]
+mod revenue;
+#[cfg(feature = "listing-review")]
+mod review;
+#[cfg(feature = "rewards")]
+mod rewards;
+#[cfg(feature = "share-token")]
+mod shares;
+#[cfg(feature = "staking")]
+mod staking;
+mod storage;
+#[cfg(feature = "position-transfers")]
+mod transfers;
+mod types;
+#[cfg(feature = "usd-goals")]
+mod usd_goals;
+#[cfg(feature = "verifier-pool")]
+mod verifiers;
+#[cfg(feature = "vesting")]
+mod vesting;
+#[cfg(feature = "whitelist")]
+mod whitelist;
+#[cfg(all(feature = "preflight", any(test, feature = "testutils")))]
+pub mod testutils;
+
+pub use errors::ContractError;
+pub use types::*;
+
+#[cfg(not(feature = "co-verification"))]
+use disabled::co_verification;
+#[cfg(not(feature = "commitments"))]
+use disabled::commitments;
+#[cfg(not(feature = "config-history"))]
+use disabled::config_history;
+#[cfg(all(feature = "preflight", not(feature = "delegation")))]
+use disabled::delegation;
+#[cfg(not(feature = "disputes"))]
+use disabled::{appeals, disputes};
+#[cfg(not(feature = "escalation"))]
+use disabled::escalation;
+#[cfg(not(feature = "hooks"))]
+use disabled::hooks;
+#[cfg(not(feature = "insurance"))]
+use disabled::insurance;
+#[cfg(not(feature = "listing-review"))]
+use disabled::review;
+#[cfg(not(feature = "matching"))]
+use disabled::matching;
+#[cfg(not(feature = "referrals"))]
+use disabled::referrals;
+#[cfg(not(feature = "auto-reinvest"))]
+use disabled::reinvest;
+#[cfg(not(feature = "revenue"))]
+use disabled::revenue;
+#[cfg(not(feature = "rewards"))]
+use disabled::rewards;
+#[cfg(not(feature = "share-token"))]
+use disabled::shares;
+#[cfg(not(feature = "staking"))]
+use disabled::staking;
+#[cfg(not(feature = "usd-goals"))]
+use disabled::usd_goals;
+#[cfg(not(feature = "verifier-pool"))]
+use disabled::verifiers;
+#[cfg(not(feature = "vesting"))]
+use disabled::vesting;
+#[cfg(not(feature = "whitelist"))]
+use disabled::whitelist;
+#[cfg(not(feature = "yield-pool"))]
+use disabled::pool;
+
+// Fixed-point scale for per-unit payout accounting
+const PAYOUT_SCALE: i128 = 1_000_000_000_000;
+
+// Per-call work caps that keep every entrypoint well inside the resource
+// budget: milestone, recipient and commitment loops are bounded up front,
+// refund payouts stop after MAX_REFUND_STEPS history entries and resume on
+// the next call, batch investments take at most MAX_BATCH_INVESTMENTS legs
+// projects define at most MAX_REWARD_TIERS reward tiers, a matching round
+// takes at most MAX_ROUND_PROJECTS projects, a project keeps at most
+// MAX_AUDITS audits, the verifier pool holds at most MAX_VERIFIER_POOL
+// verifiers, a BLS committee at most MAX_COMMITTEE_SIZE members, one per
+// bit of the participation bitmap, and at most MAX_HOOKS observer hooks are
+// called on each event. The recipient cap is `splitter::MAX_RECIPIENTS`.
+const MAX_MILESTONES: u32 = 20;
+const MAX_REFUND_STEPS: u32 = 50;
+#[cfg(any(
+    feature = "audits",
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+const MAX_PAGE_SIZE: u32 = 50;
+#[cfg(feature = "commitments")]
+const MAX_COMMITMENTS: u32 = 10;
+#[cfg(feature = "batch-invest")]
+const MAX_BATCH_INVESTMENTS: u32 = 10;
+#[cfg(feature = "rewards")]
+const MAX_REWARD_TIERS: u32 = 10;
+#[cfg(feature = "matching")]
+const MAX_ROUND_PROJECTS: u32 = 20;
+#[cfg(feature = "audits")]
+const MAX_AUDITS: u32 = 20;
+#[cfg(feature = "verifier-pool")]
+const MAX_VERIFIER_POOL: u32 = 20;
+#[cfg(feature = "committee")]
+const MAX_COMMITTEE_SIZE: u32 = 64;
+#[cfg(feature = "hooks")]
+const MAX_HOOKS: u32 = 5;
+
+#[cfg(feature = "vesting")]
+const SECONDS_PER_DAY: u64 = 86_400;
+
+// Oldest oracle price, in seconds, an investment is converted to USD at
+#[cfg(feature = "usd-goals")]
+const MAX_PRICE_AGE: u64 = 900;
+
+// Ledgers an archive summary lives for unless the admin configures otherwise,
+// about 180 days at 5 second ledgers
+const DEFAULT_ARCHIVE_TTL: u32 = 3_110_400;
+
+// Upper bounds on metadata sizes, in bytes
+const MAX_NAME_LEN: u32 = 64;
+const MAX_URI_LEN: u32 = 256;
+#[cfg(feature = "evidence-access")]
+const MAX_ENVELOPE_LEN: u32 = 512;
+
+// Most tags a project's metadata can carry
+const MAX_TAGS: u32 = 5;
+
+// Category whose fee strategy applies to projects listed without metadata
+#[cfg(feature = "fees")]
+const DEFAULT_CATEGORY: &str = "default";
 
 #[contract]
 pub struct StellarBridgeContract;
 
 #[contractimpl]
 impl StellarBridgeContract {
-    
     /// Initialize contract with verifier and token
     pub fn initialize(env: Env, verifier: Address, token: Address) {
-        if env.storage().instance().has(&DataKey::Verifier) {
-            panic!("Contract already initialized");
-        }
-        
-        verifier.require_auth();
-        
-        env.storage().instance().set(&DataKey::Verifier, &verifier);
-        env.storage().instance().set(&DataKey::Token, &token);
-        env.storage().instance().set(&DataKey::ProjectCounter, &0u32);
-        
-        log!(&env, "Contract initialized with verifier: {}", verifier);
-    }
-    
-    /// Create a new project with milestones
+        admin::initialize(env, verifier, token)
+    }
+    
+    /// Hand over admin rights (admin only)
+    pub fn set_admin(env: Env, new_admin: Address) {
+        admin::set_admin(env, new_admin)
+    }
+    
+    /// Create a new project with milestones, raising in `token`; one listed
+    /// without metadata waits for listing approval before taking investment
     pub fn create_project(
         env: Env,
         owner: Address,
+        token: Address,
         goal_amount: i128,
         milestone_amounts: Vec<i128>,
         milestone_deadlines: Vec<u64>,
-    ) -> u32 {
-        owner.require_auth();
-        
-        if milestone_amounts.len() != milestone_deadlines.len() {
-            panic!("Milestone amounts and deadlines must match");
-        }
-        
-        let mut total_milestone_amount: i128 = 0;
-        let mut milestones: Vec<Milestone> = Vec::new(&env);
-        
-        for i in 0..milestone_amounts.len() {
-            let amount = milestone_amounts.get(i).unwrap();
-            let deadline = milestone_deadlines.get(i).unwrap();
-            
-            total_milestone_amount += amount;
-            
-            milestones.push_back(Milestone {
-                amount,
-                deadline,
-                status: MilestoneStatus::Pending,
-                evidence_hash: None,
-            });
-        }
-        
-        if total_milestone_amount > goal_amount {
-            panic!("Total milestone amount exceeds goal");
-        }
-        
-        let mut counter: u32 = env.storage().instance().get(&DataKey::ProjectCounter).unwrap_or(0);
-        counter += 1;
-        
-        let project = Project {
-            id: counter,
-            owner: owner.clone(),
-            goal_amount,
-            raised: 0,
-            milestones,
-            active: true,
-        };
-        
-        env.storage().instance().set(&DataKey::Project(counter), &project);
-        env.storage().instance().set(&DataKey::ProjectCounter, &counter);
-        
-        log!(&env, "Project {} created by {}", counter, owner);
-        
-        counter
-    }
-    
-    /// Invest in a project
-    pub fn invest(env: Env, project_id: u32, investor: Address, amount: i128) {
-        investor.require_auth();
-        
-        let mut project: Project = env.storage()
-            .instance()
-            .get(&DataKey::Project(project_id))
-            .expect("Project not found");
-        
-        if !project.active {
-            panic!("Project is not active");
-        }
-        
-        if amount <= 0 {
-            panic!("Investment amount must be positive");
-        }
-        
-        let token_address: Address = env.storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("Token not set");
-        
-        // Transfer tokens to contract
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&investor, &env.current_contract_address(), &amount);
-        
-        // Update project raised amount
-        project.raised += amount;
-        env.storage().instance().set(&DataKey::Project(project_id), &project);
-        
-        // Track investor contribution
-        let investor_key = DataKey::InvestorAmount(project_id, investor.clone());
-        let current: i128 = env.storage().instance().get(&investor_key).unwrap_or(0);
-        env.storage().instance().set(&investor_key, &(current + amount));
-        
-        // Add to investments list
-        let investments_key = DataKey::ProjectInvestments(project_id);
-        let mut investments: Vec<Investment> = env.storage()
-            .instance()
-            .get(&investments_key)
-            .unwrap_or(Vec::new(&env));
-        
-        investments.push_back(Investment {
-            investor: investor.clone(),
-            amount,
-            timestamp: env.ledger().timestamp(),
-        });
-        
-        env.storage().instance().set(&investments_key, &investments);
-        
-        log!(&env, "Investment of {} in project {} from {}", amount, project_id, investor);
+        metadata: Option<ProjectMetadata>,
+    ) -> Result<u32, ContractError> {
+        projects::create_project(env, owner, token, goal_amount, milestone_amounts, milestone_deadlines, metadata)
+    }
+    
+    /// Invest in a project, crediting `referrer` with a referral reward when
+    /// the project runs a referral program
+    pub fn invest(env: Env, project_id: u32, investor: Address, amount: i128, referrer: Option<Address>) -> Result<(), ContractError> {
+        investments::invest(env, project_id, investor, amount, referrer)
     }
     
     /// Submit evidence for a milestone
@@ -189,171 +245,1365 @@ impl StellarBridgeContract {
         milestone_index: u32,
         evidence_hash: BytesN<32>,
     ) {
-        let mut project: Project = env.storage()
-            .instance()
-            .get(&DataKey::Project(project_id))
-            .expect("Project not found");
-        
-        project.owner.require_auth();
-        
-        if milestone_index >= project.milestones.len() {
-            panic!("Invalid milestone index");
-        }
-        
-        let mut milestone = project.milestones.get(milestone_index).unwrap();
-        
-        if milestone.status != MilestoneStatus::Pending {
-            panic!("Milestone not in pending state");
-        }
-        
-        milestone.evidence_hash = Some(evidence_hash.clone());
-        milestone.status = MilestoneStatus::EvidenceSubmitted;
-        
-        project.milestones.set(milestone_index, milestone);
-        env.storage().instance().set(&DataKey::Project(project_id), &project);
-        
-        log!(&env, "Evidence submitted for project {} milestone {}", project_id, milestone_index);
-    }
-    
-    /// Verify a milestone (verifier only)
+        milestones::submit_evidence(env, project_id, milestone_index, evidence_hash)
+    }
+    
+    /// Verify a milestone (the project's verifier only); `reason_hash`
+    /// optionally points to the verifier's comments, which owners need most
+    /// for rejections. Approving a milestone above the co-verification
+    /// threshold only releases it once the admin has co-verified too.
     pub fn verify_milestone(
         env: Env,
         project_id: u32,
         milestone_index: u32,
         approved: bool,
-    ) {
-        let verifier: Address = env.storage()
-            .instance()
-            .get(&DataKey::Verifier)
-            .expect("Verifier not set");
-        
-        verifier.require_auth();
-        
-        let mut project: Project = env.storage()
-            .instance()
-            .get(&DataKey::Project(project_id))
-            .expect("Project not found");
-        
-        if milestone_index >= project.milestones.len() {
-            panic!("Invalid milestone index");
-        }
-        
-        let mut milestone = project.milestones.get(milestone_index).unwrap();
-        
-        if milestone.status != MilestoneStatus::EvidenceSubmitted {
-            panic!("No evidence submitted for this milestone");
-        }
-        
-        if approved {
-            milestone.status = MilestoneStatus::Verified;
-            
-            // Release funds to project owner
-            let token_address: Address = env.storage()
-                .instance()
-                .get(&DataKey::Token)
-                .expect("Token not set");
-            
-            let token_client = token::Client::new(&env, &token_address);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &project.owner,
-                &milestone.amount
-            );
-            
-            log!(&env, "Milestone {} verified for project {}", milestone_index, project_id);
-        } else {
-            milestone.status = MilestoneStatus::Rejected;
-            log!(&env, "Milestone {} rejected for project {}", milestone_index, project_id);
-        }
-        
-        project.milestones.set(milestone_index, milestone);
-        env.storage().instance().set(&DataKey::Project(project_id), &project);
-    }
-    
-    /// Trigger refund if milestone deadline expired
-    pub fn trigger_refund(env: Env, project_id: u32, milestone_index: u32) {
-        let mut project: Project = env.storage()
-            .instance()
-            .get(&DataKey::Project(project_id))
-            .expect("Project not found");
-        
-        if milestone_index >= project.milestones.len() {
-            panic!("Invalid milestone index");
-        }
-        
-        let milestone = project.milestones.get(milestone_index).unwrap();
-        
-        if env.ledger().timestamp() < milestone.deadline {
-            panic!("Milestone deadline not yet expired");
-        }
-        
-        if milestone.status == MilestoneStatus::Verified {
-            panic!("Milestone already verified");
-        }
-        
-        // Calculate refund amount (proportional to unverified milestones)
-        let mut unverified_amount: i128 = 0;
-        for i in milestone_index..project.milestones.len() {
-            let m = project.milestones.get(i).unwrap();
-            if m.status != MilestoneStatus::Verified {
-                unverified_amount += m.amount;
-            }
-        }
-        
-        // Refund proportionally to investors
-        let investments_key = DataKey::ProjectInvestments(project_id);
-        let investments: Vec<Investment> = env.storage()
-            .instance()
-            .get(&investments_key)
-            .unwrap_or(Vec::new(&env));
-        
-        let token_address: Address = env.storage()
-            .instance()
-            .get(&DataKey::Token)
-            .expect("Token not set");
-        
-        let token_client = token::Client::new(&env, &token_address);
-        
-        for i in 0..investments.len() {
-            let investment = investments.get(i).unwrap();
-            let refund = (investment.amount * unverified_amount) / project.raised;
-            
-            if refund > 0 {
-                token_client.transfer(
-                    &env.current_contract_address(),
-                    &investment.investor,
-                    &refund
-                );
-            }
-        }
-        
-        project.active = false;
-        env.storage().instance().set(&DataKey::Project(project_id), &project);
-        
-        log!(&env, "Refund triggered for project {}", project_id);
+        reason_hash: Option<BytesN<32>>,
+        expected_version: u32,
+    ) -> Result<(), ContractError> {
+        milestones::verify_milestone(env, project_id, milestone_index, approved, reason_hash, expected_version)
+    }
+    
+    /// Trigger refund if milestone deadline expired (the admin or an investor
+    /// of the project). Large investor lists are paid out over several calls:
+    /// while `ResumeRequired` is returned the project stays locked and
+    /// calling again, by anyone, continues where it stopped.
+    pub fn trigger_refund(env: Env, caller: Address, project_id: u32, milestone_index: u32) -> Result<RefundProgress, ContractError> {
+        refunds::trigger_refund(env, caller, project_id, milestone_index)
+    }
+    
+    /// Wind down a project before any milestone is verified (owner only);
+    /// everything still held in escrow becomes claimable through claim_refund
+    pub fn cancel_project(env: Env, project_id: u32) -> Result<(), ContractError> {
+        refunds::cancel_project(env, project_id)
+    }
+    
+    /// Withdraw the investor's share of refundable funds from rejected milestones
+    /// or a cancelled project
+    pub fn claim_refund(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        refunds::claim_refund(env, project_id, investor)
+    }
+    
+    /// Withdraw the investor's share of what a finished project raised over
+    /// its goal
+    pub fn claim_surplus(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        refunds::claim_surplus(env, project_id, investor)
+    }
+    
+    /// Get a single milestone of a project
+    pub fn get_milestone(env: Env, project_id: u32, milestone_index: u32) -> Milestone {
+        milestones::get_milestone(env, project_id, milestone_index)
+    }
+    
+    /// Get the number of milestones a project has
+    pub fn get_milestone_count(env: Env, project_id: u32) -> u32 {
+        milestones::get_milestone_count(env, project_id)
     }
     
     /// Get project details
     pub fn get_project(env: Env, project_id: u32) -> Project {
-        env.storage()
-            .instance()
-            .get(&DataKey::Project(project_id))
-            .expect("Project not found")
+        projects::get_project(env, project_id)
+    }
+    
+    /// Get project metadata, if any was set
+    pub fn get_project_metadata(env: Env, project_id: u32) -> Option<ProjectMetadata> {
+        projects::get_project_metadata(env, project_id)
     }
     
     /// Get investor amount for a project
     pub fn get_investor_amount(env: Env, project_id: u32, investor: Address) -> i128 {
-        env.storage()
-            .instance()
-            .get(&DataKey::InvestorAmount(project_id, investor))
-            .unwrap_or(0)
+        investments::get_investor_amount(env, project_id, investor)
+    }
+    
+    /// Get the refund an investor can currently claim
+    pub fn get_claimable_refund(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        refunds::get_claimable_refund(env, project_id, investor)
+    }
+    
+    /// Get the share of a finished project's surplus an investor can
+    /// currently claim
+    pub fn get_claimable_surplus(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        refunds::get_claimable_surplus(env, project_id, investor)
+    }
+    
+    /// Check whether trigger_refund has already paid an investor their
+    /// refund from a project; each investor is paid at most once
+    pub fn is_refund_paid(env: Env, project_id: u32, investor: Address) -> bool {
+        refunds::is_refund_paid(env, project_id, investor)
+    }
+    
+    /// Check whether new projects may raise in a token
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        admin::is_token_allowed(env, token)
+    }
+    
+    /// Check whether an address is the global verifier or approved, here or
+    /// in the verifier registry, to verify individual projects
+    pub fn is_verifier(env: Env, verifier: Address) -> bool {
+        admin::is_verifier(env, verifier)
+    }
+    
+    /// Get the deployment's global settings
+    pub fn get_config(env: Env) -> Config {
+        admin::get_config(env)
     }
     
     /// Get total number of projects
     pub fn get_project_count(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&DataKey::ProjectCounter)
-            .unwrap_or(0)
+        projects::get_project_count(env)
+    }
+}
+
+// Archival of finished projects
+#[cfg(feature = "archive")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set how many ledgers archive summaries are kept for (admin only);
+    /// capped at the network's maximum entry lifetime
+    pub fn set_archive_ttl(env: Env, ledgers: u32) {
+        admin::set_archive_ttl(env, ledgers)
+    }
+    
+    /// Archive a finished project (admin only): a project that was wound down,
+    /// or whose milestones are all verified or finally rejected. Its history
+    /// is replaced by a summary in temporary storage; claims stay open.
+    pub fn archive_project(env: Env, project_id: u32) -> Result<(), ContractError> {
+        archive::archive_project(env, project_id)
+    }
+    
+    /// Get an archived project's summary while it is still retained
+    pub fn get_project_archive(env: Env, project_id: u32) -> Option<ProjectArchive> {
+        archive::get_project_archive(env, project_id)
+    }
+}
+
+// Audits by independent auditors
+#[cfg(feature = "audits")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Approve (or stop approving) an address as an independent auditor
+    /// (admin only); audits it already posted stay on record
+    pub fn set_auditor_approval(env: Env, auditor: Address, approved: bool) {
+        admin::set_auditor_approval(env, auditor, approved)
+    }
+    
+    /// Post an audit attestation on a project, or on one of its milestones,
+    /// anchoring the report by its hash (approved auditor only)
+    pub fn post_audit(
+        env: Env,
+        auditor: Address,
+        project_id: u32,
+        milestone_index: Option<u32>,
+        report_hash: BytesN<32>,
+        verdict: AuditVerdict,
+    ) {
+        audits::post_audit(env, auditor, project_id, milestone_index, report_hash, verdict)
+    }
+    
+    /// Get up to `limit` of the audits posted on a project, oldest first,
+    /// starting at `start`
+    pub fn get_audits(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<Audit> {
+        audits::get_audits(env, project_id, start, limit)
+    }
+    
+    /// Check whether an address is approved to post audits
+    pub fn is_auditor(env: Env, auditor: Address) -> bool {
+        admin::is_auditor(env, auditor)
+    }
+}
+
+// Claims reinvested in another project
+#[cfg(feature = "auto-reinvest")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Have refunds and revenue the investor claims invested in another
+    /// project instead of paid out (investor only); `None` pays them out.
+    /// A claim the target cannot take, or in a different token, is paid out.
+    pub fn set_auto_reinvest(env: Env, investor: Address, target: Option<u32>) {
+        reinvest::set_auto_reinvest(env, investor, target)
+    }
+    
+    /// Get the project an investor's claims are reinvested in, if any
+    pub fn get_auto_reinvest(env: Env, investor: Address) -> Option<u32> {
+        reinvest::get_auto_reinvest(env, investor)
+    }
+}
+
+// Batch investments
+#[cfg(feature = "batch-invest")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Invest in several projects at once under a single investor auth;
+    /// the whole batch fails if any investment in it does
+    pub fn invest_batch(env: Env, investor: Address, investments: Vec<(u32, i128)>) -> Result<(), ContractError> {
+        investments::invest_batch(env, investor, investments)
+    }
+}
+
+// Admin co-verification of large milestones
+#[cfg(feature = "co-verification")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the milestone amount above which approvals need the admin's
+    /// sign-off as well as the verifier's, approvals the contract takes
+    /// itself included (admin only); None lets the verifier approve any
+    /// milestone alone
+    pub fn set_co_verification_threshold(env: Env, threshold: Option<i128>) {
+        admin::set_co_verification_threshold(env, threshold)
+    }
+    
+    /// Add the admin's sign-off to approving a milestone above the
+    /// co-verification threshold, before or after the verifier's (admin
+    /// only)
+    pub fn co_verify_milestone(env: Env, project_id: u32, milestone_index: u32, expected_version: u32) -> Result<(), ContractError> {
+        co_verification::co_verify_milestone(env, project_id, milestone_index, expected_version)
+    }
+    
+    /// Get the sign-offs collected so far on approving a large milestone,
+    /// if any
+    pub fn get_co_verification(env: Env, project_id: u32, milestone_index: u32) -> Option<CoVerification> {
+        co_verification::get_co_verification(env, project_id, milestone_index)
+    }
+}
+
+// Conditional and recurring investments
+#[cfg(feature = "commitments")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Escrow `amount` now to invest it once the project has raised at least
+    /// `min_raised` from others, provided that happens by `expires_at`;
+    /// otherwise the escrow is returned through `settle_commitments`
+    pub fn commit_investment(
+        env: Env,
+        project_id: u32,
+        investor: Address,
+        amount: i128,
+        min_raised: i128,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        commitments::commit_investment(env, project_id, investor, amount, min_raised, expires_at)
+    }
+    
+    /// Return the escrow of every commitment that expired unexecuted, or of
+    /// all of them once the project is no longer active; returns how many
+    /// were returned. Callable by anyone.
+    pub fn settle_commitments(env: Env, project_id: u32) -> u32 {
+        commitments::settle_commitments(env, project_id)
+    }
+    
+    /// Invest `amount` every `interval` seconds, starting now, out of an
+    /// allowance the investor gives this contract on the project token,
+    /// until cancelled (investor only)
+    pub fn commit_recurring(env: Env, project_id: u32, investor: Address, amount: i128, interval: u64) {
+        commitments::commit_recurring(env, project_id, investor, amount, interval)
+    }
+    
+    /// Stop an investor's recurring commitment to a project (investor only)
+    pub fn cancel_recurring(env: Env, project_id: u32, investor: Address) {
+        commitments::cancel_recurring(env, project_id, investor)
+    }
+    
+    /// Pull and invest every recurring commitment that is due, ending those
+    /// the project refuses or the allowance no longer covers; returns how
+    /// many were invested. Callable by anyone.
+    pub fn execute_due_commitments(env: Env, project_id: u32) -> Result<u32, ContractError> {
+        commitments::execute_due_commitments(env, project_id)
+    }
+    
+    /// Get the commitments still waiting for their condition on an active
+    /// project
+    pub fn get_commitments(env: Env, project_id: u32) -> Vec<Commitment> {
+        commitments::get_commitments(env, project_id)
+    }
+    
+    /// Get a project's recurring commitments
+    pub fn get_recurring_commitments(env: Env, project_id: u32) -> Vec<RecurringCommitment> {
+        commitments::get_recurring_commitments(env, project_id)
+    }
+    
+    /// Get the total escrowed by a project's active commitments
+    pub fn get_committed_amount(env: Env, project_id: u32) -> i128 {
+        commitments::get_committed_amount(env, project_id)
+    }
+}
+
+// BLS committee approvals
+#[cfg(feature = "committee")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Register the BLS committee whose aggregate signatures approve
+    /// milestones, as BLS12-381 public keys in G1 with proofs of possession
+    /// checked beforehand, and how many members must sign; an empty list
+    /// disbands it (admin only)
+    pub fn set_bls_committee(env: Env, keys: Vec<BytesN<96>>, threshold: u32) {
+        admin::set_bls_committee(env, keys, threshold)
+    }
+    
+    /// Approve a milestone awaiting verification on the committee's aggregate
+    /// BLS signature, in G2, over `committee_message`; bit i of
+    /// `participants` marks member i as a signer, and at least the threshold
+    /// must have signed. Callable by anyone. The decision is recorded with
+    /// this contract as the verifier.
+    pub fn approve_by_committee(env: Env, project_id: u32, milestone_index: u32, participants: u64, signature: BytesN<192>) -> Result<(), ContractError> {
+        committee::approve_by_committee(env, project_id, milestone_index, participants, signature)
+    }
+    
+    /// Get the message committee members sign to approve a milestone at the
+    /// project's current version
+    pub fn committee_message(env: Env, project_id: u32, milestone_index: u32) -> Bytes {
+        committee::committee_message(env, project_id, milestone_index)
+    }
+}
+
+// History of admin setting changes
+#[cfg(feature = "config-history")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Get up to `limit` admin configuration changes, oldest first, starting at `start`
+    pub fn get_config_history(env: Env, start: u32, limit: u32) -> Vec<ConfigChange> {
+        config_history::get_config_history(env, start, limit)
+    }
+}
+
+// Log of verification decisions
+#[cfg(feature = "decision-log")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Get up to `limit` of a project's verification decisions, oldest first,
+    /// starting at `start`
+    pub fn get_verification_decisions(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<VerificationDecision> {
+        milestones::get_verification_decisions(env, project_id, start, limit)
+    }
+}
+
+// Verification delegated by the verifier
+#[cfg(feature = "delegation")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Hand verification of a project, or of one of its milestones when
+    /// `milestone_index` is set, to `delegate` until `expires_at` (the
+    /// project's verifier only)
+    pub fn delegate_verification(
+        env: Env,
+        project_id: u32,
+        milestone_index: Option<u32>,
+        delegate: Address,
+        expires_at: u64,
+    ) {
+        delegation::delegate_verification(env, project_id, milestone_index, delegate, expires_at)
+    }
+    
+    /// Revoke a delegation made with delegate_verification (the project's
+    /// verifier only)
+    pub fn revoke_delegation(env: Env, project_id: u32, milestone_index: Option<u32>) {
+        delegation::revoke_delegation(env, project_id, milestone_index)
+    }
+    
+    /// Verify a milestone on behalf of the verifier (delegates only)
+    pub fn verify_as_delegate(
+        env: Env,
+        delegate: Address,
+        project_id: u32,
+        milestone_index: u32,
+        approved: bool,
+        reason_hash: Option<BytesN<32>>,
+        expected_version: u32,
+    ) -> Result<(), ContractError> {
+        delegation::verify_as_delegate(env, delegate, project_id, milestone_index, approved, reason_hash, expected_version)
+    }
+}
+
+// Disputes and appeals ruled on by the arbiter
+#[cfg(feature = "disputes")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the account that rules on disputed milestones (admin only)
+    pub fn set_arbiter(env: Env, arbiter: Address) {
+        admin::set_arbiter(env, arbiter)
+    }
+    
+    /// Set how long an approved milestone's release is held for investors
+    /// to dispute (admin only); applies to milestones approved afterwards,
+    /// None releases on approval
+    pub fn set_dispute_window(env: Env, window: Option<u64>) {
+        admin::set_dispute_window(env, window)
+    }
+    
+    /// Set how long the owner has to appeal a rejection that leaves a
+    /// milestone no resubmissions (admin only); applies to rejections made
+    /// afterwards, None makes such rejections final at once
+    pub fn set_appeal_window(env: Env, window: Option<u64>) {
+        admin::set_appeal_window(env, window)
+    }
+    
+    /// Challenge a verified milestone's held release, freezing it until the
+    /// arbiter rules; `bond` is escrowed from the challenger (investor only)
+    pub fn open_dispute(env: Env, project_id: u32, milestone_index: u32, challenger: Address, bond: i128) {
+        disputes::open_dispute(env, project_id, milestone_index, challenger, bond)
+    }
+    
+    /// Rule on a disputed milestone: upholding fails it, refunds its held
+    /// release and returns the bond, dismissing slashes the bond to the
+    /// project owner (arbiter only)
+    pub fn resolve_dispute(env: Env, project_id: u32, milestone_index: u32, upheld: bool) -> Result<(), ContractError> {
+        disputes::resolve_dispute(env, project_id, milestone_index, upheld)
+    }
+    
+    /// Pay out a verified milestone's held release once the dispute window
+    /// has passed without an open dispute. Callable by anyone.
+    pub fn release_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        disputes::release_milestone(env, project_id, milestone_index)
+    }
+    
+    /// Appeal a rejection held open for appeal, with new evidence for the
+    /// arbiter to weigh; a milestone takes one appeal (owner only)
+    pub fn appeal_rejection(env: Env, project_id: u32, milestone_index: u32, appeal_evidence_hash: BytesN<32>) {
+        appeals::appeal_rejection(env, project_id, milestone_index, appeal_evidence_hash)
+    }
+    
+    /// Rule on an appealed rejection: upholding restores the milestone to
+    /// review on the appeal's evidence, dismissing fails it (arbiter only)
+    pub fn resolve_appeal(env: Env, project_id: u32, milestone_index: u32, upheld: bool) -> Result<(), ContractError> {
+        appeals::resolve_appeal(env, project_id, milestone_index, upheld)
+    }
+    
+    /// Settle a rejection whose appeal window has passed without an appeal,
+    /// making it final. Callable by anyone.
+    pub fn finalize_rejection(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        appeals::finalize_rejection(env, project_id, milestone_index)
+    }
+    
+    /// Get the release held back for a verified milestone, if any
+    pub fn get_pending_release(env: Env, project_id: u32, milestone_index: u32) -> Option<PendingRelease> {
+        disputes::get_pending_release(env, project_id, milestone_index)
+    }
+    
+    /// Get the open dispute on a milestone, if any
+    pub fn get_dispute(env: Env, project_id: u32, milestone_index: u32) -> Option<Dispute> {
+        disputes::get_dispute(env, project_id, milestone_index)
+    }
+    
+    /// Get the appeal filed against a milestone's rejection and its ruling,
+    /// if any
+    pub fn get_appeal(env: Env, project_id: u32, milestone_index: u32) -> Option<Appeal> {
+        appeals::get_appeal(env, project_id, milestone_index)
+    }
+}
+
+// Donations to a beneficiary
+#[cfg(feature = "donations")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Make the project a donation, whose contributions are never refunded,
+    /// or refundable again (owner only, before the first investment).
+    /// Donations need a beneficiary: missed or finally rejected milestones
+    /// roll their funds onto the next open milestone, or to the beneficiary
+    /// from the last one, and cancellation, surplus and penalties pay it
+    /// too.
+    pub fn set_refundable(env: Env, project_id: u32, refundable: bool, beneficiary: Option<Address>) {
+        projects::set_refundable(env, project_id, refundable, beneficiary)
+    }
+    
+    /// Roll a donation project's milestone that missed its deadline
+    /// undecided over onto the next open milestone, or pay its funds to the
+    /// beneficiary if it was the last; callable by anyone
+    pub fn roll_over_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        milestones::roll_over_milestone(env, project_id, milestone_index)
+    }
+}
+
+// Escalation of unreviewed milestones
+#[cfg(feature = "escalation")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set how long the verifier has to review submitted evidence before it
+    /// can be escalated (admin only); applies to projects created afterwards,
+    /// None disables escalation
+    pub fn set_verification_window(env: Env, window: Option<u64>) {
+        admin::set_verification_window(env, window)
+    }
+    
+    /// Let owners approve milestones below `threshold` themselves once their
+    /// evidence has gone `challenge_window` seconds without the verifier
+    /// rejecting it (admin only); None turns the fast path off
+    pub fn set_auto_approval(env: Env, threshold: Option<i128>, challenge_window: u64) {
+        admin::set_auto_approval(env, threshold, challenge_window)
+    }
+    
+    /// Choose what happens to evidence left unreviewed past the verification
+    /// window (owner only, before the first investment)
+    pub fn set_escalation(env: Env, project_id: u32, escalation: Escalation) {
+        projects::set_escalation(env, project_id, escalation)
+    }
+    
+    /// Approve a milestone below the auto-approval threshold whose evidence
+    /// has gone unchallenged for the challenge window, releasing it without
+    /// a verifier fee (owner only)
+    pub fn auto_approve_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        escalation::auto_approve_milestone(env, project_id, milestone_index)
+    }
+    
+    /// Escalate evidence the verifier left unreviewed past the verification
+    /// window, per the project's escalation setting; callable by anyone.
+    /// Decisions taken here are recorded with this contract as the verifier.
+    pub fn escalate_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        escalation::escalate_milestone(env, project_id, milestone_index)
+    }
+    
+    /// Vote on an escalated milestone with the weight of the investor's
+    /// position; each investor votes once
+    pub fn vote_on_milestone(env: Env, project_id: u32, milestone_index: u32, investor: Address, approve: bool) {
+        escalation::vote_on_milestone(env, project_id, milestone_index, investor, approve)
+    }
+    
+    /// Invest and vote on open milestone votes in one signature; `votes`
+    /// pairs milestone indexes with whether to approve
+    pub fn invest_and_vote(env: Env, project_id: u32, investor: Address, amount: i128, votes: Vec<(u32, bool)>) -> Result<(), ContractError> {
+        escalation::invest_and_vote(env, project_id, investor, amount, votes)
+    }
+    
+    /// Close an ended milestone vote and apply its outcome; the milestone is
+    /// approved only with more approving than rejecting weight. Callable by anyone.
+    pub fn finalize_milestone_vote(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        escalation::finalize_milestone_vote(env, project_id, milestone_index)
+    }
+    
+    /// Get the open investor vote on a milestone, if any
+    pub fn get_milestone_vote(env: Env, project_id: u32, milestone_index: u32) -> Option<MilestoneVote> {
+        escalation::get_milestone_vote(env, project_id, milestone_index)
+    }
+}
+
+// Access to encrypted evidence
+#[cfg(feature = "evidence-access")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Grant `grantee` access to a milestone's encrypted evidence until
+    /// `expires_at` (owner only); replaces any earlier grant
+    pub fn grant_evidence_access(
+        env: Env,
+        project_id: u32,
+        milestone_index: u32,
+        grantee: Address,
+        key_envelope: Bytes,
+        expires_at: u64,
+    ) {
+        evidence_access::grant_evidence_access(env, project_id, milestone_index, grantee, key_envelope, expires_at)
+    }
+    
+    /// Withdraw a grantee's access to a milestone's evidence (owner only)
+    pub fn revoke_evidence_access(env: Env, project_id: u32, milestone_index: u32, grantee: Address) {
+        evidence_access::revoke_evidence_access(env, project_id, milestone_index, grantee)
+    }
+    
+    /// Get the key envelope granted to `grantee` for a milestone's evidence,
+    /// if the grant exists and has not expired
+    pub fn get_evidence_access(env: Env, project_id: u32, milestone_index: u32, grantee: Address) -> Option<Bytes> {
+        evidence_access::get_evidence_access(env, project_id, milestone_index, grantee)
+    }
+}
+
+// Attestations from an EVM oracle
+#[cfg(feature = "evm-attestation")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Register the uncompressed secp256k1 key of the EVM oracle whose
+    /// attestations are accepted, or stop accepting them with None (admin
+    /// only)
+    pub fn set_evm_attester(env: Env, public_key: Option<BytesN<65>>) {
+        admin::set_evm_attester(env, public_key)
+    }
+    
+    /// Record an EVM oracle's attestation as a pending milestone's evidence
+    /// and decide it on the attested finding; the signature is over the
+    /// keccak-256 of this contract's address and the attestation in XDR and
+    /// must recover to the registered attester key. Callable by anyone. The
+    /// decision is recorded with this contract as the verifier.
+    pub fn submit_evm_attestation(env: Env, attestation: EvmAttestation, signature: BytesN<64>, recovery_id: u32) -> Result<(), ContractError> {
+        milestones::submit_evm_attestation(env, attestation, signature, recovery_id)
+    }
+    
+    /// Get the nonce the EVM attester's next attestation must carry
+    pub fn get_evm_attester_nonce(env: Env) -> u64 {
+        admin::get_evm_attester_nonce(env)
+    }
+}
+
+// Verification and platform fees
+#[cfg(feature = "fees")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the fee paid to the verifier out of each milestone release (admin
+    /// only); applies to projects created afterwards
+    pub fn set_verification_fee(env: Env, fee: VerificationFee) {
+        admin::set_verification_fee(env, fee)
+    }
+    
+    /// Set the platform fee strategy for a category (admin only); applies to
+    /// projects listed in it afterwards, and `Free` clears it. The `default`
+    /// category's strategy applies to projects listed without one
+    pub fn set_category_fee(env: Env, category: Symbol, strategy: FeeStrategy) {
+        admin::set_category_fee(env, category, strategy)
+    }
+    
+    /// Get the platform fee strategy set for a category
+    pub fn get_category_fee(env: Env, category: Symbol) -> FeeStrategy {
+        admin::get_category_fee(env, category)
+    }
+}
+
+// Goal bounds
+#[cfg(feature = "goal-bounds")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the goal range accepted for new projects raising in `token` (admin
+    /// only); passing no bounds removes the restriction
+    pub fn set_goal_bounds(env: Env, token: Address, min: Option<i128>, max: Option<i128>) {
+        admin::set_goal_bounds(env, token, min, max)
+    }
+    
+    /// Get the goal bounds configured for a token, if any
+    pub fn get_goal_bounds(env: Env, token: Address) -> Option<GoalBounds> {
+        admin::get_goal_bounds(env, token)
+    }
+}
+
+// Observer hooks
+#[cfg(feature = "hooks")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the observer hook contracts told of investments, releases and
+    /// refunds through `on_invest`, `on_release` and `on_refund`; a failing
+    /// hook is skipped (admin only)
+    pub fn set_hooks(env: Env, hooks: Vec<Address>) {
+        admin::set_hooks(env, hooks)
+    }
+}
+
+// Platform insurance
+#[cfg(feature = "insurance")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the insurance policy: the premium taken off each investment for
+    /// the pool, and the share of a refunded project's released funds it
+    /// compensates, capped per project (admin only); a zero premium stops
+    /// collecting for the pool
+    pub fn set_insurance_policy(env: Env, premium_bps: u32, coverage_bps: u32, max_coverage: i128) {
+        admin::set_insurance_policy(env, premium_bps, coverage_bps, max_coverage)
+    }
+    
+    /// Claim an investor's share of the insurance compensation for a
+    /// project that ended refunded; returns the amount paid (investor only)
+    pub fn claim_insurance(env: Env, investor: Address, project_id: u32) -> Result<i128, ContractError> {
+        insurance::claim_insurance(env, investor, project_id)
+    }
+    
+    /// Get the insurance pool's balance in a token
+    pub fn get_insurance_pool(env: Env, token: Address) -> i128 {
+        insurance::get_insurance_pool(env, token)
     }
-}
\ No newline at end of file
+    
+    /// Get the compensation reserved for a refunded project, once its first
+    /// claim has been made
+    pub fn get_insurance_claim(env: Env, project_id: u32) -> Option<InsuranceClaim> {
+        insurance::get_insurance_claim(env, project_id)
+    }
+}
+
+// Investments relayed from signed intents
+#[cfg(feature = "intents")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Register the ed25519 key an investor signs investment intents with, or
+    /// clear it with None (the investor only)
+    pub fn set_intent_key(env: Env, investor: Address, public_key: Option<BytesN<32>>) {
+        intents::set_intent_key(env, investor, public_key)
+    }
+    
+    /// Invest on an intent the investor signed off-chain with their intent
+    /// key, over this contract's address and the intent in XDR, out of the
+    /// allowance they gave the contract on the project token, so relayers
+    /// can pay the fees. Callable by anyone.
+    pub fn invest_with_intent(env: Env, intent: InvestmentIntent, signature: BytesN<64>) -> Result<(), ContractError> {
+        intents::invest_with_intent(env, intent, signature)
+    }
+    
+    /// Get the nonce an investor's next investment intent must carry
+    pub fn get_intent_nonce(env: Env, investor: Address) -> u64 {
+        intents::get_intent_nonce(env, investor)
+    }
+}
+
+// Investments out of an allowance
+#[cfg(feature = "invest-from")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Invest on the investor's behalf out of the allowance they gave
+    /// `spender` on the project token (spender auth)
+    pub fn invest_from(env: Env, project_id: u32, spender: Address, investor: Address, amount: i128) -> Result<(), ContractError> {
+        investments::invest_from(env, project_id, spender, investor, amount)
+    }
+}
+
+// Ticket-size limits
+#[cfg(feature = "investment-limits")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set optional ticket-size limits for future investments (owner only)
+    pub fn set_investment_limits(
+        env: Env,
+        project_id: u32,
+        min_investment: Option<i128>,
+        max_per_investor: Option<i128>,
+    ) {
+        projects::set_investment_limits(env, project_id, min_investment, max_per_investor)
+    }
+}
+
+// Late evidence and its fee
+#[cfg(feature = "late-policy")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set how late evidence may be submitted and the fee charged for it
+    /// (admin only); applies to projects created afterwards
+    pub fn set_late_policy(env: Env, grace: u64, max_fee_bps: u32) {
+        admin::set_late_policy(env, grace, max_fee_bps)
+    }
+}
+
+// Review of listings before they take investments
+#[cfg(feature = "listing-review")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Require (or stop requiring) listing approval for new projects in a
+    /// category before they accept investments (admin only)
+    pub fn set_category_review(env: Env, category: Symbol, required: bool) {
+        admin::set_category_review(env, category, required)
+    }
+    
+    /// Sign off on a project pending listing approval (admin only)
+    pub fn approve_listing(env: Env, project_id: u32) {
+        review::approve_listing(env, project_id)
+    }
+}
+
+// Project summaries, and indexes of projects by owner, investor and category
+#[cfg(feature = "listings")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Get a project's summary without its full milestone list
+    pub fn get_project_summary(env: Env, project_id: u32) -> ProjectSummary {
+        projects::get_project_summary(env, project_id)
+    }
+    
+    /// Get summaries of up to `limit` projects in creation order, skipping
+    /// the first `start`
+    pub fn list_projects(env: Env, start: u32, limit: u32) -> Vec<ProjectSummary> {
+        projects::list_projects(env, start, limit)
+    }
+    
+    /// Get the projects an investor holds a position in, with the position's
+    /// amount, in the order they first invested. Pages through up to `limit`
+    /// of the projects they ever held from `start`, leaving out positions
+    /// since transferred away in full
+    pub fn get_investor_portfolio(env: Env, investor: Address, start: u32, limit: u32) -> Vec<(u32, i128)> {
+        investments::get_investor_portfolio(env, investor, start, limit)
+    }
+    
+    /// Get up to `limit` IDs of the projects an owner has created, oldest
+    /// first, starting at `start`
+    pub fn get_projects_by_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        projects::get_projects_by_owner(env, owner, start, limit)
+    }
+    
+    /// Get the projects open for investment within the same window as
+    /// `list_projects`; a page may hold fewer than `limit` entries, so callers
+    /// advance `start` by `limit` rather than by the number returned
+    pub fn list_active_projects(env: Env, start: u32, limit: u32) -> Vec<ProjectSummary> {
+        projects::list_active_projects(env, start, limit)
+    }
+    
+    /// Get the projects listed in a category, in the order they joined it,
+    /// `limit` at a time up to the page size cap
+    pub fn list_projects_by_category(env: Env, category: Symbol, start: u32, limit: u32) -> Vec<ProjectSummary> {
+        projects::list_projects_by_category(env, category, start, limit)
+    }
+    
+    /// Get the projects with `status` within the same window as
+    /// `list_projects`; like `list_active_projects`, a page may hold fewer
+    /// than `limit` entries
+    pub fn list_projects_by_status(env: Env, status: ProjectStatus, start: u32, limit: u32) -> Vec<ProjectSummary> {
+        projects::list_projects_by_status(env, status, start, limit)
+    }
+}
+
+// Matching rounds and sponsor matches
+#[cfg(feature = "matching")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Open a quadratic funding round that matches investments made before
+    /// `ends_at` out of `pool`, escrowed from the sponsor now (sponsor only)
+    pub fn create_matching_round(env: Env, sponsor: Address, token: Address, pool: i128, ends_at: u64) -> u32 {
+        matching::create_matching_round(env, sponsor, token, pool, ends_at)
+    }
+    
+    /// Enter a project raising in the round's token into an open matching
+    /// round (project owner only)
+    pub fn join_matching_round(env: Env, round_id: u32, project_id: u32) {
+        matching::join_matching_round(env, round_id, project_id)
+    }
+    
+    /// Once a round has ended, add each project's quadratic match to its
+    /// open milestones and return the rest of the pool to the sponsor.
+    /// Callable by anyone.
+    pub fn close_matching_round(env: Env, round_id: u32) -> Result<(), ContractError> {
+        matching::close_matching_round(env, round_id)
+    }
+    
+    /// Match investments in a raising project at `ratio_bps` of each, up
+    /// to `cap`, escrowed from the sponsor now; the matches are invested in
+    /// the sponsor's name (sponsor only)
+    pub fn commit_sponsor_match(env: Env, project_id: u32, sponsor: Address, ratio_bps: u32, cap: i128) {
+        matching::commit_sponsor_match(env, project_id, sponsor, ratio_bps, cap)
+    }
+    
+    /// Return the unused part of a sponsor match once the project has
+    /// stopped raising. Callable by anyone.
+    pub fn close_sponsor_match(env: Env, project_id: u32) -> Result<(), ContractError> {
+        matching::close_sponsor_match(env, project_id)
+    }
+    
+    /// Get a matching round
+    pub fn get_matching_round(env: Env, round_id: u32) -> MatchingRound {
+        matching::get_matching_round(env, round_id)
+    }
+    
+    /// Get what a project has been contributed within a matching round and,
+    /// once it closed, the match it received
+    pub fn get_round_tally(env: Env, round_id: u32, project_id: u32) -> RoundTally {
+        matching::get_round_tally(env, round_id, project_id)
+    }
+    
+    /// Get a project's sponsor match, if it has one
+    pub fn get_sponsor_match(env: Env, project_id: u32) -> Option<SponsorMatch> {
+        matching::get_sponsor_match(env, project_id)
+    }
+}
+
+// Projects raising in other tokens
+#[cfg(feature = "multi-token")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Allow projects to raise in a token (admin only)
+    pub fn add_allowed_token(env: Env, token: Address) {
+        admin::add_allowed_token(env, token)
+    }
+    
+    /// Stop new projects from raising in a token (admin only); existing projects keep it
+    pub fn remove_allowed_token(env: Env, token: Address) {
+        admin::remove_allowed_token(env, token)
+    }
+}
+
+// Milestones verified by an oracle
+#[cfg(feature = "oracle-milestones")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Approve (or stop approving) an address as an oracle milestones can
+    /// be verified by (admin only); milestones already bound to it keep it
+    pub fn set_oracle_approval(env: Env, oracle: Address, approved: bool) {
+        admin::set_oracle_approval(env, oracle, approved)
+    }
+    
+    /// Have a milestone verified automatically once `oracle` reports at least
+    /// `expected` (owner only, before the first investment)
+    pub fn set_oracle_milestone(env: Env, project_id: u32, milestone_index: u32, oracle: Address, expected: i128) {
+        milestones::set_oracle_milestone(env, project_id, milestone_index, oracle, expected)
+    }
+    
+    /// Verify an oracle milestone whose condition holds, releasing its funds;
+    /// callable by anyone. The decision is recorded with this contract as the
+    /// verifier.
+    pub fn check_oracle_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+        milestones::check_oracle_milestone(env, project_id, milestone_index)
+    }
+}
+
+// Releases split between recipients
+#[cfg(feature = "payout-splits")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Split future milestone releases among several wallets by basis-point
+    /// shares summing to 10000 (owner only); an empty list pays the owner
+    pub fn set_payout_recipients(env: Env, project_id: u32, recipients: Vec<(Address, u32)>) {
+        projects::set_payout_recipients(env, project_id, recipients)
+    }
+}
+
+// Position transfers
+#[cfg(feature = "position-transfers")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Transfer all or part of a recorded position to another address
+    pub fn transfer_position(env: Env, project_id: u32, from: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+        transfers::transfer_position(env, project_id, from, to, amount)
+    }
+}
+
+// Pre-flight checks of actions
+#[cfg(feature = "preflight")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Check without side effects whether `actor` may currently perform
+    /// `action` on a project; amount-dependent checks are not covered
+    pub fn can_perform(env: Env, actor: Address, action: Action, project_id: u32) -> bool {
+        preflight::can_perform(env, actor, action, project_id)
+    }
+}
+
+// Edits to a project before and after listing
+#[cfg(feature = "project-edits")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Replace a project's milestones before anyone has invested (owner only)
+    pub fn update_milestones(
+        env: Env,
+        project_id: u32,
+        milestone_amounts: Vec<i128>,
+        milestone_deadlines: Vec<u64>,
+    ) -> Result<(), ContractError> {
+        milestones::update_milestones(env, project_id, milestone_amounts, milestone_deadlines)
+    }
+    
+    /// Replace a project's metadata (owner only)
+    pub fn update_project_metadata(env: Env, project_id: u32, metadata: ProjectMetadata) {
+        projects::update_project_metadata(env, project_id, metadata)
+    }
+}
+
+// Referral rewards
+#[cfg(feature = "referrals")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Reward referrers with `bps` of each investment they bring in, paid
+    /// out of the project's platform fee or out of a budget escrowed from
+    /// the owner now (project owner only)
+    pub fn set_referral_program(env: Env, project_id: u32, bps: u32, funding: ReferralFunding) {
+        referrals::set_referral_program(env, project_id, bps, funding)
+    }
+    
+    /// Stop accruing referral rewards and return the unspent budget; rewards
+    /// already accrued stay claimable (project owner only)
+    pub fn end_referral_program(env: Env, project_id: u32) -> Result<(), ContractError> {
+        referrals::end_referral_program(env, project_id)
+    }
+    
+    /// Claim a referrer's rewards from a project, as far as they are funded;
+    /// returns the amount paid (referrer only)
+    pub fn claim_referral_rewards(env: Env, referrer: Address, project_id: u32) -> Result<i128, ContractError> {
+        referrals::claim_referral_rewards(env, referrer, project_id)
+    }
+    
+    /// Get a project's referral program, if it has one
+    pub fn get_referral_program(env: Env, project_id: u32) -> Option<ReferralProgram> {
+        referrals::get_referral_program(env, project_id)
+    }
+    
+    /// Get the referral rewards a referrer has accrued from a project and
+    /// not yet claimed
+    pub fn get_referral_rewards(env: Env, project_id: u32, referrer: Address) -> i128 {
+        referrals::get_referral_rewards(env, project_id, referrer)
+    }
+}
+
+mod test;
+
+// Grace before refunds open
+#[cfg(feature = "refund-grace")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set how long after a milestone stops accepting evidence its refund
+    /// can be triggered (admin only); applies to projects created afterwards
+    pub fn set_refund_grace(env: Env, grace: u64) {
+        admin::set_refund_grace(env, grace)
+    }
+}
+
+// Track records of project owners
+#[cfg(feature = "reputation")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Get an owner's track record: projects created, milestones verified
+    /// and failed, and refunds triggered against their projects
+    pub fn get_owner_reputation(env: Env, owner: Address) -> OwnerReputation {
+        projects::get_owner_reputation(env, owner)
+    }
+}
+
+// Evidence resubmitted after a rejection
+#[cfg(feature = "resubmissions")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set how often evidence may be resubmitted after a rejection before the
+    /// milestone fails (admin only); applies to projects created afterwards
+    pub fn set_max_resubmissions(env: Env, max_resubmissions: u32) {
+        admin::set_max_resubmissions(env, max_resubmissions)
+    }
+    
+    /// Submit new evidence for a rejected milestone that has resubmissions left
+    pub fn resubmit_evidence(
+        env: Env,
+        project_id: u32,
+        milestone_index: u32,
+        evidence_hash: BytesN<32>,
+    ) {
+        milestones::resubmit_evidence(env, project_id, milestone_index, evidence_hash)
+    }
+}
+
+// Revenue sharing with investors
+#[cfg(feature = "revenue")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Let revenue left unclaimed for `expiry` seconds be escheated, either
+    /// into the next revenue round or to the treasury (owner only, before the
+    /// first investment); None keeps revenue claimable forever
+    pub fn set_revenue_expiry(env: Env, project_id: u32, expiry: Option<u64>, escheatment: Escheatment) {
+        projects::set_revenue_expiry(env, project_id, expiry, escheatment)
+    }
+    
+    /// Deposit revenue to be shared pro-rata among the project's investors (owner only)
+    pub fn deposit_revenue(env: Env, project_id: u32, amount: i128) -> Result<(), ContractError> {
+        revenue::deposit_revenue(env, project_id, amount)
+    }
+    
+    /// Withdraw the investor's share of all revenue deposited so far
+    pub fn claim_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        revenue::claim_revenue(env, project_id, investor)
+    }
+    
+    /// Escheat revenue an investor left unclaimed past the project's revenue
+    /// expiry; callable by anyone. Returns the amount escheated.
+    pub fn expire_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        revenue::expire_revenue(env, project_id, investor)
+    }
+    
+    /// Get the revenue an investor can currently claim
+    pub fn get_claimable_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        revenue::get_claimable_revenue(env, project_id, investor)
+    }
+    
+    /// Get all revenue rounds deposited for a project
+    pub fn get_revenue_rounds(env: Env, project_id: u32) -> Vec<RevenueRound> {
+        revenue::get_revenue_rounds(env, project_id)
+    }
+}
+
+// Backer reward tiers and badges
+#[cfg(feature = "rewards")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Define the project's backer reward tiers, in increasing order of
+    /// threshold (owner only, before the first investment). Each investment
+    /// moves the investor up to the highest tier that everything they have
+    /// put in reaches and that still has a slot free.
+    pub fn set_reward_tiers(env: Env, project_id: u32, tiers: Vec<RewardTier>) {
+        rewards::set_reward_tiers(env, project_id, tiers)
+    }
+    
+    /// Get a project's reward tiers with the slots claimed in each
+    pub fn get_reward_tiers(env: Env, project_id: u32) -> Vec<RewardTier> {
+        rewards::get_reward_tiers(env, project_id)
+    }
+    
+    /// Get the index of the reward tier an investor holds in a project, if
+    /// any
+    pub fn get_investor_tier(env: Env, project_id: u32, investor: Address) -> Option<u32> {
+        rewards::get_investor_tier(env, project_id, investor)
+    }
+    
+    /// Get the badge an investor earned backing a project, if any
+    pub fn get_badge(env: Env, investor: Address, project_id: u32) -> Option<Badge> {
+        rewards::get_badge(env, investor, project_id)
+    }
+    
+    /// Check whether an investor holds a project's badge at `level` or above
+    pub fn has_badge(env: Env, investor: Address, project_id: u32, level: u32) -> bool {
+        rewards::has_badge(env, investor, project_id, level)
+    }
+}
+
+// Milestones worked through in order
+#[cfg(feature = "sequential")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Require milestones to be completed in order (owner only, before the
+    /// first investment)
+    pub fn set_sequential(env: Env, project_id: u32, sequential: bool) {
+        projects::set_sequential(env, project_id, sequential)
+    }
+}
+
+// Share tokens minted for investments
+#[cfg(feature = "share-token")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the wasm deployed as share token for new projects: the bundled
+    /// stellarbridge-share-token, whose shares only this contract can move
+    /// (admin only)
+    pub fn set_share_token_wasm(env: Env, wasm_hash: BytesN<32>) {
+        admin::set_share_token_wasm(env, wasm_hash)
+    }
+}
+
+// Verifier stakes
+#[cfg(feature = "staking")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the stake the verifier must hold before verifying milestones
+    /// (admin only); zero removes the requirement
+    pub fn set_required_stake(env: Env, amount: i128) {
+        admin::set_required_stake(env, amount)
+    }
+    
+    /// Add to a verifier's stake, in the contract's base token; open to the
+    /// global verifier and approved project verifiers
+    pub fn stake_verifier(env: Env, verifier: Address, amount: i128) -> Result<(), ContractError> {
+        staking::stake_verifier(env, verifier, amount)
+    }
+    
+    /// Withdraw stake above the requirement (the staking verifier only)
+    pub fn unstake_verifier(env: Env, verifier: Address, amount: i128) {
+        staking::unstake_verifier(env, verifier, amount)
+    }
+    
+    /// Slash the verifier's stake in favour of a project's investors, who
+    /// claim it with claim_refund (admin only)
+    pub fn slash_verifier(env: Env, project_id: u32, amount: i128) -> Result<(), ContractError> {
+        staking::slash_verifier(env, project_id, amount)
+    }
+    
+    /// Get a verifier's current stake
+    pub fn get_verifier_stake(env: Env, verifier: Address) -> i128 {
+        staking::get_verifier_stake(env, verifier)
+    }
+    
+    /// Get the stake the verifier must hold to verify milestones
+    pub fn get_required_stake(env: Env) -> i128 {
+        admin::get_required_stake(env)
+    }
+}
+
+// Running totals across all projects
+#[cfg(feature = "stats")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Get running totals across all projects
+    pub fn get_stats(env: Env) -> ContractStats {
+        admin::get_stats(env)
+    }
+}
+
+// Treasury of the platform's share
+#[cfg(feature = "treasury")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Set the treasury that receives escheated revenue (admin only)
+    pub fn set_treasury(env: Env, treasury: Address) {
+        admin::set_treasury(env, treasury)
+    }
+    
+    /// Get the treasury escheated revenue is sent to, if set
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        admin::get_treasury(env)
+    }
+}
+
+// Progress updates posted by owners
+#[cfg(feature = "updates")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Post a progress update to the project's log (owner only)
+    pub fn post_update(env: Env, project_id: u32, content_hash: BytesN<32>) {
+        projects::post_update(env, project_id, content_hash)
+    }
+    
+    /// Get up to `limit` of a project's updates, oldest first, starting at `start`
+    pub fn get_project_updates(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<ProjectUpdate> {
+        projects::get_project_updates(env, project_id, start, limit)
+    }
+}
+
+// Goals priced in USD
+#[cfg(feature = "usd-goals")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Denominate the project's goal in USD, priced by an oracle, or go
+    /// back to the token goal with None (owner only, before the first
+    /// investment)
+    pub fn set_usd_goal(env: Env, project_id: u32, usd_goal: Option<UsdGoal>) {
+        usd_goals::set_usd_goal(env, project_id, usd_goal)
+    }
+    
+    /// Get a project's USD goal, if it has one
+    pub fn get_usd_goal(env: Env, project_id: u32) -> Option<UsdGoal> {
+        usd_goals::get_usd_goal(env, project_id)
+    }
+    
+    /// Get how much a project has raised against its goal, in its token and,
+    /// for a USD goal, in USD
+    pub fn get_funding_progress(env: Env, project_id: u32) -> FundingProgress {
+        usd_goals::get_funding_progress(env, project_id)
+    }
+}
+
+// Verifiers approved by the admin or a registry
+#[cfg(feature = "verifier-pool")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Approve (or stop approving) an address as a project-specific verifier
+    /// (admin only); projects already assigned to it keep it
+    pub fn set_verifier_approval(env: Env, verifier: Address, approved: bool) {
+        admin::set_verifier_approval(env, verifier, approved)
+    }
+    
+    /// Resolve project-specific verifiers through a registry contract's
+    /// `is_registered` for `domain`, alongside those approved here, or stop
+    /// (admin only)
+    pub fn set_verifier_registry(env: Env, registry: Option<Address>, domain: Symbol) {
+        admin::set_verifier_registry(env, registry, domain)
+    }
+    
+    /// Set the pool new projects are pseudorandomly assigned a verifier from,
+    /// or clear it with an empty list (admin only); while it is set, owners
+    /// cannot pick their own verifier
+    pub fn set_verifier_pool(env: Env, verifiers: Vec<Address>) {
+        admin::set_verifier_pool(env, verifiers)
+    }
+    
+    /// Assign the project an admin-approved verifier, or fall back to the
+    /// global verifier with None (owner only, before the first investment
+    /// and while no verifier pool is set)
+    pub fn set_project_verifier(env: Env, project_id: u32, verifier: Option<Address>) {
+        verifiers::set_project_verifier(env, project_id, verifier)
+    }
+}
+
+// Verification decisions signed off-chain by the verifier
+#[cfg(feature = "verifier-signatures")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Register the ed25519 key a verifier signs attestations with, or clear
+    /// it with None (the verifier only)
+    pub fn set_verifier_key(env: Env, verifier: Address, public_key: Option<BytesN<32>>) {
+        admin::set_verifier_key(env, verifier, public_key)
+    }
+    
+    /// Verify a milestone on an attestation the project's verifier signed
+    /// off-chain with its registered key, over this contract's address and
+    /// the payload in XDR, so relayers can pay the fees. Callable by anyone.
+    pub fn verify_with_signature(env: Env, payload: VerificationAttestation, signature: BytesN<64>) -> Result<(), ContractError> {
+        milestones::verify_with_signature(env, payload, signature)
+    }
+    
+    /// Get the key a verifier signs attestations with
+    pub fn get_verifier_key(env: Env, verifier: Address) -> Option<BytesN<32>> {
+        admin::get_verifier_key(env, verifier)
+    }
+    
+    /// Get the nonce a verifier's next attestation must carry
+    pub fn get_verifier_nonce(env: Env, verifier: Address) -> u64 {
+        admin::get_verifier_nonce(env, verifier)
+    }
+}
+
+// Vesting of milestone releases
+#[cfg(feature = "vesting")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Vest verified milestone amounts linearly over `days` instead of
+    /// releasing them at once (owner only, before the first investment)
+    pub fn set_vesting(env: Env, project_id: u32, days: Option<u32>) {
+        vesting::set_vesting(env, project_id, days)
+    }
+    
+    /// Release everything vested so far to the owner or recipients (owner only)
+    pub fn claim_vested(env: Env, project_id: u32) -> Result<i128, ContractError> {
+        vesting::claim_vested(env, project_id)
+    }
+    
+    /// Get the vested amount the owner can currently claim
+    pub fn get_claimable_vested(env: Env, project_id: u32) -> Result<i128, ContractError> {
+        vesting::get_claimable_vested(env, project_id)
+    }
+}
+
+// Investor whitelists
+#[cfg(feature = "whitelist")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Turn whitelist-only investing on or off; `compliance`, when set,
+    /// manages the whitelist instead of the owner. The owner decides until a
+    /// compliance account is set, then only that account or the admin can
+    pub fn set_whitelist_mode(env: Env, caller: Address, project_id: u32, enabled: bool, compliance: Option<Address>) {
+        whitelist::set_whitelist_mode(env, caller, project_id, enabled, compliance)
+    }
+    
+    /// Allow an investor into a whitelisted project (compliance address, or owner if none)
+    pub fn add_to_whitelist(env: Env, project_id: u32, investor: Address) {
+        whitelist::add_to_whitelist(env, project_id, investor)
+    }
+    
+    /// Remove an investor from a project's whitelist (compliance address, or owner if none)
+    pub fn remove_from_whitelist(env: Env, project_id: u32, investor: Address) {
+        whitelist::remove_from_whitelist(env, project_id, investor)
+    }
+    
+    /// Check whether an investor is on a project's whitelist
+    pub fn is_whitelisted(env: Env, project_id: u32, investor: Address) -> bool {
+        whitelist::is_whitelisted(env, project_id, investor)
+    }
+}
+
+// Lending pool yield on idle escrow
+#[cfg(feature = "yield-pool")]
+#[contractimpl]
+impl StellarBridgeContract {
+    /// Lend idle escrow in `token` to a lending pool, or stop doing so with
+    /// None (admin only); applies as projects take in or hold escrow, and a
+    /// position already open stays with its pool until withdrawn
+    pub fn set_yield_pool(env: Env, token: Address, pool: Option<YieldPool>) {
+        admin::set_yield_pool(env, token, pool)
+    }
+    
+    /// Withdraw a project's lending pool position and deposit it again, so
+    /// the yield earned so far becomes claimable; anyone may call
+    pub fn harvest_yield(env: Env, project_id: u32) -> Result<i128, ContractError> {
+        pool::harvest_yield(env, project_id)
+    }
+    
+    /// Write off a project's lending pool position when the pool will not
+    /// pay it out, taking the deposit off the project's escrow (admin only)
+    pub fn write_off_pool_position(env: Env, project_id: u32) -> Result<(), ContractError> {
+        pool::write_off_pool_position(env, project_id)
+    }
+    
+    /// Withdraw the investor's share of the yield a project's escrow earned
+    pub fn claim_yield(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        pool::claim_yield(env, project_id, investor)
+    }
+    
+    /// Get the yield an investor can currently claim
+    pub fn get_claimable_yield(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+        pool::get_claimable_yield(env, project_id, investor)
+    }
+    
+    /// Get the lending pool idle escrow in a token is deposited in, if any
+    pub fn get_yield_pool(env: Env, token: Address) -> Option<YieldPool> {
+        pool::get_yield_pool(env, token)
+    }
+    
+    /// Get a project's current lending pool position, if it has one
+    pub fn get_pool_position(env: Env, project_id: u32) -> Option<PoolPosition> {
+        pool::get_pool_position(env, project_id)
+    }
+}
@@ -0,0 +1,370 @@
+// Sponsor-funded matching. In a quadratic funding round a sponsor escrows
+// a matching pool, projects in the round's token opt in, and what each
+// unique investor puts into them while the round is open is tallied.
+// Closing the round splits the pool by each project's quadratic weight and
+// adds the matches to its open milestones, so they are released and
+// refunded like invested funds. A sponsor match instead backs a single
+// project while it raises, investing a share of each investment in the
+// sponsor's name out of an escrowed cap
+
+use soroban_sdk::{log, token, Address, Env, Symbol, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::milestones::milestone_done;
+use crate::pool;
+use crate::storage::{self, try_update_stats};
+use crate::investments::record_match;
+use crate::types::{MatchingKey, MatchingRound, Project, ProjectStatus, RoundTally, SponsorMatch};
+use crate::{math, splitter, StellarBridgeContract, MAX_ROUND_PROJECTS};
+
+fn round(env: &Env, round_id: u32) -> MatchingRound {
+    storage::get(env, &MatchingKey::MatchingRound(round_id)).expect("Round not found")
+}
+
+fn set_round(env: &Env, round_id: u32, round: &MatchingRound) {
+    storage::set(env, &MatchingKey::MatchingRound(round_id), round);
+    storage::extend_instance(env);
+}
+
+// A project can still take a match while it runs and has a milestone open
+fn accepts_match(project: &Project) -> bool {
+    project.active && project.milestones.iter().any(|m| !milestone_done(project, &m))
+}
+
+// Add a match to the project's escrow, spread over its open milestones in
+// proportion to their amounts with the rounding remainder on the last
+fn credit_match(env: &Env, project: &mut Project, amount: i128) -> Result<(), ContractError> {
+    let mut open_total: i128 = 0;
+    let mut last = 0;
+    for i in 0..project.milestones.len() {
+        let milestone = project.milestones.get(i).unwrap();
+        if !milestone_done(project, &milestone) {
+            open_total = math::add(open_total, milestone.amount)?;
+            last = i;
+        }
+    }
+    
+    let mut remaining = amount;
+    for i in 0..=last {
+        let mut milestone = project.milestones.get(i).unwrap();
+        if milestone_done(project, &milestone) {
+            continue;
+        }
+        let share = if i == last {
+            remaining
+        } else if open_total == 0 {
+            0
+        } else {
+            math::mul_div(amount, milestone.amount, open_total)?
+        };
+        remaining = math::sub(remaining, share)?;
+        milestone.amount = math::add(milestone.amount, share)?;
+        project.milestones.set(i, milestone);
+    }
+    
+    project.escrow_balance = math::add(project.escrow_balance, amount)?;
+    storage::set_project(env, project);
+    try_update_stats(env, |stats| {
+        stats.value_locked = math::add(stats.value_locked, amount)?;
+        Ok(())
+    })?;
+    pool::deposit_idle(env, project)?;
+    
+    Ok(())
+}
+
+pub fn create_matching_round(env: Env, sponsor: Address, token: Address, pool: i128, ends_at: u64) -> u32 {
+    sponsor.require_auth();
+    
+    if pool <= 0 {
+        panic!("Matching pool must be positive");
+    }
+    
+    if ends_at <= env.ledger().timestamp() {
+        panic!("Round must end in the future");
+    }
+    
+    if !StellarBridgeContract::is_token_allowed(env.clone(), token.clone()) {
+        panic!("{}", errors::TOKEN_NOT_ALLOWED);
+    }
+    
+    token::Client::new(&env, &token).transfer(&sponsor, &env.current_contract_address(), &pool);
+    
+    let round_id: u32 = env.storage()
+        .instance()
+        .get(&MatchingKey::MatchingRoundCount)
+        .unwrap_or(0) + 1;
+    env.storage().instance().set(&MatchingKey::MatchingRoundCount, &round_id);
+    set_round(&env, round_id, &MatchingRound {
+        sponsor: sponsor.clone(),
+        token,
+        pool,
+        ends_at,
+        projects: Vec::new(&env),
+        closed: false,
+    });
+    
+    env.events().publish(
+        (Symbol::new(&env, "round_created"), round_id),
+        (sponsor, pool, ends_at),
+    );
+    
+    round_id
+}
+
+pub fn join_matching_round(env: Env, round_id: u32, project_id: u32) {
+    let project = storage::project(&env, project_id);
+    project.owner.require_auth();
+    
+    let mut round = round(&env, round_id);
+    
+    if round.closed || env.ledger().timestamp() >= round.ends_at {
+        panic!("Round is closed");
+    }
+    
+    if !accepts_match(&project) {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if project.token != round.token {
+        panic!("Project token does not match the round");
+    }
+    
+    let project_round_key = MatchingKey::ProjectRound(project_id);
+    if storage::has(&env, &project_round_key) {
+        panic!("Project already in a matching round");
+    }
+    
+    if round.projects.len() >= MAX_ROUND_PROJECTS {
+        panic!("Too many projects in round");
+    }
+    
+    round.projects.push_back(project_id);
+    set_round(&env, round_id, &round);
+    storage::set(&env, &project_round_key, &round_id);
+    
+    env.events().publish(
+        (Symbol::new(&env, "round_joined"), round_id),
+        project_id,
+    );
+}
+
+// Tally an investment towards the project's open round, if it is in one
+pub fn record_contribution(env: &Env, project_id: u32, investor: &Address, amount: i128) -> Result<(), ContractError> {
+    let round_id: Option<u32> = storage::get(env, &MatchingKey::ProjectRound(project_id));
+    let Some(round_id) = round_id else {
+        return Ok(());
+    };
+    
+    if env.ledger().timestamp() >= round(env, round_id).ends_at {
+        return Ok(());
+    }
+    
+    let contribution_key = MatchingKey::RoundContribution(round_id, project_id, investor.clone());
+    let before: i128 = storage::get(env, &contribution_key).unwrap_or(0);
+    let after = math::add(before, amount)?;
+    storage::set(env, &contribution_key, &after);
+    
+    let mut tally = get_round_tally(env.clone(), round_id, project_id);
+    tally.contributed = math::add(tally.contributed, amount)?;
+    tally.root_sum = math::add(math::sub(tally.root_sum, math::sqrt(before))?, math::sqrt(after))?;
+    storage::set(env, &MatchingKey::RoundTally(round_id, project_id), &tally);
+    
+    Ok(())
+}
+
+pub fn close_matching_round(env: Env, round_id: u32) -> Result<(), ContractError> {
+    let mut round = round(&env, round_id);
+    
+    if round.closed {
+        panic!("Round already closed");
+    }
+    
+    if env.ledger().timestamp() < round.ends_at {
+        panic!("Round still open");
+    }
+    
+    // Projects that can no longer take funds get no weight; a project with
+    // a single investor has none either, as (√c)² − c is zero
+    let mut weights: Vec<i128> = Vec::new(&env);
+    let mut total: i128 = 0;
+    for project_id in round.projects.iter() {
+        storage::remove(&env, &MatchingKey::ProjectRound(project_id));
+        let tally = get_round_tally(env.clone(), round_id, project_id);
+        let weight = if accepts_match(&storage::project(&env, project_id)) {
+            math::sub(math::mul(tally.root_sum, tally.root_sum)?, tally.contributed)?.max(0)
+        } else {
+            0
+        };
+        weights.push_back(weight);
+        total = math::add(total, weight)?;
+    }
+    
+    let mut matched_total: i128 = 0;
+    for i in 0..round.projects.len() {
+        let weight = weights.get(i).unwrap();
+        if total == 0 || weight == 0 {
+            continue;
+        }
+        
+        let project_id = round.projects.get(i).unwrap();
+        let matched = math::mul_div(round.pool, weight, total)?;
+        let mut project = storage::project(&env, project_id);
+        credit_match(&env, &mut project, matched)?;
+        
+        let mut tally = get_round_tally(env.clone(), round_id, project_id);
+        tally.matched = matched;
+        storage::set(&env, &MatchingKey::RoundTally(round_id, project_id), &tally);
+        matched_total = math::add(matched_total, matched)?;
+        
+        env.events().publish(
+            (Symbol::new(&env, "round_matched"), round_id),
+            (project_id, matched),
+        );
+    }
+    
+    // Whatever was not matched, rounding included, goes back to the sponsor
+    let unused = math::sub(round.pool, matched_total)?;
+    if unused > 0 {
+        token::Client::new(&env, &round.token).transfer(&env.current_contract_address(), &round.sponsor, &unused);
+    }
+    
+    round.closed = true;
+    set_round(&env, round_id, &round);
+    
+    env.events().publish(
+        (Symbol::new(&env, "round_closed"), round_id),
+        (matched_total, unused),
+    );
+    
+    log!(&env, "Matching round {} closed, {} matched", round_id, matched_total);
+    
+    Ok(())
+}
+
+pub fn get_matching_round(env: Env, round_id: u32) -> MatchingRound {
+    round(&env, round_id)
+}
+
+pub fn get_round_tally(env: Env, round_id: u32, project_id: u32) -> RoundTally {
+    storage::get(&env, &MatchingKey::RoundTally(round_id, project_id))
+        .unwrap_or(RoundTally {
+            contributed: 0,
+            root_sum: 0,
+            matched: 0,
+        })
+}
+
+pub fn commit_sponsor_match(env: Env, project_id: u32, sponsor: Address, ratio_bps: u32, cap: i128) {
+    sponsor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    if !project.active || project.status != ProjectStatus::Funding {
+        panic!("Project is not raising");
+    }
+    
+    if ratio_bps == 0 {
+        panic!("Match ratio must be positive");
+    }
+    
+    if cap <= 0 {
+        panic!("Match cap must be positive");
+    }
+    
+    let key = MatchingKey::SponsorMatch(project_id);
+    if storage::has(&env, &key) {
+        panic!("Project already has a sponsor match");
+    }
+    
+    token::Client::new(&env, &project.token).transfer(&sponsor, &env.current_contract_address(), &cap);
+    
+    storage::set(&env, &key, &SponsorMatch {
+        sponsor: sponsor.clone(),
+        ratio_bps,
+        cap,
+        matched: 0,
+        closed: false,
+    });
+    storage::extend_instance(&env);
+    
+    env.events().publish(
+        (Symbol::new(&env, "match_committed"), project_id),
+        (sponsor, ratio_bps, cap),
+    );
+}
+
+// Match an investment out of the project's sponsor pledge, investing the
+// match in the sponsor's name; the pledge closes once the project stops
+// raising. What the sponsor invests themselves is not matched.
+pub fn apply_sponsor_match(env: &Env, project: &mut Project, investor: &Address, amount: i128) -> Result<(), ContractError> {
+    let key = MatchingKey::SponsorMatch(project.id);
+    let pledge: Option<SponsorMatch> = storage::get(env, &key);
+    let Some(mut pledge) = pledge else {
+        return Ok(());
+    };
+    
+    if pledge.closed || *investor == pledge.sponsor {
+        return Ok(());
+    }
+    
+    let matched = math::mul_div(amount, pledge.ratio_bps as i128, splitter::BASIS_POINTS as i128)?
+        .min(math::sub(pledge.cap, pledge.matched)?);
+    if matched > 0 {
+        pledge.matched = math::add(pledge.matched, matched)?;
+        storage::set(env, &key, &pledge);
+        record_match(env, project, &pledge.sponsor, matched)?;
+        
+        env.events().publish(
+            (Symbol::new(env, "investment_matched"), project.id),
+            (investor.clone(), matched),
+        );
+    }
+    
+    if project.status != ProjectStatus::Funding {
+        close_pledge(env, project, pledge)?;
+    }
+    
+    Ok(())
+}
+
+// Return what is left of the pledge to the sponsor
+fn close_pledge(env: &Env, project: &Project, mut pledge: SponsorMatch) -> Result<(), ContractError> {
+    let unused = math::sub(pledge.cap, pledge.matched)?;
+    if unused > 0 {
+        token::Client::new(env, &project.token).transfer(&env.current_contract_address(), &pledge.sponsor, &unused);
+    }
+    
+    pledge.closed = true;
+    storage::set(env, &MatchingKey::SponsorMatch(project.id), &pledge);
+    storage::extend_instance(env);
+    
+    env.events().publish(
+        (Symbol::new(env, "match_closed"), project.id),
+        (pledge.matched, unused),
+    );
+    
+    Ok(())
+}
+
+pub fn close_sponsor_match(env: Env, project_id: u32) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    let pledge: SponsorMatch = storage::get(&env, &MatchingKey::SponsorMatch(project_id))
+        .expect("No sponsor match");
+    
+    if pledge.closed {
+        panic!("Sponsor match already closed");
+    }
+    
+    if project.active && project.status == ProjectStatus::Funding {
+        panic!("Project is still raising");
+    }
+    
+    close_pledge(&env, &project, pledge)?;
+    
+    Ok(())
+}
+
+pub fn get_sponsor_match(env: Env, project_id: u32) -> Option<SponsorMatch> {
+    storage::get(&env, &MatchingKey::SponsorMatch(project_id))
+}
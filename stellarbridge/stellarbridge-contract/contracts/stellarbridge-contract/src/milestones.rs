@@ -0,0 +1,852 @@
+// Milestone schedules and their review: evidence, verification by the
+// verifier, its delegates, oracles, escalation and investor votes
+
+#[cfg(any(feature = "evm-attestation", feature = "verifier-signatures"))]
+use soroban_sdk::xdr::ToXdr;
+#[cfg(feature = "fees")]
+use soroban_sdk::token;
+use soroban_sdk::{log, Address, BytesN, Env, Symbol, Vec};
+
+use crate::appeals;
+use crate::co_verification;
+use crate::disputes;
+use crate::errors::{self, ContractError};
+use crate::escalation;
+use crate::escrow::{give_back, release_funds, release_surplus};
+#[cfg(feature = "fees")]
+use crate::fee;
+use crate::hooks;
+use crate::pool;
+#[cfg(feature = "fees")]
+use crate::referrals;
+#[cfg(feature = "donations")]
+use crate::refunds::refund_opens;
+use crate::staking;
+use crate::storage::{self, try_update_stats, update_reputation};
+use crate::types::{Milestone, MilestoneKind, MilestoneStatus, Payout, Project, ProjectStatus};
+#[cfg(feature = "decision-log")]
+use crate::types::{ListKey, VerificationDecision};
+#[cfg(feature = "evm-attestation")]
+use crate::types::{AttestationKey, EvmAttestation};
+#[cfg(feature = "oracle-milestones")]
+use crate::types::{OracleClient, OracleCondition, OracleKey};
+#[cfg(feature = "verifier-signatures")]
+use crate::types::{SignatureKey, VerificationAttestation};
+#[cfg(feature = "fees")]
+use crate::types::VerificationFee;
+use crate::vesting;
+#[cfg(feature = "late-policy")]
+use crate::splitter;
+use crate::{math, schedule, StellarBridgeContract, MAX_MILESTONES};
+
+// Pending milestones from parallel amount/deadline lists, once they pass
+// the shared schedule rules for the goal
+pub fn build_milestones(env: &Env, goal_amount: i128, amounts: &Vec<i128>, deadlines: &Vec<u64>) -> Result<Vec<Milestone>, ContractError> {
+    schedule::validate(goal_amount, amounts, deadlines, env.ledger().timestamp(), MAX_MILESTONES)?;
+    
+    let mut milestones: Vec<Milestone> = Vec::new(env);
+    for (amount, deadline) in amounts.iter().zip(deadlines.iter()) {
+        milestones.push_back(Milestone {
+            amount,
+            deadline,
+            kind: MilestoneKind::Evidence,
+            status: MilestoneStatus::Pending,
+            evidence_hash: None,
+            submitted_at: None,
+            resubmissions: 0,
+            rejection_reason: None,
+            appealable_until: None,
+        });
+    }
+    
+    Ok(milestones)
+}
+
+// Whether a milestone is done with: verified, finally rejected or rolled
+// over
+pub fn milestone_done(project: &Project, milestone: &Milestone) -> bool {
+    milestone.status == MilestoneStatus::Verified
+        || milestone.status == MilestoneStatus::RolledOver
+        || rejection_is_final(project, milestone)
+}
+
+// Whether a sequential project's milestones before `index` are all done
+// with; always true otherwise
+#[cfg(feature = "sequential")]
+pub fn in_sequence(project: &Project, index: u32) -> bool {
+    if !project.sequential {
+        return true;
+    }
+    
+    for i in 0..index {
+        if !milestone_done(project, &project.milestones.get(i).unwrap()) {
+            return false;
+        }
+    }
+    
+    true
+}
+
+// Without sequential milestones any of them can be worked on at any time
+#[cfg(not(feature = "sequential"))]
+pub fn in_sequence(_project: &Project, _index: u32) -> bool {
+    true
+}
+
+// Move a donation project's `amount` for milestone `from` onto the next
+// milestone still open, or pay it to the beneficiary when none is left;
+// returns the milestone it went to, if any
+#[cfg(feature = "donations")]
+pub fn roll_funds(env: &Env, project: &mut Project, from: u32, amount: i128) -> Result<Option<u32>, ContractError> {
+    for i in (from + 1)..project.milestones.len() {
+        let mut next = project.milestones.get(i).unwrap();
+        if !milestone_done(project, &next) {
+            next.amount = math::add(next.amount, amount)?;
+            project.milestones.set(i, next);
+            return Ok(Some(i));
+        }
+    }
+    
+    let payout = amount.min(project.escrow_balance);
+    if payout > 0 {
+        project.escrow_balance = math::sub(project.escrow_balance, payout)?;
+        give_back(env, Payout::Refund, project, payout)?;
+        try_update_stats(env, |stats| {
+            stats.total_released = math::add(stats.total_released, payout)?;
+            stats.value_locked = math::sub(stats.value_locked, payout)?;
+            Ok(())
+        })?;
+    }
+    Ok(None)
+}
+
+// Release a verified milestone's funds to the project owner or recipients,
+// or start vesting them when the project is configured to
+pub fn pay_release(env: &Env, project: &Project, release: i128) -> Result<(), ContractError> {
+    if !vesting::vest(env, project, release)? {
+        release_funds(env, project, release)?;
+    }
+    
+    hooks::on_release(env, project.id, release);
+    
+    Ok(())
+}
+
+// Whether a project has reached a terminal status
+pub fn ended(project: &Project) -> bool {
+    matches!(project.status, ProjectStatus::Completed | ProjectStatus::Refunded | ProjectStatus::Cancelled)
+}
+
+// Bring a project's status up to date once one of its milestones is done
+// with, releasing its surplus when it is finished. A finished donation
+// project has delivered its funds whether or not anything was verified.
+pub fn update_status(env: &Env, project: &mut Project) -> Result<(), ContractError> {
+    let was_ended = ended(project);
+    let finished = project.milestones.iter().all(|m| milestone_done(project, &m));
+    let verified = project.milestones.iter().any(|m| m.status == MilestoneStatus::Verified);
+    project.status = match (finished, verified) {
+        (true, true) => ProjectStatus::Completed,
+        (true, false) if !project.refundable => ProjectStatus::Completed,
+        (true, false) => ProjectStatus::Refunded,
+        (false, true) => ProjectStatus::InProgress,
+        (false, false) => project.status,
+    };
+    if project.active && !was_ended && ended(project) {
+        storage::end_active_project(env);
+    }
+    if finished {
+        release_surplus(env, project)?;
+    }
+    
+    Ok(())
+}
+
+// Allowed milestone status changes: evidence (or an oracle reading) moves a
+// pending milestone to review, review ends in verification or rejection,
+// and a rejected milestone can go back to review with new evidence. In
+// donation mode a milestone that missed its deadline undecided is rolled over.
+// An upheld dispute fails a verified milestone whose release was held.
+pub fn can_transition(from: MilestoneStatus, to: MilestoneStatus) -> bool {
+    matches!(
+        (from, to),
+        (MilestoneStatus::Pending, MilestoneStatus::EvidenceSubmitted)
+            | (MilestoneStatus::EvidenceSubmitted, MilestoneStatus::Verified)
+            | (MilestoneStatus::EvidenceSubmitted, MilestoneStatus::Rejected)
+            | (MilestoneStatus::Rejected, MilestoneStatus::EvidenceSubmitted)
+            | (MilestoneStatus::Pending, MilestoneStatus::RolledOver)
+            | (MilestoneStatus::EvidenceSubmitted, MilestoneStatus::RolledOver)
+            | (MilestoneStatus::Rejected, MilestoneStatus::RolledOver)
+            | (MilestoneStatus::Verified, MilestoneStatus::Rejected)
+    )
+}
+
+// Move a milestone to `to` and bump the project's version; every status
+// change goes through here
+pub fn transition(project: &mut Project, milestone: &mut Milestone, to: MilestoneStatus) {
+    if !can_transition(milestone.status, to) {
+        panic!("Invalid milestone status transition");
+    }
+    
+    milestone.status = to;
+    project.version += 1;
+}
+
+// Decisions name the project version they were made against, so one that
+// was overtaken by a resubmission or replayed after the fact is refused
+pub fn check_version(project: &Project, expected_version: u32) -> Result<(), ContractError> {
+    if project.version != expected_version {
+        return Err(ContractError::VersionMismatch);
+    }
+    Ok(())
+}
+
+// A rejected milestone stays open while it has resubmissions left or its
+// rejection can still be appealed; once a rejection is final its funds
+// have been made refundable
+pub fn rejection_is_final(project: &Project, milestone: &Milestone) -> bool {
+    milestone.status == MilestoneStatus::Rejected
+        && milestone.resubmissions >= project.max_resubmissions
+        && milestone.appealable_until.is_none()
+}
+
+// The project's own verifier, or the global verifier when it has none
+pub fn project_verifier(env: &Env, project: &Project) -> Address {
+    match &project.verifier {
+        Some(verifier) => verifier.clone(),
+        None => storage::verifier(env),
+    }
+}
+
+// Verification fee on a milestone release, never more than the release
+#[cfg(feature = "fees")]
+pub fn verification_fee(project: &Project, amount: i128) -> Result<i128, ContractError> {
+    let fee = match project.verification_fee {
+        VerificationFee::Free => 0,
+        VerificationFee::Flat(fee) => fee,
+        VerificationFee::BasisPoints(bps) => fee::charge(bps, amount)?,
+    };
+    Ok(fee.min(amount))
+}
+
+// Pay the verification and platform fees out of a milestone release,
+// returning both
+#[cfg(feature = "fees")]
+fn charge_fees(env: &Env, project: &Project, verifier: &Address, amount: i128) -> Result<(i128, i128), ContractError> {
+    // The verifier's fee comes off the top; the contract charges none for
+    // its own decisions
+    let fee = if *verifier == env.current_contract_address() {
+        0
+    } else {
+        verification_fee(project, amount)?
+    };
+    if fee > 0 {
+        let token_client = token::Client::new(env, &project.token);
+        token_client.transfer(&env.current_contract_address(), verifier, &fee);
+    }
+    
+    // The platform's fee goes to the treasury at the project's rate less
+    // whatever the project's referral rewards are still owed
+    let platform_fee = fee::charge(project.platform_fee_bps, amount)?.min(math::sub(amount, fee)?);
+    let to_treasury = math::sub(platform_fee, referrals::fund_from_fee(env, project, platform_fee)?)?;
+    if to_treasury > 0 {
+        let treasury = storage::treasury(env);
+        let token_client = token::Client::new(env, &project.token);
+        token_client.transfer(&env.current_contract_address(), &treasury, &to_treasury);
+    }
+    
+    Ok((fee, platform_fee))
+}
+
+// End of the window in which evidence for a milestone is still accepted
+pub fn grace_end(project: &Project, milestone: &Milestone) -> u64 {
+    milestone.deadline.saturating_add(project.late_policy.grace)
+}
+
+// Whether evidence for the milestone may still be submitted; always true
+// without a grace period
+pub fn accepts_evidence(env: &Env, project: &Project, milestone: &Milestone) -> bool {
+    project.late_policy.grace == 0 || env.ledger().timestamp() <= grace_end(project, milestone)
+}
+
+// Fee for evidence submitted late, growing quadratically from nothing at
+// the deadline to the policy's maximum at the end of the grace period
+#[cfg(feature = "late-policy")]
+pub fn late_fee(project: &Project, milestone: &Milestone) -> Result<i128, ContractError> {
+    let grace = project.late_policy.grace;
+    let late = match milestone.submitted_at {
+        Some(at) if grace > 0 && at > milestone.deadline => (at - milestone.deadline).min(grace),
+        _ => return Ok(0),
+    };
+    
+    Ok(math::mul_div(
+        milestone.amount,
+        math::mul(project.late_policy.max_fee_bps as i128, math::mul(late as i128, late as i128)?)?,
+        math::mul(splitter::BASIS_POINTS as i128, math::mul(grace as i128, grace as i128)?)?,
+    )?)
+}
+
+// Without a late policy there is no grace period to charge for
+#[cfg(not(feature = "late-policy"))]
+pub fn late_fee(_project: &Project, _milestone: &Milestone) -> Result<i128, ContractError> {
+    Ok(0)
+}
+
+// Settle a finally rejected milestone: a donation project's funds move on to
+// what is left, a refundable project's become claimable by investors
+pub fn fail_milestone(env: &Env, project: &mut Project, milestone_index: u32, milestone: &mut Milestone) -> Result<(), ContractError> {
+    update_reputation(env, &project.owner, |reputation| reputation.milestones_failed += 1);
+    
+    #[cfg(feature = "donations")]
+    if !project.refundable {
+        let amount = milestone.amount;
+        milestone.amount = 0;
+        roll_funds(env, project, milestone_index, amount)?;
+        
+        log!(env, "Milestone {} rejected for project {}, {} rolled over", milestone_index, project.id, amount);
+        return Ok(());
+    }
+    
+    // Capped at what the project still holds in escrow
+    let refund = milestone.amount.min(project.escrow_balance);
+    
+    if refund > 0 {
+        project.escrow_balance = math::sub(project.escrow_balance, refund)?;
+        give_back(env, Payout::Refund, project, refund)?;
+        try_update_stats(env, |stats| {
+            stats.total_refunded = math::add(stats.total_refunded, refund)?;
+            stats.value_locked = math::sub(stats.value_locked, refund)?;
+            Ok(())
+        })?;
+    }
+    
+    log!(env, "Milestone {} rejected for project {}, {} refundable", milestone_index, project.id, refund);
+    
+    Ok(())
+}
+
+// Approve or reject a milestone with submitted evidence, releasing or
+// refunding its funds, and record who decided. Without the decision log
+// nothing records who decided, so `verifier` may go unused
+#[cfg_attr(not(feature = "decision-log"), allow(unused_variables))]
+pub fn decide_milestone(
+    env: &Env,
+    project_id: u32,
+    milestone_index: u32,
+    approved: bool,
+    reason_hash: Option<BytesN<32>>,
+    verifier: &Address,
+    delegated: bool,
+) -> Result<(), ContractError> {
+    let mut project = storage::project(env, project_id);
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    if milestone.status != MilestoneStatus::EvidenceSubmitted {
+        panic!("No evidence submitted for this milestone");
+    }
+    
+    if escalation::under_vote(env, project_id, milestone_index) {
+        panic!("Milestone is under investor vote");
+    }
+    
+    if !in_sequence(&project, milestone_index) {
+        panic!("Earlier milestones are not complete");
+    }
+    
+    // Escalated and oracle decisions are taken by the contract itself and
+    // need no stake
+    if *verifier != env.current_contract_address() && !staking::stake_sufficient(env, &project_verifier(env, &project)) {
+        panic!("Verifier stake below requirement");
+    }
+    
+    // Escrow lent to a pool comes back before any of it is released
+    pool::withdraw(env, &mut project)?;
+    
+    if approved {
+        if milestone.amount > project.escrow_balance {
+            panic!("Milestone exceeds escrow balance");
+        }
+        
+        transition(&mut project, &mut milestone, MilestoneStatus::Verified);
+        project.escrow_balance = math::sub(project.escrow_balance, milestone.amount)?;
+        try_update_stats(env, |stats| {
+            stats.total_released = math::add(stats.total_released, milestone.amount)?;
+            stats.value_locked = math::sub(stats.value_locked, milestone.amount)?;
+            Ok(())
+        })?;
+        update_reputation(env, &project.owner, |reputation| reputation.milestones_verified += 1);
+        
+        #[cfg(feature = "fees")]
+        let (fee, platform_fee) = charge_fees(env, &project, verifier, milestone.amount)?;
+        #[cfg(not(feature = "fees"))]
+        let (fee, platform_fee) = (0, 0);
+        
+        // A late submission's fee stays in escrow for investors to claim
+        let late_fee = late_fee(&project, &milestone)?.min(milestone.amount - fee - platform_fee);
+        if late_fee > 0 {
+            give_back(env, Payout::Refund, &project, late_fee)?;
+        }
+        let release = milestone.amount - fee - platform_fee - late_fee;
+        
+        // With a dispute window the owner's part is held for investors to
+        // challenge before it goes out
+        if !disputes::hold_release(env, project_id, milestone_index, release) {
+            pay_release(env, &project, release)?;
+        }
+        
+        env.events().publish(
+            (Symbol::new(env, "milestone_verified"), project_id),
+            (milestone_index, fee, late_fee, platform_fee),
+        );
+        
+        log!(env, "Milestone {} verified for project {}", milestone_index, project_id);
+    } else {
+        transition(&mut project, &mut milestone, MilestoneStatus::Rejected);
+        milestone.rejection_reason = reason_hash.clone();
+        
+        // With an appeal window, a rejection that leaves no resubmissions
+        // is held open for the owner's one appeal before it settles
+        appeals::hold_rejection(env, &project, milestone_index, &mut milestone);
+        
+        env.events().publish(
+            (Symbol::new(env, "milestone_rejected"), project_id),
+            (milestone_index, reason_hash.clone()),
+        );
+        
+        if rejection_is_final(&project, &milestone) {
+            fail_milestone(env, &mut project, milestone_index, &mut milestone)?;
+        } else if let Some(appealable_until) = milestone.appealable_until {
+            log!(env, "Milestone {} rejected for project {}, appealable until {}", milestone_index, project_id, appealable_until);
+        } else {
+            log!(env, "Milestone {} rejected for project {}, resubmission allowed", milestone_index, project_id);
+        }
+    }
+    
+    #[cfg(feature = "decision-log")]
+    let evidence_hash = milestone.evidence_hash.clone();
+    project.milestones.set(milestone_index, milestone);
+    
+    update_status(env, &mut project)?;
+    storage::set_project(env, &project);
+    pool::deposit_idle(env, &project)?;
+    
+    #[cfg(feature = "decision-log")]
+    storage::list_push(
+        env,
+        &ListKey::DecisionCount(project_id),
+        |index| ListKey::Decision(project_id, index),
+        &VerificationDecision {
+            milestone_index,
+            verifier: verifier.clone(),
+            delegated,
+            approved,
+            evidence_hash,
+            reason_hash,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+    
+    Ok(())
+}
+
+#[cfg(feature = "project-edits")]
+pub fn update_milestones(
+    env: Env,
+    project_id: u32,
+    milestone_amounts: Vec<i128>,
+    milestone_deadlines: Vec<u64>,
+) -> Result<(), ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    project.milestones = build_milestones(&env, project.goal_amount, &milestone_amounts, &milestone_deadlines)?;
+    project.version += 1;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Milestones updated for project {}", project_id);
+    
+    Ok(())
+}
+
+#[cfg(feature = "oracle-milestones")]
+pub fn set_oracle_milestone(env: Env, project_id: u32, milestone_index: u32, oracle: Address, expected: i128) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    // The owner picks among oracles the admin vouches for, not any contract
+    // that would report what it likes
+    if !storage::has(&env, &OracleKey::Oracle(oracle.clone())) {
+        panic!("Oracle not approved");
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    milestone.kind = MilestoneKind::OracleVerified(OracleCondition { oracle: oracle.clone(), expected });
+    project.milestones.set(milestone_index, milestone);
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Milestone {} of project {} verified by oracle {}", milestone_index, project_id, oracle);
+}
+
+pub fn submit_evidence(
+    env: Env,
+    project_id: u32,
+    milestone_index: u32,
+    evidence_hash: BytesN<32>,
+) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    if milestone.status != MilestoneStatus::Pending {
+        panic!("Milestone not in pending state");
+    }
+    
+    if milestone.kind != MilestoneKind::Evidence {
+        panic!("Milestone is verified by oracle");
+    }
+    
+    if !accepts_evidence(&env, &project, &milestone) {
+        panic!("Submission grace period has ended");
+    }
+    
+    if !in_sequence(&project, milestone_index) {
+        panic!("Earlier milestones are not complete");
+    }
+    
+    milestone.evidence_hash = Some(evidence_hash.clone());
+    transition(&mut project, &mut milestone, MilestoneStatus::EvidenceSubmitted);
+    milestone.submitted_at = Some(env.ledger().timestamp());
+    
+    project.milestones.set(milestone_index, milestone);
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Evidence submitted for project {} milestone {}", project_id, milestone_index);
+}
+
+#[cfg(feature = "resubmissions")]
+pub fn resubmit_evidence(
+    env: Env,
+    project_id: u32,
+    milestone_index: u32,
+    evidence_hash: BytesN<32>,
+) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    if milestone.status != MilestoneStatus::Rejected {
+        panic!("Milestone not in rejected state");
+    }
+    
+    if milestone.resubmissions >= project.max_resubmissions {
+        panic!("No resubmissions left for this milestone");
+    }
+    
+    if !accepts_evidence(&env, &project, &milestone) {
+        panic!("Submission grace period has ended");
+    }
+    
+    milestone.evidence_hash = Some(evidence_hash.clone());
+    transition(&mut project, &mut milestone, MilestoneStatus::EvidenceSubmitted);
+    milestone.submitted_at = Some(env.ledger().timestamp());
+    milestone.resubmissions += 1;
+    
+    project.milestones.set(milestone_index, milestone);
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Evidence resubmitted for project {} milestone {}", project_id, milestone_index);
+}
+
+// Decide a milestone as `verifier`, unless it is an approval above the
+// co-verification threshold, which only counts as the verifier's sign-off.
+// Approvals the contract takes itself (auto-approval, escalation, investor
+// votes, oracles and attestations) wait for the admin the same way.
+pub fn decide_or_co_verify(
+    env: &Env,
+    project_id: u32,
+    milestone_index: u32,
+    approved: bool,
+    reason_hash: Option<BytesN<32>>,
+    verifier: &Address,
+    delegated: bool,
+) -> Result<(), ContractError> {
+    if approved && co_verification::hold_approval(env, project_id, milestone_index, verifier, delegated)? {
+        return Ok(());
+    }
+    
+    decide_milestone(env, project_id, milestone_index, approved, reason_hash, verifier, delegated)?;
+    
+    Ok(())
+}
+
+pub fn verify_milestone(
+    env: Env,
+    project_id: u32,
+    milestone_index: u32,
+    approved: bool,
+    reason_hash: Option<BytesN<32>>,
+    expected_version: u32,
+) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    let verifier = project_verifier(&env, &project);
+    verifier.require_auth();
+    
+    check_version(&project, expected_version)?;
+    
+    decide_or_co_verify(&env, project_id, milestone_index, approved, reason_hash, &verifier, false)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "verifier-signatures")]
+pub fn verify_with_signature(env: Env, payload: VerificationAttestation, signature: BytesN<64>) -> Result<(), ContractError> {
+    let project = storage::project(&env, payload.project_id);
+    let verifier = project_verifier(&env, &project);
+    
+    let public_key: BytesN<32> = storage::get(&env, &SignatureKey::VerifierKey(verifier.clone()))
+        .expect("Verifier has no signing key");
+    
+    let nonce_key = SignatureKey::VerifierNonce(verifier.clone());
+    let nonce: u64 = storage::get(&env, &nonce_key).unwrap_or(0);
+    if payload.nonce != nonce {
+        panic!("Invalid attestation nonce");
+    }
+    
+    check_version(&project, payload.version)?;
+    
+    // Signed together with this contract's address so an attestation cannot
+    // be replayed against another deployment
+    let message = (env.current_contract_address(), payload.clone()).to_xdr(&env);
+    env.crypto().ed25519_verify(&public_key, &message, &signature);
+    storage::set(&env, &nonce_key, &(nonce + 1));
+    
+    decide_or_co_verify(&env, payload.project_id, payload.milestone_index, payload.approved, None, &verifier, false)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "oracle-milestones")]
+pub fn check_oracle_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    let condition = match milestone.kind.clone() {
+        MilestoneKind::OracleVerified(condition) => condition,
+        MilestoneKind::Evidence => panic!("Milestone is not verified by oracle"),
+    };
+    
+    if milestone.status != MilestoneStatus::Pending {
+        panic!("Milestone not in pending state");
+    }
+    
+    // A reading counts only while evidence could still be submitted
+    if !accepts_evidence(&env, &project, &milestone) {
+        panic!("Submission grace period has ended");
+    }
+    
+    if OracleClient::new(&env, &condition.oracle).value() < condition.expected {
+        panic!("Oracle condition not met");
+    }
+    
+    // The oracle reading stands in for evidence, then the milestone goes
+    // through the regular decision path
+    transition(&mut project, &mut milestone, MilestoneStatus::EvidenceSubmitted);
+    milestone.submitted_at = Some(env.ledger().timestamp());
+    project.milestones.set(milestone_index, milestone);
+    storage::set_project(&env, &project);
+    
+    decide_or_co_verify(&env, project_id, milestone_index, true, None, &env.current_contract_address(), false)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "evm-attestation")]
+pub fn submit_evm_attestation(env: Env, attestation: EvmAttestation, signature: BytesN<64>, recovery_id: u32) -> Result<(), ContractError> {
+    let attester: BytesN<65> = env.storage()
+        .instance()
+        .get(&AttestationKey::EvmAttester)
+        .expect("No EVM attester registered");
+    
+    let nonce: u64 = env.storage()
+        .instance()
+        .get(&AttestationKey::EvmAttesterNonce)
+        .unwrap_or(0);
+    if attestation.nonce != nonce {
+        panic!("Invalid attestation nonce");
+    }
+    
+    let message = (env.current_contract_address(), attestation.clone()).to_xdr(&env);
+    let digest = env.crypto().keccak256(&message);
+    if env.crypto().secp256k1_recover(&digest, &signature, recovery_id) != attester {
+        panic!("Attestation not signed by the EVM attester");
+    }
+    env.storage().instance().set(&AttestationKey::EvmAttesterNonce, &(nonce + 1));
+    
+    let project_id = attestation.project_id;
+    let milestone_index = attestation.milestone_index;
+    let mut project = storage::project(&env, project_id);
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    if milestone.status != MilestoneStatus::Pending {
+        panic!("Milestone not in pending state");
+    }
+    
+    if !in_sequence(&project, milestone_index) {
+        panic!("Earlier milestones are not complete");
+    }
+    
+    // The attested hash is recorded as the milestone's evidence, then the
+    // attester's finding goes through the regular decision path
+    milestone.evidence_hash = Some(attestation.evidence_hash.clone());
+    transition(&mut project, &mut milestone, MilestoneStatus::EvidenceSubmitted);
+    milestone.submitted_at = Some(env.ledger().timestamp());
+    project.milestones.set(milestone_index, milestone);
+    storage::set_project(&env, &project);
+    
+    decide_or_co_verify(&env, project_id, milestone_index, attestation.approved, None, &env.current_contract_address(), false)?;
+    
+    Ok(())
+}
+
+#[cfg(feature = "donations")]
+pub fn roll_over_milestone(env: Env, project_id: u32, milestone_index: u32) -> Result<(), ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    if project.refundable {
+        panic!("Project is refundable");
+    }
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if milestone_index >= project.milestones.len() {
+        panic!("{}", errors::INVALID_MILESTONE);
+    }
+    
+    let mut milestone = project.milestones.get(milestone_index).unwrap();
+    
+    if milestone_done(&project, &milestone) {
+        panic!("Milestone already settled");
+    }
+    
+    if env.ledger().timestamp() < refund_opens(&project, &milestone) {
+        panic!("Milestone deadline not yet expired");
+    }
+    
+    if escalation::under_vote(&env, project_id, milestone_index) {
+        panic!("Milestone is under investor vote");
+    }
+    
+    pool::withdraw(&env, &mut project)?;
+    
+    transition(&mut project, &mut milestone, MilestoneStatus::RolledOver);
+    let amount = milestone.amount;
+    milestone.amount = 0;
+    project.milestones.set(milestone_index, milestone);
+    let target = roll_funds(&env, &mut project, milestone_index, amount)?;
+    update_reputation(&env, &project.owner, |reputation| reputation.milestones_failed += 1);
+    
+    update_status(&env, &mut project)?;
+    storage::set_project(&env, &project);
+    pool::deposit_idle(&env, &project)?;
+    
+    env.events().publish(
+        (Symbol::new(&env, "milestone_rolled_over"), project_id),
+        (milestone_index, target, amount),
+    );
+    
+    Ok(())
+}
+
+pub fn get_milestone(env: Env, project_id: u32, milestone_index: u32) -> Milestone {
+    StellarBridgeContract::get_project(env, project_id)
+        .milestones
+        .get(milestone_index)
+        .expect(errors::INVALID_MILESTONE)
+}
+
+pub fn get_milestone_count(env: Env, project_id: u32) -> u32 {
+    StellarBridgeContract::get_project(env, project_id).milestones.len()
+}
+
+#[cfg(feature = "decision-log")]
+pub fn get_verification_decisions(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<VerificationDecision> {
+    storage::list_page(
+        &env,
+        &ListKey::DecisionCount(project_id),
+        |index| ListKey::Decision(project_id, index),
+        start,
+        limit,
+    )
+}
+
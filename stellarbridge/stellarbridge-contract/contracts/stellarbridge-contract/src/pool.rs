@@ -0,0 +1,199 @@
+// Idle escrow lent out to the lending pool the admin configured for the
+// project's token. Escrow is deposited as it comes in and the whole
+// position is withdrawn before any escrow is released or refunded, so the
+// rest of the contract only ever moves funds it holds; the yield realized
+// on each withdrawal is split between investors and the treasury. A pool
+// that stops paying out can be written off by the admin.
+
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{log, token, vec, Address, Env, IntoVal, Symbol};
+
+use crate::errors::ContractError;
+use crate::escrow::{accrued_payout, claim_payout, credit_payout};
+use crate::storage::{self, try_update_stats};
+use crate::types::{
+    LendingPoolClient, Payout, PoolKey, PoolPosition, Project, ProjectStatus, YieldPool,
+};
+use crate::{math, splitter, PAYOUT_SCALE};
+
+// Deposit whatever escrow the project holds outside its pool position,
+// provided the project is still raising or running and its token has a
+// pool configured. A position opened with an earlier pool is left as it
+// is until it is withdrawn.
+pub fn deposit_idle(env: &Env, project: &Project) -> Result<(), ContractError> {
+    if !project.active || !matches!(project.status, ProjectStatus::Funding | ProjectStatus::InProgress) {
+        return Ok(());
+    }
+    
+    let config: Option<YieldPool> = env.storage().instance().get(&PoolKey::YieldPool(project.token.clone()));
+    let Some(config) = config else {
+        return Ok(());
+    };
+    
+    let key = PoolKey::PoolPosition(project.id);
+    let mut position: PoolPosition = storage::get(env, &key)
+        .unwrap_or(PoolPosition { pool: config.pool.clone(), shares: 0, principal: 0 });
+    if position.pool != config.pool {
+        return Ok(());
+    }
+    
+    let idle = math::sub(project.escrow_balance, position.principal)?;
+    if idle <= 0 {
+        return Ok(());
+    }
+    
+    // The pool pulls the deposit from this contract
+    let contract = env.current_contract_address();
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: project.token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (contract.clone(), position.pool.clone(), idle).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    let shares = LendingPoolClient::new(env, &position.pool).deposit(&contract, &idle);
+    
+    position.shares = math::add(position.shares, shares)?;
+    position.principal = math::add(position.principal, idle)?;
+    storage::set(env, &key, &position);
+    
+    log!(env, "Deposited {} of project {} in its lending pool", idle, project.id);
+    
+    Ok(())
+}
+
+// Withdraw the project's whole pool position and return the yield it
+// realized. The investors' share of the yield becomes claimable pro-rata
+// and the rest goes to the treasury; a withdrawal worth less than was
+// deposited comes off the project's escrow.
+pub fn withdraw(env: &Env, project: &mut Project) -> Result<i128, ContractError> {
+    let key = PoolKey::PoolPosition(project.id);
+    let position: Option<PoolPosition> = storage::get(env, &key);
+    let Some(position) = position else {
+        return Ok(0);
+    };
+    storage::remove(env, &key);
+    
+    // A pool that cannot pay out blocks every release and refund of the
+    // project until the admin writes the position off
+    let pool = LendingPoolClient::new(env, &position.pool);
+    let withdrawn = match pool.try_withdraw(&env.current_contract_address(), &position.shares) {
+        Ok(Ok(withdrawn)) => withdrawn,
+        _ => panic!("Lending pool withdrawal failed"),
+    };
+    
+    if withdrawn < position.principal {
+        let loss = math::sub(position.principal, withdrawn)?;
+        project.escrow_balance = math::sub(project.escrow_balance, loss)?;
+        try_update_stats(env, |stats| {
+            stats.value_locked = math::sub(stats.value_locked, loss)?;
+            Ok(())
+        })?;
+        
+        log!(env, "Lending pool returned {} less than deposited for project {}", loss, project.id);
+        
+        return Ok(0);
+    }
+    
+    let earned = math::sub(withdrawn, position.principal)?;
+    if earned == 0 {
+        return Ok(0);
+    }
+    
+    let config: Option<YieldPool> = env.storage().instance().get(&PoolKey::YieldPool(project.token.clone()));
+    let investor_bps = config.map_or(splitter::BASIS_POINTS, |config| config.investor_bps);
+    let to_investors = math::mul_div(earned, investor_bps as i128, splitter::BASIS_POINTS as i128)?;
+    let to_treasury = math::sub(earned, to_investors)?;
+    
+    if to_investors > 0 {
+        credit_payout(env, Payout::Yield, project, to_investors)?;
+    }
+    if to_treasury > 0 {
+        let token_client = token::Client::new(env, &project.token);
+        token_client.transfer(&env.current_contract_address(), &storage::treasury(env), &to_treasury);
+    }
+    
+    env.events().publish(
+        (Symbol::new(env, "yield_realized"), project.id),
+        (to_investors, to_treasury),
+    );
+    
+    Ok(earned)
+}
+
+pub fn harvest_yield(env: Env, project_id: u32) -> Result<i128, ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    if !storage::has(&env, &PoolKey::PoolPosition(project_id)) {
+        panic!("Project has no lending pool position");
+    }
+    
+    let earned = withdraw(&env, &mut project)?;
+    storage::set_project(&env, &project);
+    deposit_idle(&env, &project)?;
+    
+    log!(&env, "Yield of {} harvested for project {}", earned, project_id);
+    
+    Ok(earned)
+}
+
+// Give up on a position whose pool will not pay out, taking what was
+// deposited off the project's escrow so its releases and refunds can go
+// ahead with what the contract still holds
+pub fn write_off_pool_position(env: Env, project_id: u32) -> Result<(), ContractError> {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    let key = PoolKey::PoolPosition(project_id);
+    let position: PoolPosition = storage::get(&env, &key).expect("Project has no lending pool position");
+    storage::remove(&env, &key);
+    
+    project.escrow_balance = math::sub(project.escrow_balance, position.principal)?;
+    try_update_stats(&env, |stats| {
+        stats.value_locked = math::sub(stats.value_locked, position.principal)?;
+        Ok(())
+    })?;
+    storage::set_project(&env, &project);
+    
+    env.events().publish(
+        (Symbol::new(&env, "pool_position_written_off"), project_id),
+        (position.pool, position.principal),
+    );
+    
+    Ok(())
+}
+
+pub fn claim_yield(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    let payout = claim_payout(&env, Payout::Yield, &project, &investor)?;
+    
+    if payout == 0 {
+        panic!("No yield to claim");
+    }
+    
+    log!(&env, "Yield of {} claimed from project {} by {}", payout, project_id, investor);
+    
+    Ok(payout)
+}
+
+pub fn get_claimable_yield(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    Ok(accrued_payout(&env, Payout::Yield, project_id, &investor)? / PAYOUT_SCALE)
+}
+
+pub fn get_yield_pool(env: Env, token: Address) -> Option<YieldPool> {
+    env.storage().instance().get(&PoolKey::YieldPool(token))
+}
+
+pub fn get_pool_position(env: Env, project_id: u32) -> Option<PoolPosition> {
+    storage::get(&env, &PoolKey::PoolPosition(project_id))
+}
@@ -0,0 +1,194 @@
+// Pre-flight checks: whether an actor may currently perform an action on a
+// project, worked out from the same role, state and deadline rules the
+// entrypoints enforce, without changing anything
+
+use soroban_sdk::{Address, Env};
+#[cfg(feature = "commitments")]
+use soroban_sdk::Vec;
+
+use crate::commitments;
+use crate::delegation;
+use crate::escalation;
+use crate::investments::investment_refusal;
+use crate::milestones::{accepts_evidence, in_sequence, milestone_done, project_verifier};
+use crate::refunds::{can_trigger_refund, refund_opens};
+#[cfg(feature = "revenue")]
+use crate::revenue;
+use crate::staking;
+use crate::storage;
+use crate::types::{Action, DataKey, MilestoneKind, MilestoneStatus, Project};
+#[cfg(feature = "commitments")]
+use crate::types::{Commitment, CommitmentKey};
+#[cfg(feature = "escalation")]
+use crate::types::EscalationKey;
+#[cfg(feature = "vesting")]
+use crate::vesting;
+use crate::StellarBridgeContract;
+
+pub fn can_perform(env: Env, actor: Address, action: Action, project_id: u32) -> bool {
+    let project: Project = match storage::get(&env, &DataKey::Project(project_id)) {
+        Some(project) => project,
+        None => return false,
+    };
+    
+    let milestone_status = |index: u32| project.milestones.get(index).map(|m| m.status);
+    
+    match action {
+        Action::Invest => {
+            // The smallest investment the project would take
+            let amount = project.min_investment.unwrap_or(1);
+            investment_refusal(&env, &project, &actor, amount).is_none()
+        }
+        Action::TransferPosition => {
+            project.active
+                && !escalation::votes_open(&env, project_id)
+                && StellarBridgeContract::get_investor_amount(env, project_id, actor) > 0
+        }
+        Action::SubmitEvidence(index) => {
+            actor == project.owner
+                && project.active
+                && milestone_status(index) == Some(MilestoneStatus::Pending)
+                && project.milestones.get(index).is_some_and(|m| {
+                    m.kind == MilestoneKind::Evidence && accepts_evidence(&env, &project, &m)
+                })
+                && in_sequence(&project, index)
+        }
+        Action::ResubmitEvidence(index) => match project.milestones.get(index) {
+            Some(milestone) => {
+                actor == project.owner
+                    && project.active
+                    && milestone.status == MilestoneStatus::Rejected
+                    && milestone.resubmissions < project.max_resubmissions
+                    && accepts_evidence(&env, &project, &milestone)
+            }
+            None => false,
+        },
+        Action::VerifyMilestone(index) => {
+            let verifier = project_verifier(&env, &project);
+            (verifier == actor || delegation::is_delegate(&env, project_id, index, &actor))
+                && project.active
+                && milestone_status(index) == Some(MilestoneStatus::EvidenceSubmitted)
+                && !escalation::under_vote(&env, project_id, index)
+                && in_sequence(&project, index)
+                && staking::stake_sufficient(&env, &verifier)
+        }
+        Action::TriggerRefund(_) if storage::has(&env, &DataKey::RefundProgress(project_id)) => true,
+        Action::TriggerRefund(index) => match project.milestones.get(index) {
+            Some(milestone) => {
+                project.refundable
+                    && project.active
+                    && can_trigger_refund(&env, project_id, &actor)
+                    && env.ledger().timestamp() >= refund_opens(&project, &milestone)
+                    && milestone.status != MilestoneStatus::Verified
+            }
+            None => false,
+        },
+        Action::RollOverMilestone(index) => match project.milestones.get(index) {
+            Some(milestone) => {
+                !project.refundable
+                    && project.active
+                    && !milestone_done(&project, &milestone)
+                    && env.ledger().timestamp() >= refund_opens(&project, &milestone)
+                    && !escalation::under_vote(&env, project_id, index)
+            }
+            None => false,
+        },
+        Action::UpdateMilestones => actor == project.owner && project.active && project.raised == 0,
+        Action::CancelProject => {
+            actor == project.owner
+                && project.active
+                && !project.milestones.iter().any(|m| m.status == MilestoneStatus::Verified)
+        }
+        #[cfg(feature = "revenue")]
+        Action::DepositRevenue => actor == project.owner && project.raised > 0,
+        #[cfg(not(feature = "revenue"))]
+        Action::DepositRevenue => false,
+        #[cfg(feature = "revenue")]
+        Action::ClaimRevenue => StellarBridgeContract::get_claimable_revenue(env, project_id, actor).is_ok_and(|amount| amount > 0),
+        #[cfg(not(feature = "revenue"))]
+        Action::ClaimRevenue => false,
+        Action::ClaimRefund => StellarBridgeContract::get_claimable_refund(env, project_id, actor).is_ok_and(|amount| amount > 0),
+        Action::ClaimSurplus => StellarBridgeContract::get_claimable_surplus(env, project_id, actor).is_ok_and(|amount| amount > 0),
+        #[cfg(feature = "yield-pool")]
+        Action::ClaimYield => StellarBridgeContract::get_claimable_yield(env, project_id, actor).is_ok_and(|amount| amount > 0),
+        #[cfg(not(feature = "yield-pool"))]
+        Action::ClaimYield => false,
+        #[cfg(feature = "vesting")]
+        Action::ClaimVested => actor == project.owner && vesting::claimable_vested(&env, &project).is_ok_and(|amount| amount > 0),
+        #[cfg(not(feature = "vesting"))]
+        Action::ClaimVested => false,
+        #[cfg(feature = "revenue")]
+        Action::ExpireRevenue(investor) => match project.revenue_expiry {
+            Some(expiry) => {
+                revenue::revenue_dormant(&env, project_id, &investor, expiry)
+                    && StellarBridgeContract::get_claimable_revenue(env.clone(), project_id, investor).is_ok_and(|amount| amount > 0)
+            }
+            None => false,
+        },
+        #[cfg(not(feature = "revenue"))]
+        Action::ExpireRevenue(_) => false,
+        #[cfg(feature = "escalation")]
+        Action::EscalateMilestone(index) => match project.milestones.get(index) {
+            Some(milestone) => {
+                project.active
+                    && escalation::escalation_due(&env, &project, &milestone)
+                    && !escalation::under_vote(&env, project_id, index)
+            }
+            None => false,
+        },
+        #[cfg(not(feature = "escalation"))]
+        Action::EscalateMilestone(_) => false,
+        #[cfg(feature = "escalation")]
+        Action::VoteOnMilestone(index) => match StellarBridgeContract::get_milestone_vote(env.clone(), project_id, index) {
+            Some(vote) => {
+                let voted: Option<u64> = storage::get(&env, &EscalationKey::Voted(project_id, index, actor.clone()));
+                env.ledger().timestamp() < vote.ends_at
+                    && voted != Some(vote.ends_at)
+                    && StellarBridgeContract::get_investor_amount(env, project_id, actor) > 0
+            }
+            None => false,
+        },
+        #[cfg(not(feature = "escalation"))]
+        Action::VoteOnMilestone(_) => false,
+        #[cfg(feature = "escalation")]
+        Action::FinalizeMilestoneVote(index) => match StellarBridgeContract::get_milestone_vote(env.clone(), project_id, index) {
+            Some(vote) => project.active && env.ledger().timestamp() >= vote.ends_at,
+            None => false,
+        },
+        #[cfg(not(feature = "escalation"))]
+        Action::FinalizeMilestoneVote(_) => false,
+        Action::CheckOracleMilestone(index) => match project.milestones.get(index) {
+            Some(milestone) => {
+                project.active
+                    && milestone.status == MilestoneStatus::Pending
+                    && matches!(milestone.kind, MilestoneKind::OracleVerified(_))
+                    && in_sequence(&project, index)
+            }
+            None => false,
+        },
+        Action::ArchiveProject => {
+            let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+            let finished = project.milestones.iter().all(|m| milestone_done(&project, &m));
+            admin == Some(actor)
+                && !project.archived
+                && (!project.active || finished)
+                && !storage::has(&env, &DataKey::RefundProgress(project_id))
+                && !escalation::votes_open(&env, project_id)
+                && !commitments::pending(&env, project_id)
+        }
+        #[cfg(feature = "commitments")]
+        Action::SettleCommitments => {
+            let commitments: Vec<Commitment> = storage::get(&env, &CommitmentKey::Commitments(project_id))
+                .unwrap_or(Vec::new(&env));
+            let now = env.ledger().timestamp();
+            commitments.iter().any(|c| !project.active || now > c.expires_at)
+        }
+        #[cfg(not(feature = "commitments"))]
+        Action::SettleCommitments => false,
+        Action::ManageProject => actor == project.owner,
+        #[cfg(feature = "whitelist")]
+        Action::ManageWhitelist => actor == project.compliance.unwrap_or(project.owner),
+        #[cfg(not(feature = "whitelist"))]
+        Action::ManageWhitelist => false,
+    }
+}
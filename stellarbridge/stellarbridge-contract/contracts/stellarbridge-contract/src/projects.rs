@@ -0,0 +1,563 @@
+// Project lifecycle outside of milestones and money: creation, owner
+// settings and the read-only views
+
+#[cfg(feature = "updates")]
+use soroban_sdk::BytesN;
+use soroban_sdk::{log, Address, Env, Symbol, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::escalation;
+use crate::milestones::build_milestones;
+use crate::review;
+use crate::shares;
+use crate::storage::{self, update_reputation, update_stats};
+use crate::types::{
+    DataKey, Escalation, Escheatment, LatePolicy, Project, ProjectMetadata, ProjectStatus,
+    VerificationFee,
+};
+#[cfg(feature = "fees")]
+use crate::types::{FeeKey, FeeStrategy};
+#[cfg(feature = "goal-bounds")]
+use crate::types::{GoalBounds, GoalBoundsKey};
+#[cfg(feature = "late-policy")]
+use crate::types::LatePolicyKey;
+#[cfg(feature = "listings")]
+use crate::types::{ListingKey, MilestoneStatus, ProjectSummary};
+#[cfg(feature = "refund-grace")]
+use crate::types::RefundGraceKey;
+#[cfg(feature = "reputation")]
+use crate::types::{OwnerReputation, ReputationKey};
+#[cfg(feature = "resubmissions")]
+use crate::types::ResubmissionKey;
+#[cfg(feature = "updates")]
+use crate::types::{ProjectUpdate, UpdateKey};
+use crate::verifiers;
+#[cfg(feature = "fees")]
+use crate::{fee, DEFAULT_CATEGORY};
+#[cfg(feature = "payout-splits")]
+use crate::splitter;
+#[cfg(feature = "listings")]
+use crate::MAX_PAGE_SIZE;
+use crate::{StellarBridgeContract, MAX_NAME_LEN, MAX_TAGS, MAX_URI_LEN};
+
+pub fn validate_metadata(metadata: &ProjectMetadata) {
+    if metadata.name.is_empty() || metadata.name.len() > MAX_NAME_LEN {
+        panic!("Invalid project name length");
+    }
+    
+    if metadata.description_uri.len() > MAX_URI_LEN {
+        panic!("Description URI too long");
+    }
+    
+    if metadata.tags.len() > MAX_TAGS {
+        panic!("Too many tags");
+    }
+    
+    for i in 1..metadata.tags.len() {
+        if metadata.tags.slice(0..i).contains(metadata.tags.get(i).unwrap()) {
+            panic!("Duplicate tag");
+        }
+    }
+}
+
+// Add a project to its category's listing index, noting where it went
+#[cfg(feature = "listings")]
+fn index_category(env: &Env, category: &Symbol, project_id: u32) {
+    let index = storage::list_push(
+        env,
+        &ListingKey::CategoryCount(category.clone()),
+        |index| ListingKey::CategoryProject(category.clone(), index),
+        &project_id,
+    );
+    storage::set(env, &ListingKey::CategorySlot(project_id), &index);
+}
+
+// Take a project out of its category's listing index, moving the category's
+// last project into its place
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+fn unindex_category(env: &Env, category: &Symbol, project_id: u32) {
+    let slot_key = ListingKey::CategorySlot(project_id);
+    let Some(index) = storage::get::<_, u32>(env, &slot_key) else {
+        return;
+    };
+    
+    let count_key = ListingKey::CategoryCount(category.clone());
+    let last = storage::list_len(env, &count_key) - 1;
+    let last_key = ListingKey::CategoryProject(category.clone(), last);
+    if index != last {
+        let moved: u32 = storage::get(env, &last_key).unwrap();
+        storage::set(env, &ListingKey::CategoryProject(category.clone(), index), &moved);
+        storage::set(env, &ListingKey::CategorySlot(moved), &index);
+    }
+    storage::remove(env, &last_key);
+    storage::remove(env, &slot_key);
+    if last == 0 {
+        storage::remove(env, &count_key);
+    } else {
+        storage::set(env, &count_key, &last);
+    }
+}
+
+#[cfg(feature = "listings")]
+pub fn project_summary(env: &Env, project: &Project) -> ProjectSummary {
+    let metadata: Option<ProjectMetadata> = storage::get(env, &DataKey::ProjectMetadata(project.id));
+    let milestones_verified = project.milestones
+        .iter()
+        .filter(|m| m.status == MilestoneStatus::Verified)
+        .count() as u32;
+    
+    ProjectSummary {
+        id: project.id,
+        owner: project.owner.clone(),
+        name: metadata.map(|metadata| metadata.name),
+        token: project.token.clone(),
+        goal_amount: project.goal_amount,
+        raised: project.raised,
+        milestones: project.milestones.len(),
+        milestones_verified,
+        active: project.active,
+        pending_approval: project.pending_approval,
+        archived: project.archived,
+        status: project.status,
+        refundable: project.refundable,
+    }
+}
+
+// The fees a new project is listed with: the verification fee in force and
+// the platform fee its category's strategy fixes at listing; projects
+// without a category pay the default category's
+#[cfg(feature = "fees")]
+fn listing_fees(env: &Env, metadata: Option<&ProjectMetadata>, goal_amount: i128) -> (VerificationFee, u32) {
+    let verification_fee: VerificationFee = env.storage()
+        .instance()
+        .get(&FeeKey::VerificationFee)
+        .unwrap_or(VerificationFee::Free);
+    
+    let category = match metadata {
+        Some(metadata) => metadata.category.clone(),
+        None => Symbol::new(env, DEFAULT_CATEGORY),
+    };
+    let listings_key = FeeKey::CategoryListings(category.clone());
+    let listed: u32 = storage::get(env, &listings_key).unwrap_or(0);
+    storage::set(env, &listings_key, &(listed + 1));
+    let strategy: FeeStrategy = env.storage()
+        .instance()
+        .get(&FeeKey::CategoryFee(category))
+        .unwrap_or(FeeStrategy::Free);
+    
+    (verification_fee, fee::rate(&strategy, goal_amount, listed))
+}
+
+// Refuse a goal outside the range the admin set for its token
+#[cfg(feature = "goal-bounds")]
+fn check_goal_bounds(env: &Env, token: &Address, goal_amount: i128) {
+    let bounds: Option<GoalBounds> = env.storage().instance().get(&GoalBoundsKey::GoalBounds(token.clone()));
+    if let Some(bounds) = bounds {
+        if bounds.min.is_some_and(|min| goal_amount < min) {
+            panic!("Goal amount below minimum for token");
+        }
+        if bounds.max.is_some_and(|max| goal_amount > max) {
+            panic!("Goal amount above maximum for token");
+        }
+    }
+}
+
+pub fn create_project(
+    env: Env,
+    owner: Address,
+    token: Address,
+    goal_amount: i128,
+    milestone_amounts: Vec<i128>,
+    milestone_deadlines: Vec<u64>,
+    metadata: Option<ProjectMetadata>,
+) -> Result<u32, ContractError> {
+    owner.require_auth();
+    
+    if !StellarBridgeContract::is_token_allowed(env.clone(), token.clone()) {
+        panic!("{}", errors::TOKEN_NOT_ALLOWED);
+    }
+    
+    #[cfg(feature = "goal-bounds")]
+    check_goal_bounds(&env, &token, goal_amount);
+    
+    let milestones = build_milestones(&env, goal_amount, &milestone_amounts, &milestone_deadlines)?;
+    
+    let mut counter: u32 = env.storage().instance().get(&DataKey::ProjectCounter).unwrap_or(0);
+    counter += 1;
+    
+    let share_token = shares::deploy_share_token(&env, counter, &token);
+    let pending_approval = review::pending_at_listing(&env, metadata.as_ref());
+    #[cfg(feature = "resubmissions")]
+    let max_resubmissions: u32 = env.storage()
+        .instance()
+        .get(&ResubmissionKey::MaxResubmissions)
+        .unwrap_or(0);
+    #[cfg(not(feature = "resubmissions"))]
+    let max_resubmissions = 0;
+    let verification_window = escalation::verification_window(&env);
+    #[cfg(feature = "late-policy")]
+    let late_policy: LatePolicy = env.storage()
+        .instance()
+        .get(&LatePolicyKey::LatePolicy)
+        .unwrap_or(LatePolicy { grace: 0, max_fee_bps: 0 });
+    #[cfg(not(feature = "late-policy"))]
+    let late_policy = LatePolicy { grace: 0, max_fee_bps: 0 };
+    #[cfg(feature = "refund-grace")]
+    let refund_grace: u64 = env.storage()
+        .instance()
+        .get(&RefundGraceKey::RefundGrace)
+        .unwrap_or(0);
+    #[cfg(not(feature = "refund-grace"))]
+    let refund_grace = 0;
+    
+    #[cfg(feature = "listings")]
+    if let Some(metadata) = &metadata {
+        index_category(&env, &metadata.category, counter);
+    }
+    #[cfg(feature = "fees")]
+    let (verification_fee, platform_fee_bps) = listing_fees(&env, metadata.as_ref(), goal_amount);
+    #[cfg(not(feature = "fees"))]
+    let (verification_fee, platform_fee_bps) = (VerificationFee::Free, 0);
+    
+    let project = Project {
+        id: counter,
+        owner: owner.clone(),
+        goal_amount,
+        raised: 0,
+        escrow_balance: 0,
+        milestones,
+        active: true,
+        share_token,
+        token,
+        whitelist_only: false,
+        compliance: None,
+        min_investment: None,
+        max_per_investor: None,
+        max_resubmissions,
+        recipients: Vec::new(&env),
+        vesting_days: None,
+        sequential: false,
+        pending_approval,
+        verification_window,
+        escalation: Escalation::Refund,
+        revenue_expiry: None,
+        escheatment: Escheatment::NextRound,
+        verification_fee,
+        late_policy,
+        verifier: verifiers::assign_verifier(&env, &owner),
+        archived: false,
+        platform_fee_bps,
+        status: ProjectStatus::Funding,
+        refund_grace,
+        version: 0,
+        refundable: true,
+        beneficiary: None,
+    };
+    
+    storage::set_project(&env, &project);
+    env.storage().instance().set(&DataKey::ProjectCounter, &counter);
+    
+    update_stats(&env, |stats| stats.active_projects += 1);
+    update_reputation(&env, &owner, |reputation| reputation.projects_created += 1);
+    
+    #[cfg(feature = "listings")]
+    storage::list_push(
+        &env,
+        &ListingKey::OwnerProjectCount(owner.clone()),
+        |index| ListingKey::OwnerProject(owner.clone(), index),
+        &counter,
+    );
+    
+    if let Some(metadata) = metadata {
+        validate_metadata(&metadata);
+        storage::set(&env, &DataKey::ProjectMetadata(counter), &metadata);
+    }
+    
+    if let Some(verifier) = &project.verifier {
+        env.events().publish(
+            (Symbol::new(&env, "verifier_assigned"), counter),
+            verifier.clone(),
+        );
+    }
+    
+    log!(&env, "Project {} created by {}", counter, owner);
+    
+    Ok(counter)
+}
+
+#[cfg(feature = "payout-splits")]
+pub fn set_payout_recipients(env: Env, project_id: u32, recipients: Vec<(Address, u32)>) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !recipients.is_empty() {
+        splitter::validate(&recipients);
+    }
+    
+    project.recipients = recipients;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Payout recipients updated for project {}", project_id);
+}
+
+#[cfg(feature = "sequential")]
+pub fn set_sequential(env: Env, project_id: u32, sequential: bool) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    project.sequential = sequential;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Sequential milestones for project {} set to {}", project_id, sequential);
+}
+
+#[cfg(feature = "donations")]
+pub fn set_refundable(env: Env, project_id: u32, refundable: bool, beneficiary: Option<Address>) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if !refundable && beneficiary.is_none() {
+        panic!("Donation mode needs a beneficiary");
+    }
+    
+    project.refundable = refundable;
+    project.beneficiary = if refundable { None } else { beneficiary };
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Project {} refundable set to {}", project_id, refundable);
+}
+
+#[cfg(feature = "escalation")]
+pub fn set_escalation(env: Env, project_id: u32, escalation: Escalation) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    project.escalation = escalation;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Escalation for project {} updated", project_id);
+}
+
+#[cfg(feature = "revenue")]
+pub fn set_revenue_expiry(env: Env, project_id: u32, expiry: Option<u64>, escheatment: Escheatment) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if expiry == Some(0) {
+        panic!("Revenue expiry must be positive");
+    }
+    
+    project.revenue_expiry = expiry;
+    project.escheatment = escheatment;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Revenue expiry for project {} set to {} seconds", project_id, expiry.unwrap_or(0));
+}
+
+#[cfg(feature = "project-edits")]
+pub fn update_project_metadata(env: Env, project_id: u32, metadata: ProjectMetadata) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    validate_metadata(&metadata);
+    
+    // Moving into a reviewed category needs a fresh listing approval
+    let previous: Option<ProjectMetadata> = storage::get(&env, &DataKey::ProjectMetadata(project_id));
+    let category_changed = previous.as_ref().is_none_or(|previous| previous.category != metadata.category);
+    if category_changed && review::needs_review(&env, &metadata) {
+        project.pending_approval = true;
+        storage::set_project(&env, &project);
+    }
+    #[cfg(feature = "listings")]
+    if category_changed {
+        if let Some(previous) = &previous {
+            unindex_category(&env, &previous.category, project_id);
+        }
+        index_category(&env, &metadata.category, project_id);
+    }
+    
+    storage::set(&env, &DataKey::ProjectMetadata(project_id), &metadata);
+    
+    log!(&env, "Metadata updated for project {}", project_id);
+}
+
+#[cfg(feature = "investment-limits")]
+pub fn set_investment_limits(
+    env: Env,
+    project_id: u32,
+    min_investment: Option<i128>,
+    max_per_investor: Option<i128>,
+) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if min_investment.is_some_and(|min| min <= 0) || max_per_investor.is_some_and(|max| max <= 0) {
+        panic!("Investment limits must be positive");
+    }
+    
+    if let (Some(min), Some(max)) = (min_investment, max_per_investor) {
+        if min > max {
+            panic!("Minimum investment exceeds per-investor maximum");
+        }
+    }
+    
+    project.min_investment = min_investment;
+    project.max_per_investor = max_per_investor;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Investment limits updated for project {}", project_id);
+}
+
+#[cfg(feature = "updates")]
+pub fn post_update(env: Env, project_id: u32, content_hash: BytesN<32>) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    let update = ProjectUpdate {
+        content_hash,
+        timestamp: env.ledger().timestamp(),
+    };
+    let index = storage::list_push(
+        &env,
+        &UpdateKey::UpdateCount(project_id),
+        |index| UpdateKey::Update(project_id, index),
+        &update,
+    );
+    
+    log!(&env, "Update {} posted for project {}", index, project_id);
+}
+
+pub fn get_project(env: Env, project_id: u32) -> Project {
+    storage::project(&env, project_id)
+}
+
+#[cfg(feature = "listings")]
+pub fn get_projects_by_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+    storage::list_page(
+        &env,
+        &ListingKey::OwnerProjectCount(owner.clone()),
+        |index| ListingKey::OwnerProject(owner.clone(), index),
+        start,
+        limit,
+    )
+}
+
+#[cfg(feature = "reputation")]
+pub fn get_owner_reputation(env: Env, owner: Address) -> OwnerReputation {
+    storage::get(&env, &ReputationKey::OwnerReputation(owner))
+        .unwrap_or(OwnerReputation {
+            projects_created: 0,
+            milestones_verified: 0,
+            milestones_failed: 0,
+            refunds_triggered: 0,
+        })
+}
+
+pub fn get_project_metadata(env: Env, project_id: u32) -> Option<ProjectMetadata> {
+    if !storage::has(&env, &DataKey::Project(project_id)) {
+        panic!("{}", errors::PROJECT_NOT_FOUND);
+    }
+    
+    storage::get(&env, &DataKey::ProjectMetadata(project_id))
+}
+
+#[cfg(feature = "updates")]
+pub fn get_project_updates(env: Env, project_id: u32, start: u32, limit: u32) -> Vec<ProjectUpdate> {
+    storage::list_page(
+        &env,
+        &UpdateKey::UpdateCount(project_id),
+        |index| UpdateKey::Update(project_id, index),
+        start,
+        limit,
+    )
+}
+
+#[cfg(feature = "listings")]
+pub fn get_project_summary(env: Env, project_id: u32) -> ProjectSummary {
+    let project = StellarBridgeContract::get_project(env.clone(), project_id);
+    project_summary(&env, &project)
+}
+
+#[cfg(feature = "listings")]
+pub fn list_projects(env: Env, start: u32, limit: u32) -> Vec<ProjectSummary> {
+    let count = StellarBridgeContract::get_project_count(env.clone());
+    let start = start.min(count);
+    let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+    
+    let mut summaries = Vec::new(&env);
+    for project_id in (start + 1)..=end {
+        let project: Project = storage::get(&env, &DataKey::Project(project_id)).unwrap();
+        summaries.push_back(project_summary(&env, &project));
+    }
+    summaries
+}
+
+#[cfg(feature = "listings")]
+pub fn list_active_projects(env: Env, start: u32, limit: u32) -> Vec<ProjectSummary> {
+    let mut summaries = Vec::new(&env);
+    for summary in StellarBridgeContract::list_projects(env, start, limit).iter() {
+        if summary.active && !summary.pending_approval {
+            summaries.push_back(summary);
+        }
+    }
+    summaries
+}
+
+#[cfg(feature = "listings")]
+pub fn list_projects_by_status(env: Env, status: ProjectStatus, start: u32, limit: u32) -> Vec<ProjectSummary> {
+    let mut summaries = Vec::new(&env);
+    for summary in StellarBridgeContract::list_projects(env, start, limit).iter() {
+        if summary.status == status {
+            summaries.push_back(summary);
+        }
+    }
+    summaries
+}
+
+#[cfg(feature = "listings")]
+pub fn list_projects_by_category(env: Env, category: Symbol, start: u32, limit: u32) -> Vec<ProjectSummary> {
+    let projects: Vec<u32> = storage::list_page(
+        &env,
+        &ListingKey::CategoryCount(category.clone()),
+        |index| ListingKey::CategoryProject(category.clone(), index),
+        start,
+        limit,
+    );
+    
+    let mut summaries = Vec::new(&env);
+    for project_id in projects.iter() {
+        summaries.push_back(project_summary(&env, &storage::project(&env, project_id)));
+    }
+    summaries
+}
+
+pub fn get_project_count(env: Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProjectCounter)
+        .unwrap_or(0)
+}
@@ -0,0 +1,187 @@
+// Referral rewards: a project's owner offers a share of each investment to
+// whoever referred the investor. Rewards out of a budget are escrowed in
+// full before they accrue; rewards out of the platform fee are set aside
+// as releases charge it, so referrers may have to wait for a release, and
+// whoever claims first is paid first
+
+use soroban_sdk::{token, Address, Env, Symbol};
+
+use crate::errors::{self, ContractError};
+use crate::storage;
+use crate::types::{Project, ReferralFunding, ReferralKey, ReferralProgram};
+use crate::{math, splitter};
+
+fn program(env: &Env, project_id: u32) -> ReferralProgram {
+    storage::get(env, &ReferralKey::ReferralProgram(project_id)).expect("No referral program")
+}
+
+fn set_program(env: &Env, project_id: u32, program: &ReferralProgram) {
+    storage::set(env, &ReferralKey::ReferralProgram(project_id), program);
+    storage::extend_instance(env);
+}
+
+pub fn set_referral_program(env: Env, project_id: u32, bps: u32, funding: ReferralFunding) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if bps == 0 || bps > splitter::BASIS_POINTS {
+        panic!("Invalid referral reward");
+    }
+    
+    if storage::has(&env, &ReferralKey::ReferralProgram(project_id)) {
+        panic!("Project already has a referral program");
+    }
+    
+    let funded = match funding {
+        ReferralFunding::PlatformFee => 0,
+        ReferralFunding::Budget(budget) => {
+            if budget <= 0 {
+                panic!("Referral budget must be positive");
+            }
+            let token_client = token::Client::new(&env, &project.token);
+            token_client.transfer(&project.owner, &env.current_contract_address(), &budget);
+            budget
+        }
+    };
+    
+    set_program(&env, project_id, &ReferralProgram {
+        bps,
+        funding,
+        accrued: 0,
+        funded,
+        paid: 0,
+        active: true,
+    });
+    
+    env.events().publish(
+        (Symbol::new(&env, "referral_program_set"), project_id),
+        (bps, funding),
+    );
+}
+
+pub fn end_referral_program(env: Env, project_id: u32) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    let mut program = program(&env, project_id);
+    
+    if !program.active {
+        panic!("Referral program already ended");
+    }
+    
+    // Rewards already accrued stay payable; the rest of a budget goes back
+    program.active = false;
+    if let ReferralFunding::Budget(_) = program.funding {
+        let unspent = math::sub(program.funded, program.accrued)?;
+        if unspent > 0 {
+            let token_client = token::Client::new(&env, &project.token);
+            token_client.transfer(&env.current_contract_address(), &project.owner, &unspent);
+        }
+        program.funded = program.accrued;
+    }
+    set_program(&env, project_id, &program);
+    
+    env.events().publish(
+        (Symbol::new(&env, "referral_program_ended"), project_id),
+        program.accrued,
+    );
+    
+    Ok(())
+}
+
+// Accrue the referrer's reward for an investment, if the project runs a
+// referral program; a budget caps the rewards at what is left of it
+pub fn accrue(env: &Env, project: &Project, investor: &Address, referrer: &Address, amount: i128) -> Result<(), ContractError> {
+    if referrer == investor {
+        panic!("Investor cannot refer themselves");
+    }
+    
+    let key = ReferralKey::ReferralProgram(project.id);
+    let program: Option<ReferralProgram> = storage::get(env, &key);
+    let Some(mut program) = program.filter(|program| program.active) else {
+        return Ok(());
+    };
+    
+    let mut reward = math::mul_div(amount, program.bps as i128, splitter::BASIS_POINTS as i128)?;
+    if let ReferralFunding::Budget(_) = program.funding {
+        reward = reward.min(math::sub(program.funded, program.accrued)?);
+    }
+    if reward <= 0 {
+        return Ok(());
+    }
+    
+    program.accrued = math::add(program.accrued, reward)?;
+    set_program(env, project.id, &program);
+    
+    let reward_key = ReferralKey::ReferralReward(project.id, referrer.clone());
+    let owed: i128 = storage::get(env, &reward_key).unwrap_or(0);
+    storage::set(env, &reward_key, &math::add(owed, reward)?);
+    
+    env.events().publish(
+        (Symbol::new(env, "referral_accrued"), project.id),
+        (referrer.clone(), investor.clone(), reward),
+    );
+    
+    Ok(())
+}
+
+// Set aside as much of a release's platform fee as the project's
+// fee-funded rewards still need; returns the amount kept back from the
+// treasury
+pub fn fund_from_fee(env: &Env, project: &Project, platform_fee: i128) -> Result<i128, ContractError> {
+    let key = ReferralKey::ReferralProgram(project.id);
+    let program: Option<ReferralProgram> = storage::get(env, &key);
+    let Some(mut program) = program.filter(|program| program.funding == ReferralFunding::PlatformFee) else {
+        return Ok(0);
+    };
+    
+    let kept = platform_fee.min(math::sub(program.accrued, program.funded)?);
+    if kept > 0 {
+        program.funded = math::add(program.funded, kept)?;
+        set_program(env, project.id, &program);
+    }
+    Ok(kept)
+}
+
+pub fn claim_referral_rewards(env: Env, referrer: Address, project_id: u32) -> Result<i128, ContractError> {
+    referrer.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    let mut program = program(&env, project_id);
+    
+    let reward_key = ReferralKey::ReferralReward(project_id, referrer.clone());
+    let owed: i128 = storage::get(&env, &reward_key).unwrap_or(0);
+    let payout = owed.min(math::sub(program.funded, program.paid)?);
+    
+    if payout <= 0 {
+        panic!("No referral rewards to claim");
+    }
+    
+    program.paid = math::add(program.paid, payout)?;
+    set_program(&env, project_id, &program);
+    storage::set(&env, &reward_key, &math::sub(owed, payout)?);
+    
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&env.current_contract_address(), &referrer, &payout);
+    
+    env.events().publish(
+        (Symbol::new(&env, "referral_claimed"), project_id),
+        (referrer, payout),
+    );
+    
+    Ok(payout)
+}
+
+pub fn get_referral_program(env: Env, project_id: u32) -> Option<ReferralProgram> {
+    storage::get(&env, &ReferralKey::ReferralProgram(project_id))
+}
+
+pub fn get_referral_rewards(env: Env, project_id: u32, referrer: Address) -> i128 {
+    storage::get(&env, &ReferralKey::ReferralReward(project_id, referrer)).unwrap_or(0)
+}
@@ -0,0 +1,268 @@
+// Refunds of unverified milestones and cancelled projects, and the
+// investor claims against them
+
+use soroban_sdk::{log, token, Address, Env, Symbol, Vec};
+
+use crate::disputes::withdraw_held_releases;
+use crate::errors::{self, ContractError};
+use crate::escrow::{accrued_payout, claim_payout, give_back, release_surplus};
+use crate::hooks;
+use crate::milestones::{ended, grace_end, rejection_is_final};
+use crate::pool;
+use crate::shares;
+use crate::storage::{self, try_update_stats, update_reputation};
+use crate::types::{
+    DataKey, Investment, Milestone, MilestoneStatus, Payout, Project, ProjectStatus,
+    RefundProgress, RefundState,
+};
+use crate::{math, MAX_REFUND_STEPS, PAYOUT_SCALE};
+
+// When a refund can first be triggered for a milestone: the project's
+// refund grace after evidence stops being accepted, leaving time for
+// evidence that came in late to be verified
+pub fn refund_opens(project: &Project, milestone: &Milestone) -> u64 {
+    grace_end(project, milestone).saturating_add(project.refund_grace)
+}
+
+// Whether `caller` may start a project's refund: the admin or anyone
+// holding a position in it
+pub fn can_trigger_refund(env: &Env, project_id: u32, caller: &Address) -> bool {
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    if admin.as_ref() == Some(caller) {
+        return true;
+    }
+    
+    let position: i128 = storage::get(env, &DataKey::InvestorAmount(project_id, caller.clone())).unwrap_or(0);
+    position > 0
+}
+
+pub fn trigger_refund(env: Env, caller: Address, project_id: u32, milestone_index: u32) -> Result<RefundProgress, ContractError> {
+    caller.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    let progress_key = DataKey::RefundProgress(project_id);
+    let mut progress: RefundState = match storage::get(&env, &progress_key) {
+        Some(progress) => progress,
+        None => {
+            if !project.refundable {
+                panic!("{}", errors::NOT_REFUNDABLE);
+            }
+            
+            if !project.active {
+                panic!("{}", errors::PROJECT_NOT_ACTIVE);
+            }
+            
+            if milestone_index >= project.milestones.len() {
+                panic!("{}", errors::INVALID_MILESTONE);
+            }
+            
+            let milestone = project.milestones.get(milestone_index).unwrap();
+            
+            if !can_trigger_refund(&env, project_id, &caller) {
+                panic!("Only the admin or an investor can trigger a refund");
+            }
+            
+            if env.ledger().timestamp() < refund_opens(&project, &milestone) {
+                panic!("Milestone deadline not yet expired");
+            }
+            
+            if milestone.status == MilestoneStatus::Verified {
+                panic!("Milestone already verified");
+            }
+            
+            pool::withdraw(&env, &mut project)?;
+            
+            // Calculate refund amount (proportional to unverified milestones,
+            // including any before the one that expired, since the project
+            // ends and nothing else would return their escrow); finally
+            // rejected milestones are already claimable through claim_refund.
+            // A project that raised less than its milestones add up to can
+            // only refund what it still holds.
+            let mut unverified_amount: i128 = 0;
+            for i in 0..project.milestones.len() {
+                let m = project.milestones.get(i).unwrap();
+                if m.status != MilestoneStatus::Verified && !rejection_is_final(&project, &m) {
+                    unverified_amount = math::add(unverified_amount, m.amount)?;
+                }
+            }
+            let unverified_amount = unverified_amount.min(project.escrow_balance);
+            project.escrow_balance = math::sub(project.escrow_balance, unverified_amount)?;
+            
+            try_update_stats(&env, |stats| {
+                stats.total_refunded = math::add(stats.total_refunded, unverified_amount)?;
+                stats.value_locked = math::sub(stats.value_locked, unverified_amount)?;
+                Ok(())
+            })?;
+            
+            // Releases still held for the dispute window never reach the
+            // owner of a refunded project
+            let unverified_amount = math::add(unverified_amount, withdraw_held_releases(&env, &project)?)?;
+            if !ended(&project) {
+                storage::end_active_project(&env);
+            }
+            update_reputation(&env, &project.owner, |reputation| reputation.refunds_triggered += 1);
+            project.active = false;
+            project.status = ProjectStatus::Refunded;
+            release_surplus(&env, &mut project)?;
+            storage::set_project(&env, &project);
+            
+            env.events().publish(
+                (Symbol::new(&env, "refund_triggered"), project_id),
+                (caller.clone(), milestone_index, unverified_amount),
+            );
+            
+            RefundState { unverified_amount, cursor: 0 }
+        }
+    };
+    
+    // Refund proportionally to each investor's current position
+    let investments: Vec<Investment> = storage::get(&env, &DataKey::ProjectInvestments(project_id))
+        .unwrap_or(Vec::new(&env));
+    
+    let token_client = token::Client::new(&env, &project.token);
+    
+    let end = investments.len().min(progress.cursor + MAX_REFUND_STEPS);
+    for i in progress.cursor..end {
+        // The history can list an investor several times (top-ups, transfers)
+        let investor = investments.get(i).unwrap().investor;
+        let paid_key = DataKey::RefundPaid(project_id, investor.clone());
+        if storage::has(&env, &paid_key) {
+            continue;
+        }
+        storage::set(&env, &paid_key, &true);
+        
+        let position: i128 = storage::get(&env, &DataKey::InvestorAmount(project_id, investor.clone()))
+            .unwrap_or(0);
+        let refund = math::mul_div(progress.unverified_amount, position, project.raised)?;
+        
+        if refund > 0 {
+            token_client.transfer(
+                &env.current_contract_address(),
+                &investor,
+                &refund
+            );
+            hooks::on_refund(&env, project_id, &investor, refund);
+        }
+        shares::retire_shares(&env, &project, &investor);
+    }
+    progress.cursor = end;
+    
+    if progress.cursor < investments.len() {
+        storage::set(&env, &progress_key, &progress);
+        
+        log!(&env, "Refund for project {} paused at {}", project_id, progress.cursor);
+        
+        return Ok(RefundProgress::ResumeRequired(progress.cursor));
+    }
+    
+    storage::remove(&env, &progress_key);
+    
+    log!(&env, "Refund triggered for project {}", project_id);
+    
+    Ok(RefundProgress::Complete)
+}
+
+pub fn cancel_project(env: Env, project_id: u32) -> Result<(), ContractError> {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    for i in 0..project.milestones.len() {
+        if project.milestones.get(i).unwrap().status == MilestoneStatus::Verified {
+            panic!("Project has verified milestones");
+        }
+    }
+    
+    pool::withdraw(&env, &mut project)?;
+    
+    // Nothing has been released, so escrow is whatever rejections
+    // have not already made refundable; a donation project's goes to its
+    // beneficiary
+    let held = project.escrow_balance;
+    
+    if held > 0 {
+        give_back(&env, Payout::Refund, &project, held)?;
+    }
+    project.escrow_balance = 0;
+    
+    let refundable = project.refundable;
+    try_update_stats(&env, |stats| {
+        if refundable {
+            stats.total_refunded = math::add(stats.total_refunded, held)?;
+        } else {
+            stats.total_released = math::add(stats.total_released, held)?;
+        }
+        stats.value_locked = math::sub(stats.value_locked, held)?;
+        Ok(())
+    })?;
+    if !ended(&project) {
+        storage::end_active_project(&env);
+    }
+    project.active = false;
+    project.status = ProjectStatus::Cancelled;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Project {} cancelled, {} refundable", project_id, held);
+    
+    Ok(())
+}
+
+pub fn claim_refund(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    if !project.refundable {
+        panic!("{}", errors::NOT_REFUNDABLE);
+    }
+    
+    let refund = claim_payout(&env, Payout::Refund, &project, &investor)?;
+    
+    if refund == 0 {
+        panic!("No refund to claim");
+    }
+    
+    hooks::on_refund(&env, project_id, &investor, refund);
+    
+    // A refund claimed from a project that has ended is the last one
+    if matches!(project.status, ProjectStatus::Refunded | ProjectStatus::Cancelled) {
+        shares::retire_shares(&env, &project, &investor);
+    }
+    
+    log!(&env, "Refund of {} claimed from project {} by {}", refund, project_id, investor);
+    
+    Ok(refund)
+}
+
+pub fn claim_surplus(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    let surplus = claim_payout(&env, Payout::Surplus, &project, &investor)?;
+    
+    if surplus == 0 {
+        panic!("No surplus to claim");
+    }
+    
+    log!(&env, "Surplus of {} claimed from project {} by {}", surplus, project_id, investor);
+    
+    Ok(surplus)
+}
+
+pub fn get_claimable_refund(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    Ok(accrued_payout(&env, Payout::Refund, project_id, &investor)? / PAYOUT_SCALE)
+}
+
+pub fn get_claimable_surplus(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    Ok(accrued_payout(&env, Payout::Surplus, project_id, &investor)? / PAYOUT_SCALE)
+}
+
+pub fn is_refund_paid(env: Env, project_id: u32, investor: Address) -> bool {
+    storage::has(&env, &DataKey::RefundPaid(project_id, investor))
+}
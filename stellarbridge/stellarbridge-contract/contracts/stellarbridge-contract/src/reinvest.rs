@@ -0,0 +1,58 @@
+// Auto-reinvestment. An investor can name a project that their claims are
+// invested in instead of being paid out to them.
+
+use soroban_sdk::{log, Address, Env};
+
+use crate::commitments;
+use crate::errors::{self, ContractError};
+use crate::investments::{investment_refusal, record_investment};
+use crate::storage;
+use crate::types::{DataKey, Project, ReinvestKey};
+
+// Invest a payout in the investor's auto-reinvest target instead of paying
+// it out, if they chose one that raises in the same token and can take the
+// investment right now; returns whether it did
+pub fn reinvest(env: &Env, source: &Project, investor: &Address, amount: i128) -> Result<bool, ContractError> {
+    let target_id: u32 = match storage::get(env, &ReinvestKey::AutoReinvest(investor.clone())) {
+        Some(target_id) => target_id,
+        None => return Ok(false),
+    };
+    let mut target: Project = match storage::get(env, &DataKey::Project(target_id)) {
+        Some(target) => target,
+        None => return Ok(false),
+    };
+    
+    if target.token != source.token || investment_refusal(env, &target, investor, amount).is_some() {
+        return Ok(false);
+    }
+    
+    record_investment(env, &mut target, investor, amount)?;
+    commitments::execute_commitments(env, &mut target)?;
+    
+    log!(env, "Payout of {} from project {} reinvested in project {}", amount, source.id, target_id);
+    
+    Ok(true)
+}
+
+pub fn set_auto_reinvest(env: Env, investor: Address, target: Option<u32>) {
+    investor.require_auth();
+    
+    let key = ReinvestKey::AutoReinvest(investor.clone());
+    match target {
+        Some(project_id) => {
+            if !storage::has(&env, &DataKey::Project(project_id)) {
+                panic!("{}", errors::PROJECT_NOT_FOUND);
+            }
+            storage::set(&env, &key, &project_id);
+            log!(&env, "Claims by {} will be reinvested in project {}", investor, project_id);
+        }
+        None => {
+            storage::remove(&env, &key);
+            log!(&env, "Claims by {} will be paid out", investor);
+        }
+    }
+}
+
+pub fn get_auto_reinvest(env: Env, investor: Address) -> Option<u32> {
+    storage::get(&env, &ReinvestKey::AutoReinvest(investor))
+}
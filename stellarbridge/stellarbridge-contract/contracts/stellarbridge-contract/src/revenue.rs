@@ -0,0 +1,143 @@
+// Revenue sharing. An owner deposits revenue in rounds, each spread over the
+// project's investors pro-rata as a payout stream they claim from. With a
+// revenue expiry set, revenue an investor leaves unclaimed for that long can
+// be expired by anyone and escheated to the next round or the treasury.
+
+use soroban_sdk::{log, token, Address, Env, Vec};
+
+use crate::errors::ContractError;
+use crate::escrow::{accrued_payout, claim_payout, credit_payout, settle_payout};
+use crate::storage;
+use crate::types::{DataKey, Escheatment, Payout, RevenueKey, RevenueRound};
+use crate::PAYOUT_SCALE;
+
+// Restart the investor's revenue expiry clock by recording how many
+// revenue rounds they have already seen
+pub fn mark_revenue_activity(env: &Env, project_id: u32, investor: &Address) {
+    let rounds: Vec<RevenueRound> = storage::get(env, &RevenueKey::RevenueRounds(project_id))
+        .unwrap_or(Vec::new(env));
+    storage::set(env, &RevenueKey::RevenueActivity(project_id, investor.clone()), &rounds.len());
+}
+
+// Whether the investor has left revenue unclaimed for a whole `expiry`:
+// the first round deposited after their last claim or position change is
+// at least that old
+pub fn revenue_dormant(env: &Env, project_id: u32, investor: &Address, expiry: u64) -> bool {
+    let seen: u32 = storage::get(env, &RevenueKey::RevenueActivity(project_id, investor.clone())).unwrap_or(0);
+    let rounds: Vec<RevenueRound> = storage::get(env, &RevenueKey::RevenueRounds(project_id))
+        .unwrap_or(Vec::new(env));
+    
+    match rounds.get(seen) {
+        Some(round) => env.ledger().timestamp() >= round.timestamp.saturating_add(expiry),
+        None => false,
+    }
+}
+
+pub fn deposit_revenue(env: Env, project_id: u32, amount: i128) -> Result<(), ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.archived {
+        panic!("Project is archived");
+    }
+    
+    if amount <= 0 {
+        panic!("Revenue amount must be positive");
+    }
+    
+    if project.raised == 0 {
+        panic!("Project has no investors");
+    }
+    
+    let token_client = token::Client::new(&env, &project.token);
+    token_client.transfer(&project.owner, &env.current_contract_address(), &amount);
+    
+    // Escheated revenue rolled over from earlier rounds is paid out again
+    let carry_key = RevenueKey::RevenueCarry(project_id);
+    let amount = amount + storage::get::<_, i128>(&env, &carry_key).unwrap_or(0);
+    storage::remove(&env, &carry_key);
+    
+    let per_unit = credit_payout(&env, Payout::Revenue, &project, amount)?;
+    
+    let rounds_key = RevenueKey::RevenueRounds(project_id);
+    let mut rounds: Vec<RevenueRound> = storage::get(&env, &rounds_key).unwrap_or(Vec::new(&env));
+    
+    rounds.push_back(RevenueRound {
+        amount,
+        per_unit,
+        timestamp: env.ledger().timestamp(),
+    });
+    
+    storage::set(&env, &rounds_key, &rounds);
+    
+    log!(&env, "Revenue of {} deposited for project {}", amount, project_id);
+    
+    Ok(())
+}
+
+pub fn claim_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    investor.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    let payout = claim_payout(&env, Payout::Revenue, &project, &investor)?;
+    
+    if payout == 0 {
+        panic!("No revenue to claim");
+    }
+    
+    mark_revenue_activity(&env, project_id, &investor);
+    
+    log!(&env, "Revenue of {} claimed from project {} by {}", payout, project_id, investor);
+    
+    Ok(payout)
+}
+
+pub fn expire_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    let expiry = project.revenue_expiry.expect("Project revenue does not expire");
+    
+    if !revenue_dormant(&env, project_id, &investor, expiry) {
+        panic!("Revenue has not expired");
+    }
+    
+    settle_payout(&env, Payout::Revenue, project_id, &investor)?;
+    
+    let owed_key = DataKey::PayoutOwed(Payout::Revenue, project_id, investor.clone());
+    let owed: i128 = storage::get(&env, &owed_key).unwrap_or(0);
+    let expired = owed / PAYOUT_SCALE;
+    
+    if expired <= 0 {
+        panic!("No revenue to expire");
+    }
+    
+    storage::set(&env, &owed_key, &(owed % PAYOUT_SCALE));
+    mark_revenue_activity(&env, project_id, &investor);
+    
+    match project.escheatment {
+        Escheatment::NextRound => {
+            let carry_key = RevenueKey::RevenueCarry(project_id);
+            let carry: i128 = storage::get(&env, &carry_key).unwrap_or(0);
+            storage::set(&env, &carry_key, &(carry + expired));
+        }
+        Escheatment::Treasury => {
+            let treasury = storage::treasury(&env);
+            let token_client = token::Client::new(&env, &project.token);
+            token_client.transfer(&env.current_contract_address(), &treasury, &expired);
+        }
+    }
+    
+    log!(&env, "Revenue of {} expired in project {} for {}", expired, project_id, investor);
+    
+    Ok(expired)
+}
+
+pub fn get_claimable_revenue(env: Env, project_id: u32, investor: Address) -> Result<i128, ContractError> {
+    Ok(accrued_payout(&env, Payout::Revenue, project_id, &investor)? / PAYOUT_SCALE)
+}
+
+pub fn get_revenue_rounds(env: Env, project_id: u32) -> Vec<RevenueRound> {
+    storage::get(&env, &RevenueKey::RevenueRounds(project_id)).unwrap_or(Vec::new(&env))
+}
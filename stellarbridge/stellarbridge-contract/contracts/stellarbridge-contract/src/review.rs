@@ -0,0 +1,39 @@
+// Listing review. The admin can require approval of listings in chosen
+// categories, and of listings without a category, before they take
+// investments.
+
+use soroban_sdk::{log, Env};
+
+use crate::storage;
+use crate::types::{ProjectMetadata, ReviewKey};
+
+// Whether a new listing with `metadata` waits for approval; one without a
+// category could otherwise list itself past review
+pub fn pending_at_listing(env: &Env, metadata: Option<&ProjectMetadata>) -> bool {
+    metadata.is_none_or(|metadata| needs_review(env, metadata))
+}
+
+// Whether listings in the metadata's category need approval before investing
+pub fn needs_review(env: &Env, metadata: &ProjectMetadata) -> bool {
+    env.storage()
+        .instance()
+        .get(&ReviewKey::CategoryReview(metadata.category.clone()))
+        .unwrap_or(false)
+}
+
+pub fn approve_listing(env: Env, project_id: u32) {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    if !project.pending_approval {
+        panic!("Project is not pending approval");
+    }
+    
+    project.pending_approval = false;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Listing approved for project {}", project_id);
+}
@@ -0,0 +1,160 @@
+// Backer rewards. A project can define reward tiers by total contributed,
+// each with limited slots, and moves an investor up to the highest tier
+// they reach; every investor also gets a soulbound badge for the project
+// whose level follows the highest tier they have held.
+
+use soroban_sdk::{log, Address, Env, Symbol, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::storage;
+use crate::types::{Badge, RewardKey, RewardTier};
+use crate::{math, MAX_REWARD_TIERS};
+
+// Count an investment towards the investor's reward tier and badge
+pub fn reward(env: &Env, project_id: u32, investor: &Address, amount: i128) -> Result<(), ContractError> {
+    assign_tier(env, project_id, investor, contribute(env, project_id, investor, amount)?);
+    award_badge(env, project_id, investor);
+    
+    Ok(())
+}
+
+// Add to everything the investor has put into the project and return the
+// new total. Unlike their position it never shrinks with refunds or
+// transfers, so small investments add up towards a tier.
+fn contribute(env: &Env, project_id: u32, investor: &Address, amount: i128) -> Result<i128, ContractError> {
+    let key = RewardKey::Contributed(project_id, investor.clone());
+    let total = math::add(storage::get(env, &key).unwrap_or(0), amount)?;
+    storage::set(env, &key, &total);
+    Ok(total)
+}
+
+// Move the investor up to the highest reward tier their total contributed
+// reaches with a slot free, giving up the slot of the tier they held.
+// Tiers follow what the investor put in, not positions transferred to them.
+fn assign_tier(env: &Env, project_id: u32, investor: &Address, total: i128) {
+    let tiers_key = RewardKey::RewardTiers(project_id);
+    let mut tiers: Vec<RewardTier> = match storage::get(env, &tiers_key) {
+        Some(tiers) => tiers,
+        None => return,
+    };
+    
+    let tier_key = RewardKey::InvestorTier(project_id, investor.clone());
+    let held: Option<u32> = storage::get(env, &tier_key);
+    
+    let mut index = tiers.len();
+    while index > 0 {
+        index -= 1;
+        if held.is_some_and(|held| index <= held) {
+            return;
+        }
+        
+        let mut tier = tiers.get(index).unwrap();
+        if total < tier.threshold || tier.max_slots.is_some_and(|max| tier.claimed >= max) {
+            continue;
+        }
+        
+        tier.claimed += 1;
+        tiers.set(index, tier);
+        if let Some(held) = held {
+            let mut previous = tiers.get(held).unwrap();
+            previous.claimed -= 1;
+            tiers.set(held, previous);
+        }
+        storage::set(env, &tiers_key, &tiers);
+        storage::set(env, &tier_key, &index);
+        
+        match held {
+            Some(held) => env.events().publish(
+                (Symbol::new(env, "tier_upgraded"), project_id),
+                (investor.clone(), held, index),
+            ),
+            None => env.events().publish(
+                (Symbol::new(env, "tier_assigned"), project_id),
+                (investor.clone(), index),
+            ),
+        }
+        return;
+    }
+}
+
+// Give the investor their project badge, or raise its level to match the
+// reward tier they now hold; a badge never loses a level
+fn award_badge(env: &Env, project_id: u32, investor: &Address) {
+    let tier: Option<u32> = storage::get(env, &RewardKey::InvestorTier(project_id, investor.clone()));
+    let level = tier.map_or(0, |tier| tier + 1);
+    
+    let key = RewardKey::Badge(project_id, investor.clone());
+    let badge: Option<Badge> = storage::get(env, &key);
+    if badge.as_ref().is_some_and(|badge| badge.level >= level) {
+        return;
+    }
+    
+    storage::set(env, &key, &Badge {
+        level,
+        awarded_at: env.ledger().timestamp(),
+    });
+    
+    match badge {
+        Some(badge) => env.events().publish(
+            (Symbol::new(env, "badge_upgraded"), project_id),
+            (investor.clone(), badge.level, level),
+        ),
+        None => env.events().publish(
+            (Symbol::new(env, "badge_awarded"), project_id),
+            (investor.clone(), level),
+        ),
+    }
+}
+
+pub fn set_reward_tiers(env: Env, project_id: u32, tiers: Vec<RewardTier>) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if tiers.len() > MAX_REWARD_TIERS {
+        panic!("Too many reward tiers");
+    }
+    
+    let mut stored: Vec<RewardTier> = Vec::new(&env);
+    let mut previous: i128 = 0;
+    for mut tier in tiers.iter() {
+        if tier.threshold <= previous {
+            panic!("Reward tier thresholds must be positive and increasing");
+        }
+        if tier.max_slots == Some(0) {
+            panic!("Reward tier needs at least one slot");
+        }
+        previous = tier.threshold;
+        tier.claimed = 0;
+        stored.push_back(tier);
+    }
+    
+    let key = RewardKey::RewardTiers(project_id);
+    if stored.is_empty() {
+        storage::remove(&env, &key);
+    } else {
+        storage::set(&env, &key, &stored);
+    }
+    
+    log!(&env, "Reward tiers for project {} updated", project_id);
+}
+
+pub fn get_reward_tiers(env: Env, project_id: u32) -> Vec<RewardTier> {
+    storage::get(&env, &RewardKey::RewardTiers(project_id)).unwrap_or(Vec::new(&env))
+}
+
+pub fn get_investor_tier(env: Env, project_id: u32, investor: Address) -> Option<u32> {
+    storage::get(&env, &RewardKey::InvestorTier(project_id, investor))
+}
+
+pub fn get_badge(env: Env, investor: Address, project_id: u32) -> Option<Badge> {
+    storage::get(&env, &RewardKey::Badge(project_id, investor))
+}
+
+pub fn has_badge(env: Env, investor: Address, project_id: u32, level: u32) -> bool {
+    get_badge(env, investor, project_id).is_some_and(|badge| badge.level >= level)
+}
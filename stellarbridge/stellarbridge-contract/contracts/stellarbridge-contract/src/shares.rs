@@ -0,0 +1,93 @@
+// Share tokens: each project deployed while a share token wasm is configured
+// gets a SEP-41 token of its own, minted 1:1 with every investment and
+// clawed back once the position is refunded
+
+use soroban_sdk::{token, Address, BytesN, Env, String};
+
+use crate::types::{Project, ShareTokenKey};
+
+// Build "<prefix><n>" as a contract String, e.g. "SBP12"
+pub fn numbered_string(env: &Env, prefix: &[u8], n: u32) -> String {
+    let mut buf = [0u8; 48];
+    let len = prefix.len();
+    buf[..len].copy_from_slice(prefix);
+    
+    let mut digits = [0u8; 10];
+    let mut count = 0;
+    let mut rest = n;
+    loop {
+        digits[count] = b'0' + (rest % 10) as u8;
+        count += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    for i in 0..count {
+        buf[len + i] = digits[count - 1 - i];
+    }
+    
+    String::from_bytes(env, &buf[..len + count])
+}
+
+// Deploy a SEP-41 share token for a project, with this contract as its admin.
+// Shares mirror the project token's decimals so 1 share == 1 unit invested;
+// holders cannot transfer them, so payouts keyed on positions stay with
+// whoever holds the shares; this contract moves them with transferred
+// positions and claws them back once refunded.
+// Returns None when no share token wasm has been configured.
+pub fn deploy_share_token(env: &Env, project_id: u32, project_token: &Address) -> Option<Address> {
+    let wasm_hash: BytesN<32> = env.storage().instance().get(&ShareTokenKey::ShareTokenWasm)?;
+    
+    let decimals = token::Client::new(env, project_token).decimals();
+    
+    let mut salt = [0u8; 32];
+    salt[28..].copy_from_slice(&project_id.to_be_bytes());
+    
+    let share_token = env
+        .deployer()
+        .with_current_contract(BytesN::from_array(env, &salt))
+        .deploy_v2(
+            wasm_hash,
+            (
+                env.current_contract_address(),
+                decimals,
+                numbered_string(env, b"StellarBridge Project #", project_id),
+                numbered_string(env, b"SBP", project_id),
+            ),
+        );
+    
+    Some(share_token)
+}
+
+// Mint shares 1:1 with the amount invested
+pub fn mint_shares(env: &Env, project: &Project, investor: &Address, amount: i128) {
+    if let Some(share_token) = &project.share_token {
+        token::StellarAssetClient::new(env, share_token).mint(investor, &amount);
+    }
+}
+
+// Shares follow the position they were minted for; holders cannot move
+// them, so the contract reissues them to the new holder
+#[cfg(feature = "position-transfers")]
+pub fn move_shares(env: &Env, project: &Project, from: &Address, to: &Address, amount: i128) {
+    if let Some(share_token) = &project.share_token {
+        let shares = token::StellarAssetClient::new(env, share_token);
+        shares.clawback(from, &amount);
+        shares.mint(to, &amount);
+    }
+}
+
+// Claw back the investor's shares once the project has ended and their
+// position has been refunded. Shares cannot leave the position they were
+// issued for, so the investor holds exactly that many until retired.
+pub fn retire_shares(env: &Env, project: &Project, investor: &Address) {
+    let Some(share_token) = &project.share_token else {
+        return;
+    };
+    
+    let shares = token::Client::new(env, share_token).balance(investor);
+    if shares > 0 {
+        token::StellarAssetClient::new(env, share_token).clawback(investor, &shares);
+    }
+}
@@ -0,0 +1,117 @@
+// Verifier stakes. The admin can require verifiers to stake the contract's
+// base token before their decisions count, and slash a project verifier's
+// stake into the refunds of that project's investors.
+
+use soroban_sdk::{log, token, Address, Env, Symbol};
+
+use crate::errors::ContractError;
+use crate::escrow::give_back;
+use crate::milestones::project_verifier;
+use crate::storage;
+use crate::types::{Payout, Project, StakingKey};
+use crate::{math, StellarBridgeContract};
+
+// Whether `verifier` has staked at least the admin-set requirement
+pub fn stake_sufficient(env: &Env, verifier: &Address) -> bool {
+    let required: i128 = env.storage().instance().get(&StakingKey::RequiredStake).unwrap_or(0);
+    let staked: i128 = storage::get(env, &StakingKey::VerifierStake(verifier.clone())).unwrap_or(0);
+    staked >= required
+}
+
+// Take `amount` out of the project verifier's stake and make it claimable
+// by the project's investors alongside their refunds; stakes are held in
+// the contract's base token, so the project must raise in it
+pub fn slash_stake(env: &Env, project: &Project, amount: i128) -> Result<(), ContractError> {
+    let token = storage::token(env);
+    
+    if project.token != token {
+        panic!("Project does not raise in the stake token");
+    }
+    
+    if project.raised == 0 {
+        panic!("Project has no investors");
+    }
+    
+    let stake_key = StakingKey::VerifierStake(project_verifier(env, project));
+    let staked: i128 = storage::get(env, &stake_key).unwrap_or(0);
+    if amount <= 0 || amount > staked {
+        panic!("Invalid slash amount");
+    }
+    
+    storage::set(env, &stake_key, &(staked - amount));
+    give_back(env, Payout::Refund, project, amount)?;
+    
+    env.events().publish(
+        (Symbol::new(env, "verifier_slashed"), project.id),
+        amount,
+    );
+    
+    Ok(())
+}
+
+pub fn slash_verifier(env: Env, project_id: u32, amount: i128) -> Result<(), ContractError> {
+    let admin = storage::admin(&env);
+    
+    admin.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    slash_stake(&env, &project, amount)?;
+    
+    log!(&env, "Verifier slashed {} for project {}", amount, project_id);
+    
+    Ok(())
+}
+
+pub fn stake_verifier(env: Env, verifier: Address, amount: i128) -> Result<(), ContractError> {
+    verifier.require_auth();
+    
+    if !StellarBridgeContract::is_verifier(env.clone(), verifier.clone()) {
+        panic!("Not a verifier");
+    }
+    
+    if amount <= 0 {
+        panic!("Stake amount must be positive");
+    }
+    
+    let token = storage::token(&env);
+    token::Client::new(&env, &token).transfer(&verifier, &env.current_contract_address(), &amount);
+    
+    let stake_key = StakingKey::VerifierStake(verifier.clone());
+    let staked: i128 = storage::get(&env, &stake_key).unwrap_or(0);
+    storage::set(&env, &stake_key, &math::add(staked, amount)?);
+    
+    log!(&env, "Verifier {} staked {}", verifier, amount);
+    
+    Ok(())
+}
+
+pub fn unstake_verifier(env: Env, verifier: Address, amount: i128) {
+    verifier.require_auth();
+    
+    if amount <= 0 {
+        panic!("Unstake amount must be positive");
+    }
+    
+    let stake_key = StakingKey::VerifierStake(verifier.clone());
+    let staked: i128 = storage::get(&env, &stake_key).unwrap_or(0);
+    let required: i128 = env.storage().instance().get(&StakingKey::RequiredStake).unwrap_or(0);
+    if amount > staked {
+        panic!("Insufficient stake");
+    }
+    
+    if staked - amount < required {
+        panic!("Stake would fall below requirement");
+    }
+    
+    storage::set(&env, &stake_key, &(staked - amount));
+    
+    let token = storage::token(&env);
+    token::Client::new(&env, &token).transfer(&env.current_contract_address(), &verifier, &amount);
+    
+    log!(&env, "Verifier {} unstaked {}", verifier, amount);
+}
+
+pub fn get_verifier_stake(env: Env, verifier: Address) -> i128 {
+    storage::get(&env, &StakingKey::VerifierStake(verifier)).unwrap_or(0)
+}
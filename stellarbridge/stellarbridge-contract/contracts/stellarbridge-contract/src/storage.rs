@@ -0,0 +1,567 @@
+// Typed access to the values every entrypoint reads: the contract-wide
+// addresses, projects and the running totals. Writes through here also
+// keep the instance entry alive.
+//
+// Only config and counters live in the instance, which is a single entry
+// loaded on every call. Anything keyed by a project, investor, round or
+// account grows with use, so it gets a persistent entry of its own whose
+// TTL is extended whenever it is read or written.
+
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+use soroban_sdk::Vec;
+
+use crate::errors::{self, ContractError};
+use crate::types::{ContractStats, DataKey, OwnerReputation, Project};
+#[cfg(feature = "decision-log")]
+use crate::types::ListKey;
+#[cfg(feature = "reputation")]
+use crate::types::ReputationKey;
+#[cfg(feature = "stats")]
+use crate::types::StatsKey;
+#[cfg(any(feature = "reputation", feature = "stats"))]
+use crate::StellarBridgeContract;
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+use crate::MAX_PAGE_SIZE;
+
+// Ledgers the instance is kept alive for after a write once it has fewer
+// than `INSTANCE_TTL_THRESHOLD` left, about 30 and 7 days at 5 second ledgers
+pub const INSTANCE_TTL_EXTEND: u32 = 518_400;
+pub const INSTANCE_TTL_THRESHOLD: u32 = 120_960;
+
+pub fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_TTL_THRESHOLD, INSTANCE_TTL_EXTEND);
+}
+
+// Ledgers a persistent entry is kept alive for once it has fewer than
+// `PERSISTENT_TTL_THRESHOLD` left, matching the instance
+pub const PERSISTENT_TTL_EXTEND: u32 = INSTANCE_TTL_EXTEND;
+pub const PERSISTENT_TTL_THRESHOLD: u32 = INSTANCE_TTL_THRESHOLD;
+
+// A storage key that knows whether its entry is persistent
+pub trait Key: IntoVal<Env, Val> {
+    fn persistent(&self) -> bool;
+}
+
+impl Key for DataKey {
+    fn persistent(&self) -> bool {
+        !matches!(
+            self,
+            DataKey::ProjectCounter
+                | DataKey::Verifier
+                | DataKey::Token
+                | DataKey::Admin
+                | DataKey::AllowedTokens
+        )
+    }
+}
+
+#[cfg(feature = "decision-log")]
+impl Key for ListKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+// Keys of the optional subsystems are persistent but for their config
+#[cfg(feature = "revenue")]
+impl Key for crate::types::RevenueKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "whitelist")]
+impl Key for crate::types::WhitelistKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "evidence-access")]
+impl Key for crate::types::EvidenceAccessKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "vesting")]
+impl Key for crate::types::VestingKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "delegation")]
+impl Key for crate::types::DelegationKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "listing-review")]
+impl Key for crate::types::ReviewKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "escalation")]
+impl Key for crate::types::EscalationKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::VerificationWindow | Self::AutoApproveThreshold | Self::AutoApproveWindow)
+    }
+}
+
+#[cfg(feature = "staking")]
+impl Key for crate::types::StakingKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::RequiredStake)
+    }
+}
+
+#[cfg(feature = "verifier-pool")]
+impl Key for crate::types::VerifierPoolKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::VerifierRegistry | Self::VerifierDomain | Self::VerifierPool)
+    }
+}
+
+#[cfg(feature = "archive")]
+impl Key for crate::types::ArchiveKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::ArchiveTtl)
+    }
+}
+
+#[cfg(feature = "auto-reinvest")]
+impl Key for crate::types::ReinvestKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "yield-pool")]
+impl Key for crate::types::PoolKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::YieldPool(..))
+    }
+}
+
+#[cfg(feature = "usd-goals")]
+impl Key for crate::types::UsdGoalKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "commitments")]
+impl Key for crate::types::CommitmentKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "rewards")]
+impl Key for crate::types::RewardKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "matching")]
+impl Key for crate::types::MatchingKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::MatchingRoundCount)
+    }
+}
+
+#[cfg(feature = "referrals")]
+impl Key for crate::types::ReferralKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "disputes")]
+impl Key for crate::types::DisputeKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::Arbiter | Self::DisputeWindow | Self::AppealWindow)
+    }
+}
+
+#[cfg(feature = "insurance")]
+impl Key for crate::types::InsuranceKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::InsurancePolicy)
+    }
+}
+
+#[cfg(feature = "audits")]
+impl Key for crate::types::AuditKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "oracle-milestones")]
+impl Key for crate::types::OracleKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "co-verification")]
+impl Key for crate::types::CoVerificationKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::CoVerificationThreshold)
+    }
+}
+
+#[cfg(feature = "verifier-signatures")]
+impl Key for crate::types::SignatureKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "evm-attestation")]
+impl Key for crate::types::AttestationKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "committee")]
+impl Key for crate::types::CommitteeKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "intents")]
+impl Key for crate::types::IntentsKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "hooks")]
+impl Key for crate::types::HookKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "config-history")]
+impl Key for crate::types::ConfigHistoryKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "updates")]
+impl Key for crate::types::UpdateKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "listings")]
+impl Key for crate::types::ListingKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "goal-bounds")]
+impl Key for crate::types::GoalBoundsKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "share-token")]
+impl Key for crate::types::ShareTokenKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "stats")]
+impl Key for crate::types::StatsKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "reputation")]
+impl Key for crate::types::ReputationKey {
+    fn persistent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "fees")]
+impl Key for crate::types::FeeKey {
+    fn persistent(&self) -> bool {
+        !matches!(self, Self::VerificationFee | Self::CategoryFee(..))
+    }
+}
+
+#[cfg(feature = "treasury")]
+impl Key for crate::types::TreasuryKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "late-policy")]
+impl Key for crate::types::LatePolicyKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "resubmissions")]
+impl Key for crate::types::ResubmissionKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "refund-grace")]
+impl Key for crate::types::RefundGraceKey {
+    fn persistent(&self) -> bool {
+        false
+    }
+}
+
+fn extend_persistent<K: Key>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND);
+}
+
+// Read, check, write and remove an entry wherever its key says it lives
+pub fn get<K: Key, V: TryFromVal<Env, Val>>(env: &Env, key: &K) -> Option<V> {
+    if !key.persistent() {
+        return env.storage().instance().get(key);
+    }
+    
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(env, key);
+    }
+    value
+}
+
+pub fn has<K: Key>(env: &Env, key: &K) -> bool {
+    if !key.persistent() {
+        return env.storage().instance().has(key);
+    }
+    
+    let present = env.storage().persistent().has(key);
+    if present {
+        extend_persistent(env, key);
+    }
+    present
+}
+
+pub fn set<K: Key, V: IntoVal<Env, Val>>(env: &Env, key: &K, value: &V) {
+    if !key.persistent() {
+        env.storage().instance().set(key, value);
+        return;
+    }
+    
+    env.storage().persistent().set(key, value);
+    extend_persistent(env, key);
+}
+
+pub fn remove<K: Key>(env: &Env, key: &K) {
+    if key.persistent() {
+        env.storage().persistent().remove(key);
+    } else {
+        env.storage().instance().remove(key);
+    }
+}
+
+// Number of items in the list whose length is under `count`
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+pub fn list_len<K: Key>(env: &Env, count: &K) -> u32 {
+    get(env, count).unwrap_or(0)
+}
+
+// Append `value` to a list, storing it under `item(index)`; returns its index
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+pub fn list_push<K: Key, I: Key, V: IntoVal<Env, Val>>(
+    env: &Env,
+    count: &K,
+    item: impl FnOnce(u32) -> I,
+    value: &V,
+) -> u32 {
+    let index = list_len(env, count);
+    set(env, &item(index), value);
+    set(env, count, &(index + 1));
+    index
+}
+
+// Up to `limit` items of a list, oldest first, starting at `start`
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+pub fn list_page<K: Key, I: Key, V: IntoVal<Env, Val> + TryFromVal<Env, Val>>(
+    env: &Env,
+    count: &K,
+    item: impl Fn(u32) -> I,
+    start: u32,
+    limit: u32,
+) -> Vec<V> {
+    let len = list_len(env, count);
+    let start = start.min(len);
+    let end = len.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+    
+    let mut page = Vec::new(env);
+    for index in start..end {
+        page.push_back(get(env, &item(index)).unwrap());
+    }
+    page
+}
+
+// Remove every item of a list along with its length
+#[cfg(all(feature = "archive", any(feature = "decision-log", feature = "updates")))]
+pub fn list_clear<K: Key, I: Key>(env: &Env, count: &K, item: impl Fn(u32) -> I) {
+    for index in 0..list_len(env, count) {
+        remove(env, &item(index));
+    }
+    remove(env, count);
+}
+
+pub fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect(errors::ADMIN_NOT_SET)
+}
+
+pub fn token(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Token)
+        .expect(errors::TOKEN_NOT_SET)
+}
+
+#[cfg(any(feature = "fees", feature = "revenue", feature = "yield-pool"))]
+pub fn treasury(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&crate::types::TreasuryKey::Treasury)
+        .expect(errors::TREASURY_NOT_SET)
+}
+
+pub fn verifier(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Verifier)
+        .expect(errors::VERIFIER_NOT_SET)
+}
+
+#[cfg(feature = "disputes")]
+pub fn arbiter(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&crate::types::DisputeKey::Arbiter)
+        .expect(errors::ARBITER_NOT_SET)
+}
+
+pub fn project(env: &Env, project_id: u32) -> Project {
+    get(env, &DataKey::Project(project_id)).expect(errors::PROJECT_NOT_FOUND)
+}
+
+pub fn set_project(env: &Env, project: &Project) {
+    set(env, &DataKey::Project(project.id), project);
+    extend_instance(env);
+}
+
+// Apply `update` to the stored running totals
+#[cfg(feature = "stats")]
+pub fn update_stats(env: &Env, update: impl FnOnce(&mut ContractStats)) {
+    let mut stats = StellarBridgeContract::get_stats(env.clone());
+    update(&mut stats);
+    env.storage().instance().set(&StatsKey::Stats, &stats);
+    extend_instance(env);
+}
+
+// Without stats no running totals are kept, so updates to them go nowhere
+#[cfg(not(feature = "stats"))]
+pub fn update_stats(_env: &Env, _update: impl FnOnce(&mut ContractStats)) {}
+
+// Apply a fallible `update` to the stored running totals, storing nothing
+// if it fails
+#[cfg(feature = "stats")]
+pub fn try_update_stats(
+    env: &Env,
+    update: impl FnOnce(&mut ContractStats) -> Result<(), ContractError>,
+) -> Result<(), ContractError> {
+    let mut stats = StellarBridgeContract::get_stats(env.clone());
+    update(&mut stats)?;
+    env.storage().instance().set(&StatsKey::Stats, &stats);
+    extend_instance(env);
+    Ok(())
+}
+
+#[cfg(not(feature = "stats"))]
+pub fn try_update_stats(
+    _env: &Env,
+    _update: impl FnOnce(&mut ContractStats) -> Result<(), ContractError>,
+) -> Result<(), ContractError> {
+    Ok(())
+}
+
+// Take a project off the active count as it ends; called once per project,
+// on whichever terminal transition it reaches first
+pub fn end_active_project(env: &Env) {
+    update_stats(env, |stats| {
+        stats.active_projects = stats.active_projects
+            .checked_sub(1)
+            .expect("No active project to end");
+    });
+}
+
+// Apply `update` to the stored track record of `owner`
+#[cfg(feature = "reputation")]
+pub fn update_reputation(env: &Env, owner: &Address, update: impl FnOnce(&mut OwnerReputation)) {
+    let mut reputation = StellarBridgeContract::get_owner_reputation(env.clone(), owner.clone());
+    update(&mut reputation);
+    set(env, &ReputationKey::OwnerReputation(owner.clone()), &reputation);
+    extend_instance(env);
+}
+
+// Without reputation no track record is kept
+#[cfg(not(feature = "reputation"))]
+pub fn update_reputation(_env: &Env, _owner: &Address, _update: impl FnOnce(&mut OwnerReputation)) {}
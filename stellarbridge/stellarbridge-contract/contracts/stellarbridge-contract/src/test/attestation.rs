@@ -0,0 +1,71 @@
+// Verifiers can sign decisions off-chain with a registered ed25519 key for
+// anyone to submit, each attestation carrying the verifier's next nonce
+
+extern crate std;
+
+use super::Setup;
+use crate::{ContractError, VerificationAttestation};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::BytesN;
+use std::vec::Vec as StdVec;
+
+// Default project, fully funded, with evidence submitted for both
+// milestones and the global verifier signing with `signer`
+fn create(setup: &Setup, signer: &SigningKey) -> u32 {
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, &setup.investor(1_000), &1_000, &None);
+    for index in 0..2u32 {
+        client.submit_evidence(&project_id, &index, &BytesN::from_array(env, &[7u8; 32]));
+    }
+
+    let public_key = BytesN::from_array(env, &signer.verifying_key().to_bytes());
+    client.set_verifier_key(&setup.verifier, &Some(public_key));
+    project_id
+}
+
+// Attestation over the project as it stands now
+fn attestation(
+    setup: &Setup,
+    project_id: u32,
+    milestone_index: u32,
+    approved: bool,
+    nonce: u64,
+) -> VerificationAttestation {
+    VerificationAttestation {
+        project_id,
+        milestone_index,
+        approved,
+        nonce,
+        version: setup.client.get_project(&project_id).version,
+    }
+}
+
+fn sign(setup: &Setup, signer: &SigningKey, payload: &VerificationAttestation) -> BytesN<64> {
+    let env = &setup.env;
+    let message = (setup.client.address.clone(), payload.clone()).to_xdr(env);
+    let message: StdVec<u8> = message.iter().collect();
+    BytesN::from_array(env, &signer.sign(&message).to_bytes())
+}
+
+#[test]
+fn attestation_signed_before_a_resubmission_is_stale() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    client.set_max_resubmissions(&1);
+    let signer = SigningKey::from_bytes(&[1u8; 32]);
+    let project_id = create(&setup, &signer);
+    let payload = attestation(&setup, project_id, 0, true, 0);
+    let signature = sign(&setup, &signer, &payload);
+
+    // The evidence the approval was signed over is rejected and replaced
+    let version = client.get_project(&project_id).version;
+    client.verify_milestone(&project_id, &0, &false, &None, &version);
+    client.resubmit_evidence(&project_id, &0, &BytesN::from_array(env, &[8u8; 32]));
+
+    let result = client.try_verify_with_signature(&payload, &signature);
+    assert_eq!(result, Err(Ok(ContractError::VersionMismatch)));
+}
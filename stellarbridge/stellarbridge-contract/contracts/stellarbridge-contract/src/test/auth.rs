@@ -0,0 +1,2177 @@
+// Negative-path authorization tests: every mutating entrypoint is invoked
+// once signed only by a plausible wrong party, once with no signatures at
+// all, and must reject both before succeeding for the intended signer.
+//
+// `escalate_milestone`, `roll_over_milestone`, `finalize_milestone_vote`,
+// `expire_revenue`, `check_oracle_milestone`, `settle_commitments`,
+// `execute_due_commitments`, `close_matching_round`, `close_sponsor_match`,
+// `release_milestone`, `finalize_rejection`, `verify_with_signature`,
+// `submit_evm_attestation`, `approve_by_committee` and `invest_with_intent`
+// are deliberately permissionless and are not covered here.
+
+extern crate std;
+
+#[cfg(feature = "yield-pool")]
+use super::pool::lend_to_frozen_pool;
+use super::Setup;
+use crate::{ProjectMetadata, StellarBridgeContract, StellarBridgeContractClient};
+#[cfg(feature = "audits")]
+use crate::AuditVerdict;
+#[cfg(feature = "escalation")]
+use crate::Escalation;
+#[cfg(feature = "fees")]
+use crate::{FeeStrategy, VerificationFee};
+#[cfg(feature = "revenue")]
+use crate::Escheatment;
+#[cfg(feature = "referrals")]
+use crate::ReferralFunding;
+#[cfg(feature = "rewards")]
+use crate::RewardTier;
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, Ledger, MockAuth, MockAuthInvoke};
+#[cfg(feature = "evidence-access")]
+use soroban_sdk::Bytes;
+#[cfg(any(feature = "listing-review", feature = "project-edits"))]
+use soroban_sdk::String;
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+const GOAL: i128 = 1_000;
+#[cfg(any(
+    feature = "co-verification",
+    feature = "commitments",
+    feature = "escalation",
+    feature = "insurance",
+    feature = "matching",
+    feature = "position-transfers",
+    feature = "project-edits",
+    feature = "referrals",
+    feature = "revenue",
+    feature = "rewards"
+))]
+const MILESTONE: i128 = 500;
+
+// Asserts that `call` fails when only `intruder` signs and when nobody
+// signs, then succeeds with all auths mocked and records `expected` as the
+// address that authorized `fn_name` with `args`
+fn assert_requires_auth(
+    env: &Env,
+    contract: &Address,
+    expected: &Address,
+    intruder: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+    call: impl Fn() -> bool,
+) {
+    env.mock_auths(&[MockAuth {
+        address: intruder,
+        invoke: &MockAuthInvoke {
+            contract,
+            fn_name,
+            args: args.clone(),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(!call(), "{} accepted a signature from the wrong address", fn_name);
+
+    env.set_auths(&[]);
+    assert!(!call(), "{} succeeded without any signature", fn_name);
+
+    env.mock_all_auths();
+    assert!(call(), "{} failed for the intended signer", fn_name);
+
+    let authorized = env.auths().iter().any(|(address, invocation)| {
+        address == expected
+            && invocation.function
+                == AuthorizedFunction::Contract((
+                    contract.clone(),
+                    Symbol::new(env, fn_name),
+                    args.clone(),
+                ))
+    });
+    assert!(authorized, "{} was not authorized by the expected address", fn_name);
+}
+
+// Creates a single-milestone project and returns its id and owner
+fn create_project(setup: &Setup) -> (u32, Address) {
+    let env = &setup.env;
+    let owner = Address::generate(env);
+    let project_id = setup.client.create_project(
+        &owner,
+        &setup.token.address,
+        &GOAL,
+        &vec![env, GOAL],
+        &vec![env, env.ledger().timestamp() + 100],
+        &None,
+    );
+    #[cfg(feature = "listing-review")]
+    setup.client.approve_listing(&project_id);
+    (project_id, owner)
+}
+
+// Funds `investor` and invests the whole goal into the project
+fn fund(setup: &Setup, project_id: u32) -> Address {
+    let investor = Address::generate(&setup.env);
+    setup.token_admin.mint(&investor, &GOAL);
+    setup.client.invest(&project_id, &investor, &GOAL, &None);
+    investor
+}
+
+#[test]
+fn initialize_requires_verifier() {
+    let env = Env::default();
+    let verifier = Address::generate(&env);
+    let token = Address::generate(&env);
+    let contract_id = env.register(StellarBridgeContract, ());
+    let client = StellarBridgeContractClient::new(&env, &contract_id);
+
+    assert_requires_auth(
+        &env,
+        &contract_id,
+        &verifier,
+        &Address::generate(&env),
+        "initialize",
+        (verifier.clone(), token.clone()).into_val(&env),
+        || client.try_initialize(&verifier, &token).is_ok(),
+    );
+}
+
+#[test]
+fn set_admin_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let new_admin = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &new_admin,
+        "set_admin",
+        (new_admin.clone(),).into_val(env),
+        || setup.client.try_set_admin(&new_admin).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "share-token")]
+fn set_share_token_wasm_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let wasm_hash = BytesN::from_array(env, &[1u8; 32]);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_share_token_wasm",
+        (wasm_hash.clone(),).into_val(env),
+        || setup.client.try_set_share_token_wasm(&wasm_hash).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "multi-token")]
+fn add_allowed_token_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let token = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "add_allowed_token",
+        (token.clone(),).into_val(env),
+        || setup.client.try_add_allowed_token(&token).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "multi-token")]
+fn remove_allowed_token_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let token = setup.token.address.clone();
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "remove_allowed_token",
+        (token.clone(),).into_val(env),
+        || setup.client.try_remove_allowed_token(&token).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "goal-bounds")]
+fn set_goal_bounds_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let token = setup.token.address.clone();
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_goal_bounds",
+        (token.clone(), Some(100i128), Some(GOAL)).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_goal_bounds(&token, &Some(100), &Some(GOAL))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "listing-review")]
+fn listing_review_is_managed_by_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let category = Symbol::new(env, "securities");
+    let owner = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "set_category_review",
+        (category.clone(), true).into_val(env),
+        || setup.client.try_set_category_review(&category, &true).is_ok(),
+    );
+
+    let metadata = ProjectMetadata {
+        name: String::from_str(env, "Tokenized Bond"),
+        description_uri: String::from_str(env, "ipfs://bond"),
+        category,
+        image_hash: None,
+        tags: Vec::new(env),
+    };
+    let project_id = setup.client.create_project(
+        &owner,
+        &setup.token.address,
+        &GOAL,
+        &vec![env, GOAL],
+        &vec![env, 100u64],
+        &Some(metadata),
+    );
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "approve_listing",
+        (project_id,).into_val(env),
+        || setup.client.try_approve_listing(&project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "resubmissions")]
+fn set_max_resubmissions_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_max_resubmissions",
+        (2u32,).into_val(env),
+        || setup.client.try_set_max_resubmissions(&2).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn set_verification_window_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_verification_window",
+        (Some(100u64),).into_val(env),
+        || setup.client.try_set_verification_window(&Some(100)).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "staking")]
+fn set_required_stake_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_required_stake",
+        (100i128,).into_val(env),
+        || setup.client.try_set_required_stake(&100).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "staking")]
+fn slash_verifier_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    fund(&setup, project_id);
+    setup.token_admin.mint(&setup.verifier, &100);
+    setup.client.stake_verifier(&setup.verifier, &100);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "slash_verifier",
+        (project_id, 100i128).into_val(env),
+        || setup.client.try_slash_verifier(&project_id, &100).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "treasury")]
+fn set_treasury_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let treasury = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &treasury,
+        "set_treasury",
+        (treasury.clone(),).into_val(env),
+        || setup.client.try_set_treasury(&treasury).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn set_arbiter_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let arbiter = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &arbiter,
+        "set_arbiter",
+        (arbiter.clone(),).into_val(env),
+        || setup.client.try_set_arbiter(&arbiter).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn set_dispute_window_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_dispute_window",
+        (Some(100u64),).into_val(env),
+        || setup.client.try_set_dispute_window(&Some(100)).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn set_appeal_window_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_appeal_window",
+        (Some(100u64),).into_val(env),
+        || setup.client.try_set_appeal_window(&Some(100)).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "insurance")]
+fn set_insurance_policy_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_insurance_policy",
+        (100u32, 5_000u32, MILESTONE).into_val(env),
+        || setup.client.try_set_insurance_policy(&100, &5_000, &MILESTONE).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "audits")]
+fn set_auditor_approval_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let auditor = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &auditor,
+        "set_auditor_approval",
+        (auditor.clone(), true).into_val(env),
+        || setup.client.try_set_auditor_approval(&auditor, &true).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "oracle-milestones")]
+fn set_oracle_approval_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let oracle = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &oracle,
+        "set_oracle_approval",
+        (oracle.clone(), true).into_val(env),
+        || setup.client.try_set_oracle_approval(&oracle, &true).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "yield-pool")]
+fn write_off_pool_position_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, investor) = lend_to_frozen_pool(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &investor,
+        "write_off_pool_position",
+        (project_id,).into_val(env),
+        || setup.client.try_write_off_pool_position(&project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "co-verification")]
+fn set_co_verification_threshold_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_co_verification_threshold",
+        (Some(MILESTONE),).into_val(env),
+        || setup.client.try_set_co_verification_threshold(&Some(MILESTONE)).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn set_auto_approval_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_auto_approval",
+        (Some(MILESTONE), 100u64).into_val(env),
+        || setup.client.try_set_auto_approval(&Some(MILESTONE), &100).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn set_verification_fee_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let fee = VerificationFee::BasisPoints(100);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_verification_fee",
+        (fee,).into_val(env),
+        || setup.client.try_set_verification_fee(&fee).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn set_category_fee_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let category = Symbol::new(env, "energy");
+    let strategy = FeeStrategy::Flat(100);
+    setup.client.set_treasury(&Address::generate(env));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_category_fee",
+        (category.clone(), strategy.clone()).into_val(env),
+        || setup.client.try_set_category_fee(&category, &strategy).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "late-policy")]
+fn set_late_policy_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_late_policy",
+        (100u64, 500u32).into_val(env),
+        || setup.client.try_set_late_policy(&100, &500).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn set_verifier_approval_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let verifier = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &verifier,
+        "set_verifier_approval",
+        (verifier.clone(), true).into_val(env),
+        || setup.client.try_set_verifier_approval(&verifier, &true).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn set_verifier_registry_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let registry = Some(Address::generate(env));
+    let domain = Symbol::new(env, "energy");
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_verifier_registry",
+        (registry.clone(), domain.clone()).into_val(env),
+        || setup.client.try_set_verifier_registry(&registry, &domain).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn set_verifier_pool_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let verifier = Address::generate(env);
+    setup.client.set_verifier_approval(&verifier, &true);
+    let pool = vec![env, verifier];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_verifier_pool",
+        (pool.clone(),).into_val(env),
+        || setup.client.try_set_verifier_pool(&pool).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-signatures")]
+fn set_verifier_key_requires_verifier() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let public_key = Some(BytesN::from_array(env, &[1u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_verifier_key",
+        (setup.verifier.clone(), public_key.clone()).into_val(env),
+        || setup.client.try_set_verifier_key(&setup.verifier, &public_key).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "evm-attestation")]
+fn set_evm_attester_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let public_key = Some(BytesN::from_array(env, &[4u8; 65]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_evm_attester",
+        (public_key.clone(),).into_val(env),
+        || setup.client.try_set_evm_attester(&public_key).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "committee")]
+fn set_bls_committee_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let keys: Vec<BytesN<96>> = Vec::new(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_bls_committee",
+        (keys.clone(), 0u32).into_val(env),
+        || setup.client.try_set_bls_committee(&keys, &0).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "hooks")]
+fn set_hooks_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let hooks = vec![env, Address::generate(env)];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_hooks",
+        (hooks.clone(),).into_val(env),
+        || setup.client.try_set_hooks(&hooks).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "archive")]
+fn set_archive_ttl_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_archive_ttl",
+        (1_000u32,).into_val(env),
+        || setup.client.try_set_archive_ttl(&1_000).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "refund-grace")]
+fn set_refund_grace_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "set_refund_grace",
+        (600u64,).into_val(env),
+        || setup.client.try_set_refund_grace(&600).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "archive")]
+fn archive_project_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    setup.client.cancel_project(&project_id);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "archive_project",
+        (project_id,).into_val(env),
+        || setup.client.try_archive_project(&project_id).is_ok(),
+    );
+}
+
+#[test]
+fn create_project_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let owner = Address::generate(env);
+    let amounts = vec![env, GOAL];
+    let deadlines = vec![env, 100u64];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "create_project",
+        (
+            owner.clone(),
+            setup.token.address.clone(),
+            GOAL,
+            amounts.clone(),
+            deadlines.clone(),
+            None::<ProjectMetadata>,
+        )
+            .into_val(env),
+        || {
+            setup
+                .client
+                .try_create_project(&owner, &setup.token.address, &GOAL, &amounts, &deadlines, &None)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "project-edits")]
+fn update_milestones_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let amounts = vec![env, MILESTONE, MILESTONE];
+    let deadlines = vec![env, 100u64, 200];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "update_milestones",
+        (project_id, amounts.clone(), deadlines.clone()).into_val(env),
+        || {
+            setup
+                .client
+                .try_update_milestones(&project_id, &amounts, &deadlines)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "payout-splits")]
+fn set_payout_recipients_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let partner = Address::generate(env);
+    let recipients = vec![env, (owner.clone(), 6_000u32), (partner.clone(), 4_000u32)];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &partner,
+        "set_payout_recipients",
+        (project_id, recipients.clone()).into_val(env),
+        || setup.client.try_set_payout_recipients(&project_id, &recipients).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "sequential")]
+fn set_sequential_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_sequential",
+        (project_id, true).into_val(env),
+        || setup.client.try_set_sequential(&project_id, &true).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn set_escalation_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_escalation",
+        (project_id, Escalation::InvestorVote).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_escalation(&project_id, &Escalation::InvestorVote)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "revenue")]
+fn set_revenue_expiry_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_revenue_expiry",
+        (project_id, Some(100u64), Escheatment::Treasury).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_revenue_expiry(&project_id, &Some(100), &Escheatment::Treasury)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "oracle-milestones")]
+fn set_oracle_milestone_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let oracle = Address::generate(env);
+    setup.client.set_oracle_approval(&oracle, &true);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_oracle_milestone",
+        (project_id, 0u32, oracle.clone(), 100i128).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_oracle_milestone(&project_id, &0, &oracle, &100)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn set_project_verifier_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let verifier = Address::generate(env);
+    setup.client.set_verifier_approval(&verifier, &true);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &verifier,
+        "set_project_verifier",
+        (project_id, Some(verifier.clone())).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_project_verifier(&project_id, &Some(verifier.clone()))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "rewards")]
+fn set_reward_tiers_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let tiers = vec![
+        env,
+        RewardTier {
+            threshold: MILESTONE,
+            reward: BytesN::from_array(env, &[1u8; 32]),
+            max_slots: None,
+            claimed: 0,
+        },
+    ];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_reward_tiers",
+        (project_id, tiers.clone()).into_val(env),
+        || setup.client.try_set_reward_tiers(&project_id, &tiers).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "donations")]
+fn set_refundable_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let beneficiary = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &beneficiary,
+        "set_refundable",
+        (project_id, false, Some(beneficiary.clone())).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_refundable(&project_id, &false, &Some(beneficiary.clone()))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "project-edits")]
+fn update_project_metadata_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let metadata = ProjectMetadata {
+        name: String::from_str(env, "Solar Farm"),
+        description_uri: String::from_str(env, "ipfs://solar-farm"),
+        category: Symbol::new(env, "energy"),
+        image_hash: None,
+        tags: Vec::new(env),
+    };
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "update_project_metadata",
+        (project_id, metadata.clone()).into_val(env),
+        || setup.client.try_update_project_metadata(&project_id, &metadata).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "investment-limits")]
+fn set_investment_limits_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &Address::generate(env),
+        "set_investment_limits",
+        (project_id, Some(10i128), Some(GOAL)).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_investment_limits(&project_id, &Some(10), &Some(GOAL))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "whitelist")]
+fn set_whitelist_mode_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let compliance = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &compliance,
+        "set_whitelist_mode",
+        (owner.clone(), project_id, true, Some(compliance.clone())).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_whitelist_mode(&owner, &project_id, &true, &Some(compliance.clone()))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "whitelist")]
+fn whitelist_is_managed_by_owner_without_compliance() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &investor,
+        "add_to_whitelist",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_add_to_whitelist(&project_id, &investor).is_ok(),
+    );
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &investor,
+        "remove_from_whitelist",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_remove_from_whitelist(&project_id, &investor).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "whitelist")]
+fn whitelist_is_managed_by_compliance_once_set() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let compliance = Address::generate(env);
+    let investor = Address::generate(env);
+    setup
+        .client
+        .set_whitelist_mode(&owner, &project_id, &true, &Some(compliance.clone()));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &compliance,
+        &owner,
+        "add_to_whitelist",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_add_to_whitelist(&project_id, &investor).is_ok(),
+    );
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &compliance,
+        &owner,
+        "remove_from_whitelist",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_remove_from_whitelist(&project_id, &investor).is_ok(),
+    );
+
+    // The owner can no longer lift the compliance account it handed over
+    assert!(setup
+        .client
+        .try_set_whitelist_mode(&owner, &project_id, &false, &None)
+        .is_err());
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &compliance,
+        &owner,
+        "set_whitelist_mode",
+        (compliance.clone(), project_id, false, None::<Address>).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_whitelist_mode(&compliance, &project_id, &false, &None)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+fn invest_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &GOAL);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "invest",
+        (project_id, investor.clone(), GOAL, None::<Address>).into_val(env),
+        || setup.client.try_invest(&project_id, &investor, &GOAL, &None).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "batch-invest")]
+fn invest_batch_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &GOAL);
+    let investments = vec![env, (project_id, GOAL)];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "invest_batch",
+        (investor.clone(), investments.clone()).into_val(env),
+        || setup.client.try_invest_batch(&investor, &investments).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "intents")]
+fn set_intent_key_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let investor = Address::generate(env);
+    let public_key = Some(BytesN::from_array(env, &[1u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &Address::generate(env),
+        "set_intent_key",
+        (investor.clone(), public_key.clone()).into_val(env),
+        || setup.client.try_set_intent_key(&investor, &public_key).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "invest-from")]
+fn invest_from_requires_spender() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, _) = create_project(&setup);
+    let investor = Address::generate(env);
+    let spender = Address::generate(env);
+    setup.token_admin.mint(&investor, &GOAL);
+    setup.token.approve(&investor, &spender, &GOAL, &1_000);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &spender,
+        &investor,
+        "invest_from",
+        (project_id, spender.clone(), investor.clone(), GOAL).into_val(env),
+        || {
+            setup
+                .client
+                .try_invest_from(&project_id, &spender, &investor, &GOAL)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "commitments")]
+fn commit_investment_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &GOAL);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "commit_investment",
+        (project_id, investor.clone(), GOAL, GOAL, 1_000u64).into_val(env),
+        || {
+            setup
+                .client
+                .try_commit_investment(&project_id, &investor, &GOAL, &GOAL, &1_000)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "commitments")]
+fn recurring_commitment_is_managed_by_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "commit_recurring",
+        (project_id, investor.clone(), MILESTONE, 10u64).into_val(env),
+        || {
+            setup
+                .client
+                .try_commit_recurring(&project_id, &investor, &MILESTONE, &10)
+                .is_ok()
+        },
+    );
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "cancel_recurring",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_cancel_recurring(&project_id, &investor).is_ok(),
+    );
+}
+
+#[test]
+fn trigger_refund_requires_caller() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = fund(&setup, project_id);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 100);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "trigger_refund",
+        (investor.clone(), project_id, 0u32).into_val(env),
+        || {
+            setup
+                .client
+                .try_trigger_refund(&investor, &project_id, &0)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "position-transfers")]
+fn transfer_position_requires_sender() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, _) = create_project(&setup);
+    let from = fund(&setup, project_id);
+    let to = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &from,
+        &to,
+        "transfer_position",
+        (project_id, from.clone(), to.clone(), MILESTONE).into_val(env),
+        || {
+            setup
+                .client
+                .try_transfer_position(&project_id, &from, &to, &MILESTONE)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+fn submit_evidence_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let evidence = BytesN::from_array(env, &[7u8; 32]);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "submit_evidence",
+        (project_id, 0u32, evidence.clone()).into_val(env),
+        || setup.client.try_submit_evidence(&project_id, &0, &evidence).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "resubmissions")]
+fn resubmit_evidence_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_max_resubmissions(&1);
+    let (project_id, owner) = create_project(&setup);
+    let evidence = BytesN::from_array(env, &[7u8; 32]);
+    setup.client.submit_evidence(&project_id, &0, &evidence);
+    setup.client.verify_milestone(&project_id, &0, &false, &None, &1);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "resubmit_evidence",
+        (project_id, 0u32, evidence.clone()).into_val(env),
+        || setup.client.try_resubmit_evidence(&project_id, &0, &evidence).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "evidence-access")]
+fn evidence_access_is_managed_by_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let envelope = Bytes::from_array(env, &[9u8; 48]);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "grant_evidence_access",
+        (project_id, 0u32, setup.verifier.clone(), envelope.clone(), expires_at).into_val(env),
+        || {
+            setup
+                .client
+                .try_grant_evidence_access(&project_id, &0, &setup.verifier, &envelope, &expires_at)
+                .is_ok()
+        },
+    );
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "revoke_evidence_access",
+        (project_id, 0u32, setup.verifier.clone()).into_val(env),
+        || {
+            setup
+                .client
+                .try_revoke_evidence_access(&project_id, &0, &setup.verifier)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+fn verify_milestone_requires_verifier() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "verify_milestone",
+        (project_id, 0u32, true, None::<BytesN<32>>, 1u32).into_val(env),
+        || setup.client.try_verify_milestone(&project_id, &0, &true, &None, &1).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn verify_milestone_requires_project_verifier_when_assigned() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, _) = create_project(&setup);
+    let verifier = Address::generate(env);
+    setup.client.set_verifier_approval(&verifier, &true);
+    setup.client.set_project_verifier(&project_id, &Some(verifier.clone()));
+    fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &verifier,
+        &setup.verifier,
+        "verify_milestone",
+        (project_id, 0u32, true, None::<BytesN<32>>, 1u32).into_val(env),
+        || {
+            setup
+                .client
+                .try_verify_milestone(&project_id, &0, &true, &None, &1)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "verifier-pool")]
+fn verify_milestone_requires_verifier_assigned_from_pool() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let verifier = Address::generate(env);
+    setup.client.set_verifier_approval(&verifier, &true);
+    setup.client.set_verifier_pool(&vec![env, verifier.clone()]);
+    let (project_id, _) = create_project(&setup);
+    fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &verifier,
+        &setup.verifier,
+        "verify_milestone",
+        (project_id, 0u32, true, None::<BytesN<32>>, 1u32).into_val(env),
+        || {
+            setup
+                .client
+                .try_verify_milestone(&project_id, &0, &true, &None, &1)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "co-verification")]
+fn co_verify_milestone_requires_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_co_verification_threshold(&Some(MILESTONE));
+    let (project_id, owner) = create_project(&setup);
+    fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "co_verify_milestone",
+        (project_id, 0u32, 1u32).into_val(env),
+        || setup.client.try_co_verify_milestone(&project_id, &0, &1).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn auto_approve_milestone_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_auto_approval(&Some(GOAL * 2), &0);
+    let (project_id, owner) = create_project(&setup);
+    fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "auto_approve_milestone",
+        (project_id, 0u32).into_val(env),
+        || setup.client.try_auto_approve_milestone(&project_id, &0).is_ok(),
+    );
+}
+
+#[test]
+fn cancel_project_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = fund(&setup, project_id);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &investor,
+        "cancel_project",
+        (project_id,).into_val(env),
+        || setup.client.try_cancel_project(&project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "updates")]
+fn post_update_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let content_hash = BytesN::from_array(env, &[3u8; 32]);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "post_update",
+        (project_id, content_hash.clone()).into_val(env),
+        || setup.client.try_post_update(&project_id, &content_hash).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "vesting")]
+fn vesting_is_managed_by_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &setup.verifier,
+        "set_vesting",
+        (project_id, Some(30u32)).into_val(env),
+        || setup.client.try_set_vesting(&project_id, &Some(30)).is_ok(),
+    );
+
+    let investor = fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    setup.client.verify_milestone(&project_id, &0, &true, &None, &1);
+    env.ledger().with_mut(|ledger| ledger.timestamp += 30 * 86_400);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &investor,
+        "claim_vested",
+        (project_id,).into_val(env),
+        || setup.client.try_claim_vested(&project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "delegation")]
+fn delegation_is_managed_by_verifier() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let delegate = Address::generate(env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &owner,
+        "delegate_verification",
+        (project_id, Some(0u32), delegate.clone(), expires_at).into_val(env),
+        || {
+            setup
+                .client
+                .try_delegate_verification(&project_id, &Some(0), &delegate, &expires_at)
+                .is_ok()
+        },
+    );
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &delegate,
+        "revoke_delegation",
+        (project_id, Some(0u32)).into_val(env),
+        || setup.client.try_revoke_delegation(&project_id, &Some(0)).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "delegation")]
+fn verify_as_delegate_requires_delegate() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, _) = create_project(&setup);
+    fund(&setup, project_id);
+    let delegate = Address::generate(env);
+    setup
+        .client
+        .delegate_verification(&project_id, &None, &delegate, &(env.ledger().timestamp() + 1_000));
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &delegate,
+        &setup.verifier,
+        "verify_as_delegate",
+        (delegate.clone(), project_id, 0u32, true, None::<BytesN<32>>, 1u32).into_val(env),
+        || {
+            setup
+                .client
+                .try_verify_as_delegate(&delegate, &project_id, &0, &true, &None, &1)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "staking")]
+fn stake_is_managed_by_verifier() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.token_admin.mint(&setup.verifier, &100);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "stake_verifier",
+        (setup.verifier.clone(), 100i128).into_val(env),
+        || setup.client.try_stake_verifier(&setup.verifier, &100).is_ok(),
+    );
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &setup.verifier,
+        &Address::generate(env),
+        "unstake_verifier",
+        (setup.verifier.clone(), 100i128).into_val(env),
+        || setup.client.try_unstake_verifier(&setup.verifier, &100).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn vote_on_milestone_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_verification_window(&Some(100));
+    let (project_id, _) = create_project(&setup);
+    setup.client.set_escalation(&project_id, &Escalation::InvestorVote);
+    let investor = fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    setup.client.escalate_milestone(&project_id, &0);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &setup.verifier,
+        "vote_on_milestone",
+        (project_id, 0u32, investor.clone(), true).into_val(env),
+        || {
+            setup
+                .client
+                .try_vote_on_milestone(&project_id, &0, &investor, &true)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn invest_and_vote_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_verification_window(&Some(100));
+    let owner = Address::generate(env);
+    let project_id = setup.client.create_project(
+        &owner,
+        &setup.token.address,
+        &GOAL,
+        &vec![env, GOAL],
+        &vec![env, 1_000u64],
+        &None,
+    );
+    #[cfg(feature = "listing-review")]
+    setup.client.approve_listing(&project_id);
+    setup.client.set_escalation(&project_id, &Escalation::InvestorVote);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &(GOAL / 2));
+    setup.client.invest(&project_id, &investor, &(GOAL / 2), &None);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    env.ledger().with_mut(|li| li.timestamp += 100);
+    setup.client.escalate_milestone(&project_id, &0);
+
+    let backer = Address::generate(env);
+    setup.token_admin.mint(&backer, &(GOAL / 2));
+    let votes = vec![env, (0u32, true)];
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &backer,
+        &investor,
+        "invest_and_vote",
+        (project_id, backer.clone(), GOAL / 2, votes.clone()).into_val(env),
+        || {
+            setup
+                .client
+                .try_invest_and_vote(&project_id, &backer, &(GOAL / 2), &votes)
+                .is_ok()
+        },
+    );
+    assert_eq!(setup.client.get_milestone_vote(&project_id, &0).unwrap().approve, GOAL / 2);
+}
+
+#[test]
+#[cfg(feature = "revenue")]
+fn deposit_revenue_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = fund(&setup, project_id);
+    setup.token_admin.mint(&owner, &MILESTONE);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &investor,
+        "deposit_revenue",
+        (project_id, MILESTONE).into_val(env),
+        || setup.client.try_deposit_revenue(&project_id, &MILESTONE).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "revenue")]
+fn claim_revenue_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = fund(&setup, project_id);
+    setup.token_admin.mint(&owner, &MILESTONE);
+    setup.client.deposit_revenue(&project_id, &MILESTONE);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "claim_revenue",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_claim_revenue(&project_id, &investor).is_ok(),
+    );
+}
+
+#[test]
+fn claim_refund_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = fund(&setup, project_id);
+    setup
+        .client
+        .submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    setup.client.verify_milestone(&project_id, &0, &false, &None, &1);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "claim_refund",
+        (project_id, investor.clone()).into_val(env),
+        || setup.client.try_claim_refund(&project_id, &investor).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "auto-reinvest")]
+fn set_auto_reinvest_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let investor = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "set_auto_reinvest",
+        (investor.clone(), Some(project_id)).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_auto_reinvest(&investor, &Some(project_id))
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "matching")]
+fn matching_round_is_funded_by_sponsor_and_joined_by_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let sponsor = Address::generate(env);
+    setup.token_admin.mint(&sponsor, &MILESTONE);
+    let ends_at = env.ledger().timestamp() + 50;
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &sponsor,
+        &owner,
+        "create_matching_round",
+        (sponsor.clone(), setup.token.address.clone(), MILESTONE, ends_at).into_val(env),
+        || {
+            setup
+                .client
+                .try_create_matching_round(&sponsor, &setup.token.address, &MILESTONE, &ends_at)
+                .is_ok()
+        },
+    );
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &sponsor,
+        "join_matching_round",
+        (1u32, project_id).into_val(env),
+        || setup.client.try_join_matching_round(&1, &project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "matching")]
+fn commit_sponsor_match_requires_sponsor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let sponsor = Address::generate(env);
+    setup.token_admin.mint(&sponsor, &MILESTONE);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &sponsor,
+        &owner,
+        "commit_sponsor_match",
+        (project_id, sponsor.clone(), 10_000u32, MILESTONE).into_val(env),
+        || {
+            setup
+                .client
+                .try_commit_sponsor_match(&project_id, &sponsor, &10_000, &MILESTONE)
+                .is_ok()
+        },
+    );
+}
+
+#[test]
+#[cfg(feature = "referrals")]
+fn referral_program_is_managed_by_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let referrer = Address::generate(env);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &referrer,
+        "set_referral_program",
+        (project_id, 100u32, ReferralFunding::PlatformFee).into_val(env),
+        || {
+            setup
+                .client
+                .try_set_referral_program(&project_id, &100, &ReferralFunding::PlatformFee)
+                .is_ok()
+        },
+    );
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &referrer,
+        "end_referral_program",
+        (project_id,).into_val(env),
+        || setup.client.try_end_referral_program(&project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "referrals")]
+fn claim_referral_rewards_requires_referrer() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let referrer = Address::generate(env);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&owner, &MILESTONE);
+    setup.token_admin.mint(&investor, &GOAL);
+    setup.client.set_referral_program(&project_id, &100, &ReferralFunding::Budget(MILESTONE));
+    setup.client.invest(&project_id, &investor, &GOAL, &Some(referrer.clone()));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &referrer,
+        &owner,
+        "claim_referral_rewards",
+        (referrer.clone(), project_id).into_val(env),
+        || {
+            setup
+                .client
+                .try_claim_referral_rewards(&referrer, &project_id)
+                .is_ok()
+        },
+    );
+}
+
+// Funds a project under a dispute window and approves its milestone, so
+// that its release is held; returns the project id, investor and arbiter
+#[cfg(feature = "disputes")]
+fn hold_release(setup: &Setup) -> (u32, Address, Address) {
+    let env = &setup.env;
+    let arbiter = Address::generate(env);
+    setup.client.set_arbiter(&arbiter);
+    setup.client.set_dispute_window(&Some(100));
+    let (project_id, _) = create_project(setup);
+    let investor = fund(setup, project_id);
+    setup.client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    setup.client.verify_milestone(&project_id, &0, &true, &None, &1);
+    (project_id, investor, arbiter)
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn open_dispute_requires_challenger() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, investor, arbiter) = hold_release(&setup);
+    setup.token_admin.mint(&investor, &50);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &arbiter,
+        "open_dispute",
+        (project_id, 0u32, investor.clone(), 50i128).into_val(env),
+        || setup.client.try_open_dispute(&project_id, &0, &investor, &50).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn resolve_dispute_requires_arbiter() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, investor, arbiter) = hold_release(&setup);
+    setup.token_admin.mint(&investor, &50);
+    setup.client.open_dispute(&project_id, &0, &investor, &50);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &arbiter,
+        &investor,
+        "resolve_dispute",
+        (project_id, 0u32, false).into_val(env),
+        || setup.client.try_resolve_dispute(&project_id, &0, &false).is_ok(),
+    );
+}
+
+// Funds a project under an appeal window and rejects its milestone for
+// good, so that the rejection is open to appeal; returns the project id,
+// owner and arbiter
+#[cfg(feature = "disputes")]
+fn hold_rejection(setup: &Setup) -> (u32, Address, Address) {
+    let env = &setup.env;
+    let arbiter = Address::generate(env);
+    setup.client.set_arbiter(&arbiter);
+    setup.client.set_appeal_window(&Some(100));
+    let (project_id, owner) = create_project(setup);
+    fund(setup, project_id);
+    setup.client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    setup.client.verify_milestone(&project_id, &0, &false, &None, &1);
+    (project_id, owner, arbiter)
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn appeal_rejection_requires_owner() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner, arbiter) = hold_rejection(&setup);
+    let evidence = BytesN::from_array(env, &[9u8; 32]);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &owner,
+        &arbiter,
+        "appeal_rejection",
+        (project_id, 0u32, evidence.clone()).into_val(env),
+        || setup.client.try_appeal_rejection(&project_id, &0, &evidence).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "disputes")]
+fn resolve_appeal_requires_arbiter() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner, arbiter) = hold_rejection(&setup);
+    setup.client.appeal_rejection(&project_id, &0, &BytesN::from_array(env, &[9u8; 32]));
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &arbiter,
+        &owner,
+        "resolve_appeal",
+        (project_id, 0u32, true).into_val(env),
+        || setup.client.try_resolve_appeal(&project_id, &0, &true).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "insurance")]
+fn claim_insurance_requires_investor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    setup.client.set_insurance_policy(&1_000, &10_000, &GOAL);
+    let owner = Address::generate(env);
+    let project_id = setup.client.create_project(
+        &owner,
+        &setup.token.address,
+        &GOAL,
+        &vec![env, MILESTONE, MILESTONE],
+        &vec![env, 100u64, 200u64],
+        &None,
+    );
+    #[cfg(feature = "listing-review")]
+    setup.client.approve_listing(&project_id);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &(GOAL * 2));
+    setup.client.invest(&project_id, &investor, &(GOAL * 2), &None);
+    setup.client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    setup.client.verify_milestone(&project_id, &0, &true, &None, &1);
+    env.ledger().with_mut(|ledger| ledger.timestamp = 10_000);
+    setup.client.trigger_refund(&investor, &project_id, &1);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &investor,
+        &owner,
+        "claim_insurance",
+        (investor.clone(), project_id).into_val(env),
+        || setup.client.try_claim_insurance(&investor, &project_id).is_ok(),
+    );
+}
+
+#[test]
+#[cfg(feature = "audits")]
+fn post_audit_requires_auditor() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let (project_id, owner) = create_project(&setup);
+    let auditor = Address::generate(env);
+    setup.client.set_auditor_approval(&auditor, &true);
+    let report_hash = BytesN::from_array(env, &[1u8; 32]);
+
+    assert_requires_auth(
+        env,
+        &setup.client.address,
+        &auditor,
+        &owner,
+        "post_audit",
+        (auditor.clone(), project_id, None::<u32>, report_hash.clone(), AuditVerdict::Pass).into_val(env),
+        || {
+            setup
+                .client
+                .try_post_audit(&auditor, &project_id, &None, &report_hash, &AuditVerdict::Pass)
+                .is_ok()
+        },
+    );
+}
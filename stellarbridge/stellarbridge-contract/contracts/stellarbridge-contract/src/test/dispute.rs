@@ -0,0 +1,79 @@
+// Releases held for a dispute window: how long the hold lasts, what a
+// refund does with it and what an upheld dispute undoes
+
+use super::Setup;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN};
+
+// Default project fully funded by `investor`, whose first milestone was
+// approved at 10 under `window`
+fn approve_first(setup: &Setup, investor: &Address, window: u64) -> u32 {
+    let env = &setup.env;
+    let client = &setup.client;
+    client.set_dispute_window(&Some(window));
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, investor, &1_000, &None);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 10);
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    let version = client.get_project(&project_id).version;
+    client.verify_milestone(&project_id, &0, &true, &None, &version);
+    project_id
+}
+
+#[test]
+fn longest_window_holds_the_release_indefinitely() {
+    let setup = Setup::new();
+    let project_id = approve_first(&setup, &setup.investor(1_000), u64::MAX);
+
+    let pending = setup.client.get_pending_release(&project_id, &0).unwrap();
+    assert_eq!(pending.releasable_at, u64::MAX);
+}
+
+#[test]
+fn refund_returns_the_held_release_and_the_bond() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    client.set_arbiter(&Address::generate(env));
+    let investor = setup.investor(1_050);
+    let project_id = approve_first(&setup, &investor, 100);
+    client.open_dispute(&project_id, &0, &investor, &50);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+    client.trigger_refund(&investor, &project_id, &1);
+
+    assert_eq!(client.get_pending_release(&project_id, &0), None);
+    assert_eq!(client.get_dispute(&project_id, &0), None);
+    assert_eq!(setup.token.balance(&investor), 1_050);
+    assert!(client.try_release_milestone(&project_id, &0).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Arbiter not set")]
+fn dispute_without_an_arbiter_is_refused() {
+    let setup = Setup::new();
+    let investor = setup.investor(1_050);
+    let project_id = approve_first(&setup, &investor, 100);
+
+    setup.client.open_dispute(&project_id, &0, &investor, &50);
+}
+
+#[test]
+#[cfg(feature = "reputation")]
+fn upheld_dispute_takes_back_the_owners_verified_milestone() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    client.set_arbiter(&Address::generate(&setup.env));
+    let investor = setup.investor(1_050);
+    let project_id = approve_first(&setup, &investor, 100);
+    let owner = client.get_project(&project_id).owner;
+    assert_eq!(client.get_owner_reputation(&owner).milestones_verified, 1);
+
+    client.open_dispute(&project_id, &0, &investor, &50);
+    client.resolve_dispute(&project_id, &0, &true);
+
+    let reputation = client.get_owner_reputation(&owner);
+    assert_eq!(reputation.milestones_verified, 0);
+    assert_eq!(reputation.milestones_failed, 1);
+}
@@ -0,0 +1,29 @@
+// Donation projects roll missed milestones forward instead of refunding
+// them, and are done with once every milestone is
+
+use super::Setup;
+#[cfg(feature = "preflight")]
+use crate::Action;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN};
+
+#[test]
+fn project_with_a_rolled_over_milestone_can_be_archived() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.set_refundable(&project_id, &false, &Some(Address::generate(env)));
+    client.invest(&project_id, &setup.investor(1_000), &1_000, &None);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_001);
+    client.roll_over_milestone(&project_id, &0);
+    client.submit_evidence(&project_id, &1, &BytesN::from_array(env, &[7u8; 32]));
+    let version = client.get_project(&project_id).version;
+    client.verify_milestone(&project_id, &1, &true, &None, &version);
+
+    #[cfg(feature = "preflight")]
+    assert!(client.can_perform(&setup.verifier, &Action::ArchiveProject, &project_id));
+    client.archive_project(&project_id);
+    assert!(client.get_project(&project_id).archived);
+}
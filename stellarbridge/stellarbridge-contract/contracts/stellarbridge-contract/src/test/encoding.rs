@@ -0,0 +1,81 @@
+// Pins the on-chain encoding of stored enums: data-less enums are stored
+// as fixed u32 tags and enums with data by variant name, so a change that
+// would reinterpret existing storage after an upgrade fails here first.
+
+use crate::{
+    Escalation, Escheatment, MilestoneKind, MilestoneStatus, Payout, ProjectStatus, RefundProgress,
+};
+use soroban_sdk::{vec, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+// Asserts that `value` is stored as `tag` and decodes back to itself
+fn assert_tag<T>(env: &Env, value: T, tag: u32)
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + PartialEq + Copy,
+{
+    let val: Val = value.into_val(env);
+    assert_eq!(u32::try_from_val(env, &val).unwrap(), tag);
+    assert!(T::try_from_val(env, &val).ok() == Some(value));
+}
+
+#[test]
+fn milestone_status_tags_are_fixed() {
+    let env = Env::default();
+    assert_tag(&env, MilestoneStatus::Pending, 0);
+    assert_tag(&env, MilestoneStatus::EvidenceSubmitted, 1);
+    assert_tag(&env, MilestoneStatus::Verified, 2);
+    assert_tag(&env, MilestoneStatus::Rejected, 3);
+    assert_tag(&env, MilestoneStatus::RolledOver, 4);
+}
+
+#[test]
+fn project_status_tags_are_fixed() {
+    let env = Env::default();
+    assert_tag(&env, ProjectStatus::Funding, 0);
+    assert_tag(&env, ProjectStatus::InProgress, 1);
+    assert_tag(&env, ProjectStatus::Completed, 2);
+    assert_tag(&env, ProjectStatus::Refunded, 3);
+    assert_tag(&env, ProjectStatus::Cancelled, 4);
+}
+
+#[test]
+fn payout_tags_are_fixed() {
+    let env = Env::default();
+    assert_tag(&env, Payout::Revenue, 0);
+    assert_tag(&env, Payout::Refund, 1);
+    assert_tag(&env, Payout::Surplus, 2);
+    assert_tag(&env, Payout::Yield, 3);
+}
+
+#[test]
+fn policy_tags_are_fixed() {
+    let env = Env::default();
+    assert_tag(&env, Escalation::AutoApprove, 0);
+    assert_tag(&env, Escalation::InvestorVote, 1);
+    assert_tag(&env, Escalation::Refund, 2);
+    assert_tag(&env, Escheatment::NextRound, 0);
+    assert_tag(&env, Escheatment::Treasury, 1);
+}
+
+#[test]
+fn unknown_tags_are_rejected() {
+    let env = Env::default();
+    let val: Val = 5u32.into_val(&env);
+    assert!(MilestoneStatus::try_from_val(&env, &val).is_err());
+}
+
+#[test]
+fn data_enums_are_tagged_by_name() {
+    let env = Env::default();
+
+    let val: Val = RefundProgress::ResumeRequired(7).into_val(&env);
+    let expected: Vec<Val> = vec![
+        &env,
+        Symbol::new(&env, "ResumeRequired").into_val(&env),
+        7u32.into_val(&env),
+    ];
+    assert!(Vec::<Val>::try_from_val(&env, &val).unwrap() == expected);
+
+    let val: Val = MilestoneKind::Evidence.into_val(&env);
+    let expected: Vec<Val> = vec![&env, Symbol::new(&env, "Evidence").into_val(&env)];
+    assert!(Vec::<Val>::try_from_val(&env, &val).unwrap() == expected);
+}
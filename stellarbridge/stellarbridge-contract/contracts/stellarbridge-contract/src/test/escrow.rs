@@ -0,0 +1,128 @@
+// Per-project escrow accounting across investments, partial releases and
+// refunds
+
+use super::Setup;
+use crate::ContractError;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::BytesN;
+
+fn release_first(setup: &Setup, project_id: u32) {
+    let evidence = BytesN::from_array(&setup.env, &[7u8; 32]);
+    setup.client.submit_evidence(&project_id, &0, &evidence);
+    setup
+        .client
+        .verify_milestone(&project_id, &0, &true, &None, &1);
+}
+
+#[test]
+fn escrow_follows_investments_and_releases() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+
+    client.invest(&project_id, &setup.investor(300), &300, &None);
+    client.invest(&project_id, &setup.investor(700), &700, &None);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 1_000);
+
+    release_first(&setup, project_id);
+    let project = client.get_project(&project_id);
+    assert_eq!(project.raised, 1_000);
+    assert_eq!(project.escrow_balance, 600);
+}
+
+#[test]
+fn refund_after_partial_release_pays_out_the_remaining_escrow() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let first = setup.investor(600);
+    let second = setup.investor(400);
+    client.invest(&project_id, &first, &600, &None);
+    client.invest(&project_id, &second, &400, &None);
+    release_first(&setup, project_id);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+    client.trigger_refund(&first, &project_id, &1);
+
+    assert_eq!(setup.token.balance(&first), 360);
+    assert_eq!(setup.token.balance(&second), 240);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 0);
+    assert_eq!(setup.token.balance(&client.address), 0);
+}
+
+#[test]
+fn refund_of_underfunded_project_is_capped_at_its_escrow() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+
+    // A second, fully funded project leaves enough in the contract to
+    // cover an uncapped refund, which must not be touched
+    let project_id = setup.create_default_project();
+    let other_id = setup.create_default_project();
+    let first = setup.investor(300);
+    let second = setup.investor(200);
+    client.invest(&project_id, &first, &300, &None);
+    client.invest(&project_id, &second, &200, &None);
+    client.invest(&other_id, &setup.investor(1_000), &1_000, &None);
+    release_first(&setup, project_id);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 100);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+    client.trigger_refund(&first, &project_id, &1);
+
+    assert_eq!(setup.token.balance(&first), 60);
+    assert_eq!(setup.token.balance(&second), 40);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 0);
+    assert_eq!(client.get_project(&other_id).escrow_balance, 1_000);
+    assert_eq!(setup.token.balance(&client.address), 1_000);
+    #[cfg(feature = "stats")]
+    assert_eq!(client.get_stats().value_locked, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Milestone exceeds escrow balance")]
+fn release_beyond_escrow_is_rejected() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, &setup.investor(300), &300, &None);
+    client.invest(
+        &setup.create_default_project(),
+        &setup.investor(1_000),
+        &1_000,
+        &None,
+    );
+
+    release_first(&setup, project_id);
+}
+
+#[test]
+fn release_against_a_stale_version_is_refused() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, &setup.investor(1_000), &1_000, &None);
+    let evidence = BytesN::from_array(&setup.env, &[7u8; 32]);
+    client.submit_evidence(&project_id, &0, &evidence);
+
+    let result = client.try_verify_milestone(&project_id, &0, &true, &None, &0);
+    assert_eq!(result, Err(Ok(ContractError::VersionMismatch)));
+    assert_eq!(client.get_project(&project_id).escrow_balance, 1_000);
+}
+
+#[test]
+fn cancel_makes_the_whole_escrow_refundable() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let backer = setup.investor(500);
+    client.invest(&project_id, &backer, &500, &None);
+
+    client.cancel_project(&project_id);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 0);
+
+    client.claim_refund(&project_id, &backer);
+    assert_eq!(setup.token.balance(&backer), 500);
+}
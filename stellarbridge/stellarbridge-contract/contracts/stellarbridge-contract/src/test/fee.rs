@@ -0,0 +1,208 @@
+// Unit tests for each platform fee strategy, plus the fee's use on
+// milestone releases
+
+use super::Setup;
+use crate::fee::{charge, rate, validate, MAX_FEE_TIERS};
+use crate::{DynamicFee, FeeStrategy, FeeTier, ProjectMetadata};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, String, Symbol, Vec};
+
+fn tiered(env: &Env, tiers: &[(i128, u32)]) -> FeeStrategy {
+    let mut list = Vec::new(env);
+    for &(min_goal, bps) in tiers {
+        list.push_back(FeeTier { min_goal, bps });
+    }
+    FeeStrategy::Tiered(list)
+}
+
+fn dynamic(base_bps: u32, step_bps: u32, max_bps: u32) -> FeeStrategy {
+    FeeStrategy::Dynamic(DynamicFee { base_bps, step_bps, max_bps })
+}
+
+#[test]
+fn free_charges_nothing() {
+    validate(&FeeStrategy::Free);
+    assert_eq!(rate(&FeeStrategy::Free, 0, 0), 0);
+    assert_eq!(rate(&FeeStrategy::Free, i128::MAX, u32::MAX), 0);
+}
+
+#[test]
+fn flat_rate_ignores_goal_and_listings() {
+    let strategy = FeeStrategy::Flat(250);
+    validate(&strategy);
+    validate(&FeeStrategy::Flat(10_000));
+
+    assert_eq!(rate(&strategy, 1, 0), 250);
+    assert_eq!(rate(&strategy, 1_000_000, 40), 250);
+}
+
+#[test]
+#[should_panic(expected = "Fee cannot exceed 10000 basis points")]
+fn rejects_flat_rate_above_basis_points() {
+    validate(&FeeStrategy::Flat(10_001));
+}
+
+#[test]
+fn tiered_rate_follows_highest_tier_reached() {
+    let env = Env::default();
+    let strategy = tiered(&env, &[(1_000, 300), (10_000, 200), (100_000, 100)]);
+    validate(&strategy);
+
+    assert_eq!(rate(&strategy, 999, 0), 0);
+    assert_eq!(rate(&strategy, 1_000, 0), 300);
+    assert_eq!(rate(&strategy, 9_999, 0), 300);
+    assert_eq!(rate(&strategy, 10_000, 0), 200);
+    assert_eq!(rate(&strategy, 100_000, 7), 100);
+    assert_eq!(rate(&strategy, i128::MAX, 0), 100);
+}
+
+#[test]
+fn tiered_rate_from_zero_covers_every_goal() {
+    let env = Env::default();
+    let strategy = tiered(&env, &[(0, 500)]);
+    validate(&strategy);
+
+    assert_eq!(rate(&strategy, 0, 0), 500);
+    assert_eq!(rate(&strategy, 1, 0), 500);
+}
+
+#[test]
+#[should_panic(expected = "Fee strategy has no tiers")]
+fn rejects_empty_tiers() {
+    let env = Env::default();
+    validate(&tiered(&env, &[]));
+}
+
+#[test]
+#[should_panic(expected = "Too many fee tiers")]
+fn rejects_too_many_tiers() {
+    let env = Env::default();
+    let mut tiers = Vec::new(&env);
+    for min_goal in 0..=MAX_FEE_TIERS as i128 {
+        tiers.push_back(FeeTier { min_goal, bps: 1 });
+    }
+    validate(&FeeStrategy::Tiered(tiers));
+}
+
+#[test]
+#[should_panic(expected = "Fee tiers must have ascending non-negative goals")]
+fn rejects_unordered_tiers() {
+    let env = Env::default();
+    validate(&tiered(&env, &[(1_000, 100), (1_000, 50)]));
+}
+
+#[test]
+#[should_panic(expected = "Fee tiers must have ascending non-negative goals")]
+fn rejects_negative_tier_goal() {
+    let env = Env::default();
+    validate(&tiered(&env, &[(-1, 100)]));
+}
+
+#[test]
+#[should_panic(expected = "Fee cannot exceed 10000 basis points")]
+fn rejects_tier_rate_above_basis_points() {
+    let env = Env::default();
+    validate(&tiered(&env, &[(0, 100), (1_000, 10_001)]));
+}
+
+#[test]
+fn dynamic_rate_rises_with_listings_up_to_maximum() {
+    let strategy = dynamic(100, 25, 200);
+    validate(&strategy);
+
+    assert_eq!(rate(&strategy, 1_000, 0), 100);
+    assert_eq!(rate(&strategy, 1_000, 1), 125);
+    assert_eq!(rate(&strategy, 1, 3), 175);
+    assert_eq!(rate(&strategy, 1_000, 4), 200);
+    assert_eq!(rate(&strategy, 1_000, 5), 200);
+    assert_eq!(rate(&strategy, 1_000, u32::MAX), 200);
+}
+
+#[test]
+fn dynamic_rate_without_step_is_flat() {
+    let strategy = dynamic(150, 0, 150);
+    validate(&strategy);
+
+    assert_eq!(rate(&strategy, 1_000, 0), 150);
+    assert_eq!(rate(&strategy, 1_000, 99), 150);
+}
+
+#[test]
+#[should_panic(expected = "Base fee cannot exceed maximum fee")]
+fn rejects_dynamic_base_above_maximum() {
+    validate(&dynamic(300, 10, 200));
+}
+
+#[test]
+#[should_panic(expected = "Fee cannot exceed 10000 basis points")]
+fn rejects_dynamic_maximum_above_basis_points() {
+    validate(&dynamic(0, 10, 10_001));
+}
+
+#[test]
+fn charge_rounds_down() {
+    assert_eq!(charge(0, 1_000), Ok(0));
+    assert_eq!(charge(250, 1_000), Ok(25));
+    assert_eq!(charge(250, 39), Ok(0));
+    assert_eq!(charge(250, 40), Ok(1));
+    assert_eq!(charge(10_000, 1_000), Ok(1_000));
+}
+
+#[test]
+fn milestone_release_pays_category_fee_to_treasury() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let owner = Address::generate(env);
+    let investor = Address::generate(env);
+    let treasury = Address::generate(env);
+    let category = Symbol::new(env, "energy");
+    setup.token_admin.mint(&investor, &1_000);
+
+    client.set_treasury(&treasury);
+    client.set_category_fee(&category, &dynamic(200, 100, 1_000));
+
+    let metadata = ProjectMetadata {
+        name: String::from_str(env, "Solar Farm"),
+        description_uri: String::from_str(env, "ipfs://solar-farm"),
+        category,
+        image_hash: None,
+        tags: Vec::new(env),
+    };
+    client.create_project(
+        &owner,
+        &setup.token.address,
+        &1_000,
+        &vec![env, 1_000],
+        &vec![env, 100u64],
+        &Some(metadata.clone()),
+    );
+    let project_id = client.create_project(
+        &owner,
+        &setup.token.address,
+        &1_000,
+        &vec![env, 1_000],
+        &vec![env, 100u64],
+        &Some(metadata),
+    );
+    assert_eq!(client.get_project(&project_id).platform_fee_bps, 300);
+
+    client.invest(&project_id, &investor, &1_000, &None);
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    client.verify_milestone(&project_id, &0, &true, &None, &1);
+
+    assert_eq!(setup.token.balance(&treasury), 30);
+    assert_eq!(setup.token.balance(&owner), 970);
+}
+
+#[test]
+fn project_without_a_category_pays_the_default_fee() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    client.set_treasury(&Address::generate(env));
+    client.set_category_fee(&Symbol::new(env, "default"), &FeeStrategy::Flat(250));
+
+    let project_id = setup.create_default_project();
+    assert_eq!(client.get_project(&project_id).platform_fee_bps, 250);
+}
@@ -0,0 +1,192 @@
+// Lists that grow with use keep one entry per item and are read a page at
+// a time
+
+use super::Setup;
+#[cfg(feature = "config-history")]
+use crate::ConfigValue;
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+use crate::ProjectMetadata;
+#[cfg(any(feature = "config-history", feature = "listings"))]
+use soroban_sdk::testutils::Address as _;
+#[cfg(any(feature = "config-history", feature = "listings"))]
+use soroban_sdk::Address;
+#[cfg(any(feature = "decision-log", feature = "updates"))]
+use soroban_sdk::BytesN;
+#[cfg(feature = "listings")]
+use soroban_sdk::vec;
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+use soroban_sdk::{Env, String, Symbol, Vec};
+
+#[test]
+#[cfg(feature = "config-history")]
+fn config_history_pages_by_index() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let admins = [
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+    ];
+    for admin in admins.iter() {
+        client.set_admin(admin);
+    }
+
+    let page = client.get_config_history(&1, &1);
+    assert_eq!(page.len(), 1);
+    let change = page.get(0).unwrap();
+    assert!(matches!(change.old, ConfigValue::Address(old) if old == admins[0]));
+    assert!(matches!(change.new, ConfigValue::Address(new) if new == admins[1]));
+    assert_eq!(client.get_config_history(&0, &10).len(), 3);
+    assert!(client.get_config_history(&3, &10).is_empty());
+}
+
+#[test]
+#[cfg(feature = "decision-log")]
+fn verification_decisions_page_by_index() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, &setup.investor(1_000), &1_000, &None);
+
+    let evidence = BytesN::from_array(&setup.env, &[7u8; 32]);
+    for milestone_index in 0..2u32 {
+        client.submit_evidence(&project_id, &milestone_index, &evidence);
+        let version = client.get_project(&project_id).version;
+        client.verify_milestone(&project_id, &milestone_index, &true, &None, &version);
+    }
+
+    let page = client.get_verification_decisions(&project_id, &1, &5);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().milestone_index, 1);
+    assert_eq!(
+        client.get_verification_decisions(&project_id, &0, &5).len(),
+        2
+    );
+}
+
+#[test]
+#[cfg(feature = "updates")]
+fn project_updates_page_by_index() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    for byte in 1..=3u8 {
+        client.post_update(&project_id, &BytesN::from_array(&setup.env, &[byte; 32]));
+    }
+
+    let page = client.get_project_updates(&project_id, &2, &5);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get(0).unwrap().content_hash,
+        BytesN::from_array(&setup.env, &[3u8; 32])
+    );
+    assert_eq!(client.get_project_updates(&project_id, &0, &2).len(), 2);
+}
+
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+fn metadata(env: &Env, category: &str) -> ProjectMetadata {
+    ProjectMetadata {
+        name: String::from_str(env, "Community Garden"),
+        description_uri: String::from_str(env, "ipfs://garden"),
+        category: Symbol::new(env, category),
+        image_hash: None,
+        tags: Vec::new(env),
+    }
+}
+
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+fn listed(setup: &Setup, category: &str) -> Vec<u32> {
+    let mut ids = Vec::new(&setup.env);
+    let summaries =
+        setup
+            .client
+            .list_projects_by_category(&Symbol::new(&setup.env, category), &0, &10);
+    for summary in summaries.iter() {
+        ids.push_back(summary.id);
+    }
+    ids
+}
+
+#[test]
+#[cfg(all(feature = "listings", feature = "project-edits"))]
+fn recategorized_project_leaves_its_old_listing() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let owner = Address::generate(env);
+    for _ in 0..3 {
+        client.create_project(
+            &owner,
+            &setup.token.address,
+            &1_000,
+            &vec![env, 1_000],
+            &vec![env, 1_000u64],
+            &Some(metadata(env, "garden")),
+        );
+    }
+
+    // The last garden project takes the place of the one that moved
+    client.update_project_metadata(&1, &metadata(env, "energy"));
+    assert_eq!(listed(&setup, "garden"), vec![env, 3, 2]);
+    assert_eq!(listed(&setup, "energy"), vec![env, 1]);
+
+    client.update_project_metadata(&3, &metadata(env, "energy"));
+    client.update_project_metadata(&2, &metadata(env, "energy"));
+    assert!(listed(&setup, "garden").is_empty());
+    assert_eq!(listed(&setup, "energy"), vec![env, 1, 3, 2]);
+}
+
+#[test]
+#[cfg(feature = "listings")]
+fn owner_projects_page_by_index() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let owner = Address::generate(env);
+    for _ in 0..3 {
+        client.create_project(
+            &owner,
+            &setup.token.address,
+            &1_000,
+            &vec![env, 1_000],
+            &vec![env, 1_000u64],
+            &None,
+        );
+    }
+
+    assert_eq!(
+        client.get_projects_by_owner(&owner, &1, &5),
+        vec![env, 2, 3]
+    );
+    assert_eq!(client.get_projects_by_owner(&owner, &0, &1), vec![env, 1]);
+}
+
+#[test]
+#[cfg(feature = "listings")]
+fn investor_portfolio_pages_by_index() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let investor = setup.investor(600);
+    let projects = [
+        setup.create_default_project(),
+        setup.create_default_project(),
+        setup.create_default_project(),
+    ];
+    for project_id in projects.iter() {
+        client.invest(project_id, &investor, &100, &None);
+    }
+    // A second investment does not list the project again
+    client.invest(&projects[0], &investor, &300, &None);
+
+    assert_eq!(
+        client.get_investor_portfolio(&investor, &0, &2),
+        vec![env, (projects[0], 400), (projects[1], 100)]
+    );
+    assert_eq!(
+        client.get_investor_portfolio(&investor, &2, &2),
+        vec![env, (projects[2], 100)]
+    );
+    #[cfg(feature = "stats")]
+    assert_eq!(client.get_stats().investors, 1);
+}
@@ -0,0 +1,132 @@
+// Boundary tests for the checked amount arithmetic and the money paths
+// built on it
+
+use super::Setup;
+use crate::math::{add, mul, mul_div, sub, MathError};
+use crate::ContractError;
+#[cfg(feature = "fees")]
+use crate::VerificationFee;
+use soroban_sdk::testutils::{Address as _, Ledger};
+#[cfg(feature = "fees")]
+use soroban_sdk::BytesN;
+use soroban_sdk::{vec, Address};
+
+#[test]
+fn arithmetic_reaches_the_limits() {
+    assert_eq!(add(i128::MAX - 1, 1), Ok(i128::MAX));
+    assert_eq!(sub(i128::MIN + 1, 1), Ok(i128::MIN));
+    assert_eq!(mul(i128::MAX, 1), Ok(i128::MAX));
+    assert_eq!(mul_div(i128::MAX, 10_000, 10_000), Ok(i128::MAX));
+    assert_eq!(mul_div(i128::MAX, 1, 10_000), Ok(i128::MAX / 10_000));
+    assert_eq!(
+        mul_div(i128::MAX, 9_999, 10_000),
+        Ok(i128::MAX - i128::MAX / 10_000 - 1)
+    );
+    assert_eq!(mul_div(-7, 1, 2), Ok(-3));
+}
+
+#[test]
+fn add_past_max_overflows() {
+    assert_eq!(add(i128::MAX, 1), Err(MathError::Overflow));
+}
+
+#[test]
+fn sub_past_min_overflows() {
+    assert_eq!(sub(i128::MIN, 1), Err(MathError::Overflow));
+}
+
+#[test]
+fn mul_past_max_overflows() {
+    assert_eq!(mul(i128::MAX / 2 + 1, 2), Err(MathError::Overflow));
+}
+
+#[test]
+fn mul_div_with_result_past_max_overflows() {
+    assert_eq!(mul_div(i128::MAX, 3, 2), Err(MathError::Overflow));
+}
+
+#[test]
+fn mul_div_by_zero_is_rejected() {
+    assert_eq!(mul_div(1, 1, 0), Err(MathError::DivisionByZero));
+}
+
+#[test]
+fn milestone_total_past_max_overflows() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let result = setup.client.try_create_project(
+        &Address::generate(env),
+        &setup.token.address,
+        &i128::MAX,
+        &vec![env, i128::MAX, 1],
+        &vec![env, 100u64, 200u64],
+        &None,
+    );
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+}
+
+#[test]
+#[cfg(feature = "fees")]
+fn release_of_max_amount_charges_fee_exactly() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let owner = Address::generate(env);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &i128::MAX);
+
+    client.set_verification_fee(&VerificationFee::BasisPoints(100));
+    let project_id = client.create_project(
+        &owner,
+        &setup.token.address,
+        &i128::MAX,
+        &vec![env, i128::MAX],
+        &vec![env, 100u64],
+        &None,
+    );
+    #[cfg(feature = "listing-review")]
+    client.approve_listing(&project_id);
+    client.invest(&project_id, &investor, &i128::MAX, &None);
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    client.verify_milestone(&project_id, &0, &true, &None, &1);
+
+    let fee = mul_div(i128::MAX, 100, 10_000).unwrap();
+    assert_eq!(setup.token.balance(&setup.verifier), fee);
+    assert_eq!(setup.token.balance(&owner), i128::MAX - fee);
+    #[cfg(feature = "stats")]
+    assert_eq!(client.get_stats().total_released, i128::MAX);
+    #[cfg(feature = "stats")]
+    assert_eq!(client.get_stats().value_locked, 0);
+}
+
+#[test]
+fn refund_of_max_raise_splits_without_overflow() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let first = Address::generate(env);
+    let second = Address::generate(env);
+    let half = i128::MAX / 2;
+    setup.token_admin.mint(&first, &half);
+    setup.token_admin.mint(&second, &half);
+
+    let project_id = client.create_project(
+        &Address::generate(env),
+        &setup.token.address,
+        &(half * 2),
+        &vec![env, half * 2],
+        &vec![env, 100u64],
+        &None,
+    );
+    #[cfg(feature = "listing-review")]
+    client.approve_listing(&project_id);
+    client.invest(&project_id, &first, &half, &None);
+    client.invest(&project_id, &second, &half, &None);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 101);
+    client.trigger_refund(&first, &project_id, &0);
+
+    assert_eq!(setup.token.balance(&first), half);
+    assert_eq!(setup.token.balance(&second), half);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+extern crate std;
+
+#[cfg(all(feature = "resubmissions", feature = "verifier-signatures"))]
+mod attestation;
+mod auth;
+#[cfg(feature = "disputes")]
+mod dispute;
+#[cfg(all(feature = "archive", feature = "donations"))]
+mod donation;
+mod encoding;
+mod escrow;
+#[cfg(feature = "fees")]
+mod fee;
+#[cfg(any(
+    feature = "config-history",
+    feature = "decision-log",
+    feature = "listings",
+    feature = "updates"
+))]
+mod history;
+mod math;
+mod model;
+#[cfg(feature = "oracle-milestones")]
+mod oracle;
+#[cfg(feature = "yield-pool")]
+mod pool;
+mod refund;
+#[cfg(feature = "share-token")]
+mod share;
+mod smart_wallet;
+mod splitter;
+mod surplus;
+#[cfg(feature = "rewards")]
+mod tiers;
+#[cfg(feature = "preflight")]
+mod timeline;
+
+use crate::{StellarBridgeContract, StellarBridgeContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+// Deployed contract plus the verifier and token it was initialized with
+pub struct Setup<'a> {
+    pub env: Env,
+    pub client: StellarBridgeContractClient<'a>,
+    pub verifier: Address,
+    pub token: token::Client<'a>,
+    pub token_admin: token::StellarAssetClient<'a>,
+}
+
+impl Setup<'_> {
+    pub fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let verifier = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let token = token::Client::new(&env, &sac.address());
+        let token_admin = token::StellarAssetClient::new(&env, &sac.address());
+
+        let contract_id = env.register(StellarBridgeContract, ());
+        let client = StellarBridgeContractClient::new(&env, &contract_id);
+        client.initialize(&verifier, &sac.address());
+
+        Setup {
+            env,
+            client,
+            verifier,
+            token,
+            token_admin,
+        }
+    }
+
+    // Two-milestone project of 400 then 600 for a fresh owner, with
+    // deadlines at 1000 and 2000, approved for listing
+    pub fn create_default_project(&self) -> u32 {
+        let env = &self.env;
+        let project_id = self.client.create_project(
+            &Address::generate(env),
+            &self.token.address,
+            &1_000,
+            &vec![env, 400, 600],
+            &vec![env, 1_000u64, 2_000u64],
+            &None,
+        );
+        #[cfg(feature = "listing-review")]
+        self.client.approve_listing(&project_id);
+        project_id
+    }
+
+    // Fresh address holding `amount` of the project token
+    pub fn investor(&self, amount: i128) -> Address {
+        let investor = Address::generate(&self.env);
+        self.token_admin.mint(&investor, &amount);
+        investor
+    }
+}
@@ -0,0 +1,431 @@
+// Model-based tests: random sequences of valid and invalid operations are
+// applied both to a plain in-Rust reference model and to the contract, and
+// every outcome, as well as the observable state afterwards, must agree.
+
+extern crate std;
+
+use super::Setup;
+use crate::MilestoneStatus;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+use std::format;
+use std::vec::Vec as StdVec;
+
+const SEEDS: u64 = 16;
+const STEPS: usize = 60;
+const OWNERS: usize = 2;
+const INVESTORS: usize = 3;
+const INITIAL_BALANCE: i128 = 10_000;
+
+// Deterministic xorshift64* generator so a failing seed can be replayed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn range(&mut self, lo: i128, hi: i128) -> i128 {
+        lo + (self.next() % (hi - lo + 1) as u64) as i128
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Pending,
+    EvidenceSubmitted,
+    Verified,
+    Rejected,
+    RolledOver,
+}
+
+impl From<MilestoneStatus> for Status {
+    fn from(status: MilestoneStatus) -> Self {
+        match status {
+            MilestoneStatus::Pending => Status::Pending,
+            MilestoneStatus::EvidenceSubmitted => Status::EvidenceSubmitted,
+            MilestoneStatus::Verified => Status::Verified,
+            MilestoneStatus::Rejected => Status::Rejected,
+            MilestoneStatus::RolledOver => Status::RolledOver,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Op {
+    Create { owner: usize, goal: i128, amounts: StdVec<i128>, deadlines: StdVec<u64> },
+    Invest { project: u32, investor: usize, amount: i128 },
+    Transfer { project: u32, from: usize, to: usize, amount: i128 },
+    Submit { project: u32, milestone: u32 },
+    Verify { project: u32, milestone: u32, approved: bool },
+    Refund { project: u32, milestone: u32, caller: usize },
+    Advance { seconds: u64 },
+}
+
+#[derive(Clone)]
+struct ModelMilestone {
+    amount: i128,
+    deadline: u64,
+    status: Status,
+}
+
+#[derive(Clone)]
+struct ModelProject {
+    owner: usize,
+    goal: i128,
+    raised: i128,
+    escrow: i128,
+    active: bool,
+    milestones: StdVec<ModelMilestone>,
+    positions: [i128; INVESTORS],
+}
+
+impl ModelProject {
+    // Rejections are final without resubmissions, so a project is done once
+    // every milestone is decided
+    fn finished(&self) -> bool {
+        self.milestones
+            .iter()
+            .all(|m| m.status == Status::Verified || m.status == Status::Rejected)
+    }
+
+    // What was raised over the goal leaves escrow as a claimable surplus
+    fn release_surplus(&mut self) {
+        self.escrow -= (self.raised - self.goal).min(self.escrow).max(0);
+    }
+}
+
+// Reference model of the contract's intended behavior
+#[derive(Clone)]
+struct Model {
+    now: u64,
+    projects: StdVec<ModelProject>,
+    investor_balances: [i128; INVESTORS],
+    owner_balances: [i128; OWNERS],
+    escrow: i128,
+}
+
+impl Model {
+    fn new() -> Self {
+        Model {
+            now: 0,
+            projects: StdVec::new(),
+            investor_balances: [INITIAL_BALANCE; INVESTORS],
+            owner_balances: [0; OWNERS],
+            escrow: 0,
+        }
+    }
+
+    // Apply `op` atomically; returns whether it succeeded
+    fn apply(&mut self, op: &Op) -> bool {
+        let mut next = self.clone();
+        let ok = next.step(op).is_some();
+        if ok {
+            *self = next;
+        }
+        ok
+    }
+
+    fn project(&mut self, id: u32) -> Option<&mut ModelProject> {
+        let index = (id as usize).checked_sub(1)?;
+        self.projects.get_mut(index)
+    }
+
+    fn step(&mut self, op: &Op) -> Option<()> {
+        match *op {
+            Op::Create { owner, goal, ref amounts, ref deadlines } => {
+                check(amounts.len() == deadlines.len())?;
+                check(amounts.iter().all(|&amount| amount > 0))?;
+                check(deadlines.first().is_none_or(|&first| first > self.now))?;
+                check(deadlines.windows(2).all(|pair| pair[0] < pair[1]))?;
+                check(amounts.iter().sum::<i128>() == goal)?;
+                let milestones = amounts
+                    .iter()
+                    .zip(deadlines)
+                    .map(|(&amount, &deadline)| ModelMilestone {
+                        amount,
+                        deadline,
+                        status: Status::Pending,
+                    })
+                    .collect();
+                self.projects.push(ModelProject {
+                    owner,
+                    goal,
+                    raised: 0,
+                    escrow: 0,
+                    active: true,
+                    milestones,
+                    positions: [0; INVESTORS],
+                });
+            }
+            Op::Invest { project, investor, amount } => {
+                check(amount > 0 && self.investor_balances[investor] >= amount)?;
+                let p = self.project(project)?;
+                check(p.active && !p.finished())?;
+                p.raised += amount;
+                p.escrow += amount;
+                p.positions[investor] += amount;
+                self.investor_balances[investor] -= amount;
+                self.escrow += amount;
+            }
+            Op::Transfer { project, from, to, amount } => {
+                // Builds without position transfers refuse them all
+                check(cfg!(feature = "position-transfers"))?;
+                let p = self.project(project)?;
+                check(p.active && amount > 0 && from != to && p.positions[from] >= amount)?;
+                p.positions[from] -= amount;
+                p.positions[to] += amount;
+            }
+            Op::Submit { project, milestone } => {
+                let p = self.project(project)?;
+                check(p.active)?;
+                let m = p.milestones.get_mut(milestone as usize)?;
+                check(m.status == Status::Pending)?;
+                m.status = Status::EvidenceSubmitted;
+            }
+            Op::Verify { project, milestone, approved } => {
+                let p = self.project(project)?;
+                check(p.active)?;
+                let owner = p.owner;
+                let escrow = p.escrow;
+                let m = p.milestones.get_mut(milestone as usize)?;
+                check(m.status == Status::EvidenceSubmitted)?;
+                let mut released = 0;
+                if approved {
+                    released = m.amount;
+                    check(released >= 0 && released <= escrow)?;
+                    m.status = Status::Verified;
+                    p.escrow -= released;
+                } else {
+                    let refund = m.amount.min(escrow);
+                    m.status = Status::Rejected;
+                    p.escrow -= refund;
+                }
+                if p.finished() {
+                    p.release_surplus();
+                }
+                self.escrow -= released;
+                self.owner_balances[owner] += released;
+            }
+            Op::Refund { project, milestone, caller } => {
+                let now = self.now;
+                let p = self.project(project)?;
+                check(p.active && p.positions[caller] > 0)?;
+                let m = p.milestones.get(milestone as usize)?;
+                check(now >= m.deadline && m.status != Status::Verified)?;
+
+                let unverified: i128 = p.milestones
+                    .iter()
+                    .filter(|m| m.status != Status::Verified && m.status != Status::Rejected)
+                    .map(|m| m.amount)
+                    .sum::<i128>()
+                    .min(p.escrow);
+                let mut refunds = [0i128; INVESTORS];
+                for (refund, &position) in refunds.iter_mut().zip(&p.positions) {
+                    if position > 0 {
+                        *refund = ((position * unverified) / p.raised).max(0);
+                    }
+                }
+                let refunded: i128 = refunds.iter().sum();
+                check(refunded <= p.escrow)?;
+
+                p.active = false;
+                p.escrow -= unverified;
+                p.release_surplus();
+                for (i, refund) in refunds.iter().enumerate() {
+                    self.investor_balances[i] += refund;
+                    self.escrow -= refund;
+                }
+            }
+            Op::Advance { seconds } => {
+                self.now += seconds;
+            }
+        }
+        Some(())
+    }
+}
+
+fn check(condition: bool) -> Option<()> {
+    condition.then_some(())
+}
+
+fn random_op(rng: &mut Rng, model: &Model) -> Op {
+    // Mostly target existing projects; ids and indices sometimes overshoot
+    // by one to exercise bad input
+    let count = model.projects.len() as u64;
+    let project = if count > 0 && rng.below(4) != 0 {
+        1 + rng.below(count) as u32
+    } else {
+        rng.below(count + 2) as u32
+    };
+    let milestones = model
+        .projects
+        .get((project as usize).wrapping_sub(1))
+        .map_or(0, |p| p.milestones.len() as u64);
+    let milestone = rng.below(milestones + 1) as u32;
+
+    match rng.below(10) {
+        0 => {
+            let count = 1 + rng.below(3) as usize;
+            let amounts: StdVec<i128> = (0..count).map(|_| rng.range(-10, 600)).collect();
+            // Steps of zero give a late or repeated deadline
+            let mut deadline = model.now;
+            let deadlines = (0..count)
+                .map(|_| {
+                    deadline += rng.below(75);
+                    deadline
+                })
+                .collect();
+            Op::Create {
+                owner: rng.below(OWNERS as u64) as usize,
+                // Mostly the exact total, sometimes one off either way
+                goal: amounts.iter().sum::<i128>() + if rng.below(4) == 0 { rng.range(-1, 1) } else { 0 },
+                amounts,
+                deadlines,
+            }
+        }
+        1 | 2 => Op::Invest {
+            project,
+            investor: rng.below(INVESTORS as u64) as usize,
+            amount: rng.range(-5, 1_500),
+        },
+        3 => Op::Transfer {
+            project,
+            from: rng.below(INVESTORS as u64) as usize,
+            to: rng.below(INVESTORS as u64) as usize,
+            amount: rng.range(-5, 800),
+        },
+        4 | 5 => Op::Submit { project, milestone },
+        6 | 7 => Op::Verify {
+            project,
+            milestone,
+            approved: rng.below(3) != 0,
+        },
+        8 => Op::Refund {
+            project,
+            milestone,
+            caller: rng.below(INVESTORS as u64) as usize,
+        },
+        _ => Op::Advance {
+            seconds: rng.below(120),
+        },
+    }
+}
+
+#[test]
+fn contract_matches_reference_model() {
+    for seed in 0..SEEDS {
+        let setup = Setup::new();
+        let env = &setup.env;
+        let client = &setup.client;
+
+        let owners: StdVec<Address> = (0..OWNERS).map(|_| Address::generate(env)).collect();
+        let investors: StdVec<Address> = (0..INVESTORS).map(|_| Address::generate(env)).collect();
+        for investor in &investors {
+            setup.token_admin.mint(investor, &INITIAL_BALANCE);
+        }
+        let evidence = BytesN::from_array(env, &[7u8; 32]);
+
+        let mut rng = Rng::new(seed);
+        let mut model = Model::new();
+
+        for step in 0..STEPS {
+            let op = random_op(&mut rng, &model);
+            let context = format!("seed {} step {}: {:?}", seed, step, op);
+
+            let contract_ok = match op {
+                Op::Create { owner, goal, ref amounts, ref deadlines } => {
+                    // Uncategorized listings are approved as soon as they exist
+                    let created = client.try_create_project(
+                        &owners[owner],
+                        &setup.token.address,
+                        &goal,
+                        &Vec::from_slice(env, amounts),
+                        &Vec::from_slice(env, deadlines),
+                        &None,
+                    );
+                    #[cfg(feature = "listing-review")]
+                    if let Ok(Ok(project_id)) = created {
+                        client.approve_listing(&project_id);
+                    }
+                    created.is_ok()
+                }
+                Op::Invest { project, investor, amount } => client
+                    .try_invest(&project, &investors[investor], &amount, &None)
+                    .is_ok(),
+                #[cfg(feature = "position-transfers")]
+                Op::Transfer { project, from, to, amount } => client
+                    .try_transfer_position(&project, &investors[from], &investors[to], &amount)
+                    .is_ok(),
+                #[cfg(not(feature = "position-transfers"))]
+                Op::Transfer { .. } => false,
+                Op::Submit { project, milestone } => client
+                    .try_submit_evidence(&project, &milestone, &evidence)
+                    .is_ok(),
+                Op::Verify { project, milestone, approved } => {
+                    // Decide against the current version; missing projects
+                    // fail on their own
+                    let version = client
+                        .try_get_project(&project)
+                        .ok()
+                        .and_then(Result::ok)
+                        .map_or(0, |p| p.version);
+                    client
+                        .try_verify_milestone(&project, &milestone, &approved, &None, &version)
+                        .is_ok()
+                }
+                Op::Refund { project, milestone, caller } => client
+                    .try_trigger_refund(&investors[caller], &project, &milestone)
+                    .is_ok(),
+                Op::Advance { seconds } => {
+                    env.ledger().with_mut(|ledger| ledger.timestamp += seconds);
+                    true
+                }
+            };
+
+            let model_ok = model.apply(&op);
+            assert_eq!(contract_ok, model_ok, "outcome differs at {}", context);
+
+            assert_eq!(client.get_project_count(), model.projects.len() as u32, "{}", context);
+            for (index, expected) in model.projects.iter().enumerate() {
+                let id = index as u32 + 1;
+                let project = client.get_project(&id);
+                assert_eq!(project.raised, expected.raised, "raised of {} at {}", id, context);
+                assert_eq!(project.active, expected.active, "active of {} at {}", id, context);
+                assert_eq!(project.escrow_balance, expected.escrow, "escrow of {} at {}", id, context);
+                for (i, milestone) in expected.milestones.iter().enumerate() {
+                    let status = Status::from(project.milestones.get(i as u32).unwrap().status);
+                    assert_eq!(status, milestone.status, "milestone {} of {} at {}", i, id, context);
+                }
+                for (i, investor) in investors.iter().enumerate() {
+                    assert_eq!(
+                        client.get_investor_amount(&id, investor),
+                        expected.positions[i],
+                        "position of investor {} in {} at {}",
+                        i,
+                        id,
+                        context
+                    );
+                }
+            }
+            for (i, investor) in investors.iter().enumerate() {
+                assert_eq!(setup.token.balance(investor), model.investor_balances[i], "{}", context);
+            }
+            for (i, owner) in owners.iter().enumerate() {
+                assert_eq!(setup.token.balance(owner), model.owner_balances[i], "{}", context);
+            }
+            assert_eq!(setup.token.balance(&client.address), model.escrow, "{}", context);
+        }
+    }
+}
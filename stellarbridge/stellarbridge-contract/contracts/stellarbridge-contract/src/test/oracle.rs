@@ -0,0 +1,32 @@
+// Oracle milestones can only be bound to oracles the admin approved
+
+use super::Setup;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+#[should_panic(expected = "Oracle not approved")]
+fn milestone_cannot_name_an_unapproved_oracle() {
+    let setup = Setup::new();
+    let project_id = setup.create_default_project();
+    let oracle = Address::generate(&setup.env);
+
+    setup
+        .client
+        .set_oracle_milestone(&project_id, &0, &oracle, &100);
+}
+
+#[test]
+fn oracle_approval_can_be_withdrawn() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let oracle = Address::generate(&setup.env);
+    client.set_oracle_approval(&oracle, &true);
+    client.set_oracle_milestone(&project_id, &0, &oracle, &100);
+
+    client.set_oracle_approval(&oracle, &false);
+    assert!(client
+        .try_set_oracle_milestone(&project_id, &1, &oracle, &100)
+        .is_err());
+}
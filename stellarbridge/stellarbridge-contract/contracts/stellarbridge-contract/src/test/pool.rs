@@ -0,0 +1,71 @@
+// A lending pool that stops paying out can be written off, so the escrow it
+// held no longer blocks the project's releases and refunds
+
+use super::Setup;
+use crate::YieldPool;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env};
+
+// Pool that takes deposits and then refuses every withdrawal
+#[contract]
+pub struct FrozenPool;
+
+#[contractimpl]
+impl FrozenPool {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&(), &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) -> i128 {
+        let token: Address = env.storage().instance().get(&()).unwrap();
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        amount
+    }
+
+    pub fn withdraw(_env: Env, _to: Address, _shares: i128) -> i128 {
+        panic!("pool is frozen")
+    }
+}
+
+// Default project whose token is lent to a frozen pool, fully funded by
+// the returned investor
+pub fn lend_to_frozen_pool(setup: &Setup) -> (u32, Address) {
+    let env = &setup.env;
+    let client = &setup.client;
+    let pool = env.register(FrozenPool, (setup.token.address.clone(),));
+    client.set_treasury(&Address::generate(env));
+    client.set_yield_pool(
+        &setup.token.address,
+        &Some(YieldPool {
+            pool,
+            investor_bps: 8_000,
+        }),
+    );
+
+    let project_id = setup.create_default_project();
+    let investor = setup.investor(1_000);
+    client.invest(&project_id, &investor, &1_000, &None);
+    (project_id, investor)
+}
+
+#[test]
+fn frozen_pool_blocks_releases_until_written_off() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let (project_id, investor) = lend_to_frozen_pool(&setup);
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    let version = client.get_project(&project_id).version;
+    assert!(client
+        .try_verify_milestone(&project_id, &0, &true, &None, &version)
+        .is_err());
+
+    client.write_off_pool_position(&project_id);
+    assert!(client.get_pool_position(&project_id).is_none());
+    assert_eq!(client.get_project(&project_id).escrow_balance, 0);
+
+    // With nothing left in escrow the refund goes through and pays nothing
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+    client.trigger_refund(&investor, &project_id, &1);
+    assert_eq!(setup.token.balance(&investor), 0);
+}
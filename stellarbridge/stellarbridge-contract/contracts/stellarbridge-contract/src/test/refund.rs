@@ -0,0 +1,23 @@
+// A triggered refund returns the whole remaining escrow, not just the share
+// of the milestone it was triggered on
+
+use super::Setup;
+use soroban_sdk::testutils::Ledger;
+
+#[test]
+fn refund_returns_escrow_of_earlier_pending_milestones() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let investor = setup.investor(1_000);
+    client.invest(&project_id, &investor, &1_000, &None);
+
+    // Triggered on the second milestone while the first is still pending
+    env.ledger().with_mut(|ledger| ledger.timestamp = 2_001);
+    client.trigger_refund(&investor, &project_id, &1);
+
+    assert_eq!(setup.token.balance(&investor), 1_000);
+    assert_eq!(setup.token.balance(&client.address), 0);
+    assert_eq!(client.get_project(&project_id).escrow_balance, 0);
+}
@@ -0,0 +1,66 @@
+// Project shares are the bundled non-transferable token: the contract mints
+// them on investment, moves them only with a transferred position and claws
+// them back once the position is refunded. Build the token's wasm first with
+//   cargo build --target wasm32v1-none --release -p stellarbridge-share-token
+
+use super::Setup;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address};
+
+mod share_token {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/stellarbridge_share_token.wasm"
+    );
+}
+
+// Default project with share tokens, and the client for its shares
+fn create<'a>(setup: &'a Setup) -> (u32, token::Client<'a>) {
+    let wasm_hash = setup.env.deployer().upload_contract_wasm(share_token::WASM);
+    setup.client.set_share_token_wasm(&wasm_hash);
+
+    let project_id = setup.create_default_project();
+    let share_token = setup.client.get_project(&project_id).share_token.unwrap();
+    (project_id, token::Client::new(&setup.env, &share_token))
+}
+
+#[test]
+#[cfg(feature = "position-transfers")]
+fn shares_move_with_a_transferred_position() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let (project_id, shares) = create(&setup);
+    let investor = setup.investor(1_000);
+    let buyer = Address::generate(&setup.env);
+    client.invest(&project_id, &investor, &1_000, &None);
+    assert_eq!(shares.balance(&investor), 1_000);
+
+    client.transfer_position(&project_id, &investor, &buyer, &300);
+    assert_eq!(shares.balance(&investor), 700);
+    assert_eq!(shares.balance(&buyer), 300);
+}
+
+#[test]
+fn holders_cannot_move_shares_off_their_position() {
+    let setup = Setup::new();
+    let (project_id, shares) = create(&setup);
+    let investor = setup.investor(1_000);
+    setup.client.invest(&project_id, &investor, &1_000, &None);
+
+    let buyer = Address::generate(&setup.env);
+    assert!(shares.try_transfer(&investor, &buyer, &300).is_err());
+    assert_eq!(shares.balance(&investor), 1_000);
+}
+
+#[test]
+fn refunded_shares_are_retired() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let (project_id, shares) = create(&setup);
+    let investor = setup.investor(1_000);
+    client.invest(&project_id, &investor, &1_000, &None);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_001);
+    client.trigger_refund(&investor, &project_id, &0);
+    assert_eq!(shares.balance(&investor), 0);
+}
@@ -0,0 +1,224 @@
+// Custom account contracts invest and get refunded end to end, with their
+// own `__check_auth` deciding every authorization instead of mocked auths
+
+extern crate std;
+
+use super::Setup;
+use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::crypto::Hash;
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::xdr::{
+    InvokeContractArgs, ScAddress, ScSymbol, ScVal, SorobanAddressCredentials,
+    SorobanAuthorizationEntry, SorobanAuthorizedFunction, SorobanAuthorizedInvocation,
+    SorobanCredentials,
+};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, vec, Address, BytesN, Env, IntoVal,
+    TryFromVal, Val, Vec,
+};
+
+#[contracttype]
+enum WalletKey {
+    Owners,
+    Threshold,
+}
+
+#[contracterror]
+#[derive(Copy, Clone)]
+enum WalletError {
+    NotEnoughSigners = 1,
+}
+
+// Multisig wallet: an authorization goes through when at least `threshold`
+// distinct owners sign it, a signature being the list of signing owners
+#[contract]
+struct MockWallet;
+
+#[contractimpl]
+impl MockWallet {
+    pub fn __constructor(env: Env, owners: Vec<u32>, threshold: u32) {
+        env.storage().instance().set(&WalletKey::Owners, &owners);
+        env.storage()
+            .instance()
+            .set(&WalletKey::Threshold, &threshold);
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for MockWallet {
+    type Signature = Vec<u32>;
+    type Error = WalletError;
+
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        _signature_payload: Hash<32>,
+        signers: Vec<u32>,
+        _auth_contexts: Vec<Context>,
+    ) -> Result<(), WalletError> {
+        let owners: Vec<u32> = env.storage().instance().get(&WalletKey::Owners).unwrap();
+        let threshold: u32 = env.storage().instance().get(&WalletKey::Threshold).unwrap();
+        let mut approvals = 0;
+        for (index, signer) in signers.iter().enumerate() {
+            let repeated = signers.first_index_of(signer) != Some(index as u32);
+            if owners.contains(signer) && !repeated {
+                approvals += 1;
+            }
+        }
+        if approvals < threshold {
+            return Err(WalletError::NotEnoughSigners);
+        }
+        Ok(())
+    }
+}
+
+// Two-of-three wallet
+fn wallet(setup: &Setup) -> Address {
+    let env = &setup.env;
+    env.register(MockWallet, (vec![env, 1u32, 2, 3], 2u32))
+}
+
+fn invocation(
+    env: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+    sub_invocations: std::vec::Vec<SorobanAuthorizedInvocation>,
+) -> SorobanAuthorizedInvocation {
+    let args: std::vec::Vec<ScVal> = args
+        .iter()
+        .map(|arg| ScVal::try_from_val(env, &arg).unwrap())
+        .collect();
+    SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: ScAddress::from(contract),
+            function_name: ScSymbol(fn_name.try_into().unwrap()),
+            args: args.try_into().unwrap(),
+        }),
+        sub_invocations: sub_invocations.try_into().unwrap(),
+    }
+}
+
+// Stop mocking auths and have `wallet` authorize `root_invocation` with the
+// given owners' signatures
+fn authorize(
+    setup: &Setup,
+    wallet: &Address,
+    signers: Vec<u32>,
+    root_invocation: SorobanAuthorizedInvocation,
+) {
+    let env = &setup.env;
+    let signature: Val = signers.into_val(env);
+    env.set_auths(&[SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: ScAddress::from(wallet),
+            nonce: env.ledger().sequence() as i64,
+            signature_expiration_ledger: env.ledger().sequence() + 100,
+            signature: ScVal::try_from_val(env, &signature).unwrap(),
+        }),
+        root_invocation,
+    }]);
+}
+
+// The wallet's invest call, with the token transfer it authorizes inside it
+fn invest_invocation(
+    setup: &Setup,
+    project_id: u32,
+    wallet: &Address,
+    amount: i128,
+) -> SorobanAuthorizedInvocation {
+    let env = &setup.env;
+    let contract = &setup.client.address;
+    let transfer = invocation(
+        env,
+        &setup.token.address,
+        "transfer",
+        (wallet.clone(), contract.clone(), amount).into_val(env),
+        std::vec![],
+    );
+    invocation(
+        env,
+        contract,
+        "invest",
+        (project_id, wallet.clone(), amount, None::<Address>).into_val(env),
+        std::vec![transfer],
+    )
+}
+
+#[test]
+fn multisig_wallet_invests_and_is_refunded() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let wallet = wallet(&setup);
+    setup.token_admin.mint(&wallet, &1_000);
+
+    authorize(
+        &setup,
+        &wallet,
+        vec![env, 1, 3],
+        invest_invocation(&setup, project_id, &wallet, 1_000),
+    );
+    client.invest(&project_id, &wallet, &1_000, &None);
+    assert_eq!(client.get_investor_amount(&project_id, &wallet), 1_000);
+    assert_eq!(setup.token.balance(&wallet), 0);
+
+    // Rejecting the first milestone makes its 400 refundable
+    env.mock_all_auths();
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    let version = client.get_project(&project_id).version;
+    client.verify_milestone(&project_id, &0, &false, &None, &version);
+
+    env.ledger().with_mut(|ledger| ledger.sequence_number += 1);
+    let claim = invocation(
+        env,
+        &client.address,
+        "claim_refund",
+        (project_id, wallet.clone()).into_val(env),
+        std::vec![],
+    );
+    authorize(&setup, &wallet, vec![env, 2, 3], claim);
+    assert_eq!(client.claim_refund(&project_id, &wallet), 400);
+    assert_eq!(setup.token.balance(&wallet), 400);
+}
+
+#[test]
+fn wallet_rejecting_the_signatures_blocks_the_investment() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let wallet = wallet(&setup);
+    setup.token_admin.mint(&wallet, &1_000);
+
+    authorize(
+        &setup,
+        &wallet,
+        vec![env, 1, 1],
+        invest_invocation(&setup, project_id, &wallet, 1_000),
+    );
+    assert!(client
+        .try_invest(&project_id, &wallet, &1_000, &None)
+        .is_err());
+
+    env.mock_all_auths();
+    assert_eq!(client.get_investor_amount(&project_id, &wallet), 0);
+    assert_eq!(setup.token.balance(&wallet), 1_000);
+}
+
+#[test]
+fn triggered_refund_pays_the_wallet_directly() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    let wallet = wallet(&setup);
+    setup.token_admin.mint(&wallet, &1_000);
+    client.invest(&project_id, &wallet, &1_000, &None);
+
+    env.ledger().with_mut(|ledger| ledger.timestamp = 1_001);
+    client.trigger_refund(&setup.verifier, &project_id, &0);
+
+    assert_eq!(setup.token.balance(&wallet), 1_000);
+}
@@ -0,0 +1,132 @@
+// Unit tests for the payment splitter, plus its use for milestone releases
+
+#[cfg(feature = "payout-splits")]
+use super::Setup;
+use soroban_sdk::testutils::Address as _;
+#[cfg(feature = "payout-splits")]
+use soroban_sdk::BytesN;
+use soroban_sdk::{vec, Address, Env, Vec};
+use stellarbridge_core::splitter::{allocate, validate, BASIS_POINTS};
+
+fn split(env: &Env, shares: &[u32]) -> Vec<(Address, u32)> {
+    let mut split = Vec::new(env);
+    for &share in shares {
+        split.push_back((Address::generate(env), share));
+    }
+    split
+}
+
+fn amounts(allocations: &Vec<(Address, i128)>) -> Vec<i128> {
+    let mut amounts = Vec::new(allocations.env());
+    for (_, amount) in allocations.iter() {
+        amounts.push_back(amount);
+    }
+    amounts
+}
+
+#[test]
+fn accepts_shares_summing_to_basis_points() {
+    let env = Env::default();
+    validate(&split(&env, &[BASIS_POINTS]));
+    validate(&split(&env, &[2_500, 2_500, 5_000]));
+}
+
+#[test]
+#[should_panic(expected = "Recipient shares must sum to 10000")]
+fn rejects_shares_below_basis_points() {
+    let env = Env::default();
+    validate(&split(&env, &[5_000, 4_999]));
+}
+
+#[test]
+#[should_panic(expected = "Recipient shares must sum to 10000")]
+fn rejects_shares_above_basis_points() {
+    let env = Env::default();
+    validate(&split(&env, &[5_000, 5_001]));
+}
+
+#[test]
+#[should_panic(expected = "Recipient shares must sum to 10000")]
+fn rejects_shares_that_overflow() {
+    let env = Env::default();
+    validate(&split(&env, &[u32::MAX, 10_001]));
+}
+
+#[test]
+#[should_panic(expected = "Recipient share must be positive")]
+fn rejects_zero_share() {
+    let env = Env::default();
+    validate(&split(&env, &[BASIS_POINTS, 0]));
+}
+
+#[test]
+#[should_panic(expected = "Split has no recipients")]
+fn rejects_empty_split() {
+    let env = Env::default();
+    validate(&split(&env, &[]));
+}
+
+#[test]
+#[should_panic(expected = "Too many recipients")]
+fn rejects_too_many_recipients() {
+    let env = Env::default();
+    validate(&split(&env, &[1_000; 11]));
+}
+
+#[test]
+fn remainder_goes_to_last_recipient() {
+    let env = Env::default();
+    let shares = split(&env, &[3_333, 3_333, 3_334]);
+
+    assert_eq!(amounts(&allocate(&env, &shares, 100).unwrap()), vec![&env, 33, 33, 34]);
+    assert_eq!(amounts(&allocate(&env, &shares, 2).unwrap()), vec![&env, 0, 0, 2]);
+    assert_eq!(amounts(&allocate(&env, &shares, 0).unwrap()), vec![&env, 0, 0, 0]);
+}
+
+#[test]
+fn allocations_always_add_up_to_amount() {
+    let env = Env::default();
+    let shares = split(&env, &[1, 4_999, 1_234, 3_766]);
+
+    for amount in 0..2_000i128 {
+        let allocations = amounts(&allocate(&env, &shares, amount).unwrap());
+        assert_eq!(allocations.iter().sum::<i128>(), amount, "amount {}", amount);
+        for i in 0..allocations.len() - 1 {
+            let (_, share) = shares.get(i).unwrap();
+            let exact = amount * share as i128 / BASIS_POINTS as i128;
+            assert_eq!(allocations.get(i).unwrap(), exact, "amount {}", amount);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "payout-splits")]
+fn milestone_release_is_split_between_recipients() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let owner = Address::generate(env);
+    let partner = Address::generate(env);
+    let investor = Address::generate(env);
+    setup.token_admin.mint(&investor, &999);
+
+    let project_id = client.create_project(
+        &owner,
+        &setup.token.address,
+        &999,
+        &vec![env, 999],
+        &vec![env, 100u64],
+        &None,
+    );
+    
+    #[cfg(feature = "listing-review")]
+    client.approve_listing(&project_id);
+    client.set_payout_recipients(&project_id, &vec![env, (partner.clone(), 2_500u32), (owner.clone(), 7_500u32)]);
+    client.invest(&project_id, &investor, &999, &None);
+    client.submit_evidence(&project_id, &0, &BytesN::from_array(env, &[7u8; 32]));
+    client.verify_milestone(&project_id, &0, &true, &None, &1);
+
+    assert_eq!(setup.token.balance(&partner), 249);
+    assert_eq!(setup.token.balance(&owner), 750);
+    assert_eq!(setup.token.balance(&client.address), 0);
+}
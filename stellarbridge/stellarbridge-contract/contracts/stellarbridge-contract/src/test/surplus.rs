@@ -0,0 +1,28 @@
+// A completed project returns any surplus and takes no more investment,
+// however the question is asked
+
+use super::Setup;
+#[cfg(feature = "preflight")]
+use crate::Action;
+use crate::ProjectStatus;
+use soroban_sdk::BytesN;
+
+#[test]
+fn finished_project_takes_no_more_investment() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = setup.create_default_project();
+    client.invest(&project_id, &setup.investor(1_000), &1_000, &None);
+    for index in 0..2u32 {
+        client.submit_evidence(&project_id, &index, &BytesN::from_array(env, &[7u8; 32]));
+        let version = client.get_project(&project_id).version;
+        client.verify_milestone(&project_id, &index, &true, &None, &version);
+    }
+    assert!(client.get_project(&project_id).status == ProjectStatus::Completed);
+
+    let late = setup.investor(100);
+    #[cfg(feature = "preflight")]
+    assert!(!client.can_perform(&late, &Action::Invest, &project_id));
+    assert!(client.try_invest(&project_id, &late, &100, &None).is_err());
+}
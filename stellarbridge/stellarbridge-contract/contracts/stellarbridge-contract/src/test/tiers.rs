@@ -0,0 +1,82 @@
+// Reward tiers and badges follow everything a backer has put into a
+// project, so small investments that add up to a threshold upgrade them
+
+use super::Setup;
+use crate::RewardTier;
+#[cfg(feature = "position-transfers")]
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::Events;
+#[cfg(feature = "position-transfers")]
+use soroban_sdk::Address;
+use soroban_sdk::{vec, BytesN, IntoVal, Symbol};
+
+// Default project with tiers at 100 and 500
+fn create(setup: &Setup) -> u32 {
+    let env = &setup.env;
+    let project_id = setup.create_default_project();
+    let tiers = vec![
+        env,
+        RewardTier {
+            threshold: 100,
+            reward: BytesN::from_array(env, &[1u8; 32]),
+            max_slots: None,
+            claimed: 0,
+        },
+        RewardTier {
+            threshold: 500,
+            reward: BytesN::from_array(env, &[2u8; 32]),
+            max_slots: None,
+            claimed: 0,
+        },
+    ];
+    setup.client.set_reward_tiers(&project_id, &tiers);
+    project_id
+}
+
+#[test]
+fn small_investments_add_up_to_an_upgrade() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+    let project_id = create(&setup);
+    let backer = setup.investor(500);
+
+    client.invest(&project_id, &backer, &100, &None);
+    assert_eq!(client.get_investor_tier(&project_id, &backer), Some(0));
+    for _ in 0..4 {
+        client.invest(&project_id, &backer, &100, &None);
+    }
+
+    // Events of the investment that crossed 500
+    let events = env.events().all();
+    let tier_upgraded = (
+        client.address.clone(),
+        (Symbol::new(env, "tier_upgraded"), project_id).into_val(env),
+        (backer.clone(), 0u32, 1u32).into_val(env),
+    );
+    let badge_upgraded = (
+        client.address.clone(),
+        (Symbol::new(env, "badge_upgraded"), project_id).into_val(env),
+        (backer.clone(), 1u32, 2u32).into_val(env),
+    );
+    assert!(events.contains(&tier_upgraded));
+    assert!(events.contains(&badge_upgraded));
+    assert_eq!(client.get_investor_tier(&project_id, &backer), Some(1));
+    assert_eq!(client.get_badge(&backer, &project_id).unwrap().level, 2);
+}
+
+#[test]
+#[cfg(feature = "position-transfers")]
+fn contributions_count_after_the_position_moves() {
+    let setup = Setup::new();
+    let client = &setup.client;
+    let project_id = create(&setup);
+    let backer = setup.investor(500);
+    client.invest(&project_id, &backer, &300, &None);
+
+    // Their position is back to 200, but they have put in 500
+    client.transfer_position(&project_id, &backer, &Address::generate(&setup.env), &300);
+    client.invest(&project_id, &backer, &200, &None);
+
+    assert_eq!(client.get_investor_tier(&project_id, &backer), Some(1));
+}
@@ -0,0 +1,100 @@
+// Temporal rules walked through with the testutils Timeline: which actions
+// open and close as a project passes its deadlines, grace periods,
+// verification windows and investor votes.
+
+use super::Setup;
+use crate::testutils::{Phase, Timeline};
+#[cfg(any(feature = "escalation", feature = "late-policy"))]
+use crate::Action;
+#[cfg(feature = "escalation")]
+use crate::Escalation;
+#[cfg(any(feature = "escalation", feature = "late-policy"))]
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+#[cfg(feature = "escalation")]
+use soroban_sdk::BytesN;
+
+// Default project with an investor holding enough to fund it
+fn funded_project(setup: &Setup) -> (u32, Address, Address) {
+    let project_id = setup.create_default_project();
+    let owner = setup.client.get_project(&project_id).owner;
+    (project_id, owner, setup.investor(1_000))
+}
+
+#[test]
+#[cfg(feature = "late-policy")]
+fn evidence_closes_and_refunds_open_at_the_end_of_grace() {
+    let setup = Setup::new();
+    setup.client.set_late_policy(&100, &500);
+    let (project_id, owner, investor) = funded_project(&setup);
+    let anyone = Address::generate(&setup.env);
+
+    Timeline::new(&setup.client, project_id)
+        .at(Phase::FundingOpen)
+        .open(&investor, Action::Invest)
+        .open(&owner, Action::SubmitEvidence(0))
+        .closed(&investor, Action::TriggerRefund(0))
+        .then(|client| client.invest(&project_id, &investor, &1_000, &None))
+        .after(Phase::Deadline(0))
+        .open(&owner, Action::SubmitEvidence(0))
+        .closed(&investor, Action::TriggerRefund(0))
+        .at(Phase::GraceEnd(0))
+        .open(&owner, Action::SubmitEvidence(0))
+        .open(&investor, Action::TriggerRefund(0))
+        .closed(&anyone, Action::TriggerRefund(0))
+        .after(Phase::GraceEnd(0))
+        .closed(&owner, Action::SubmitEvidence(0))
+        .open(&investor, Action::TriggerRefund(0))
+        .then(|client| {
+            client.trigger_refund(&investor, &project_id, &0);
+        })
+        .closed(&investor, Action::Invest)
+        .closed(&owner, Action::SubmitEvidence(1));
+}
+
+#[test]
+#[cfg(feature = "escalation")]
+fn unreviewed_evidence_goes_to_an_investor_vote() {
+    let setup = Setup::new();
+    setup.client.set_verification_window(&Some(500));
+    let (project_id, owner, investor) = funded_project(&setup);
+    setup
+        .client
+        .set_escalation(&project_id, &Escalation::InvestorVote);
+    let verifier = setup.verifier.clone();
+    let anyone = Address::generate(&setup.env);
+    let evidence = BytesN::from_array(&setup.env, &[7u8; 32]);
+
+    Timeline::new(&setup.client, project_id)
+        .then(|client| {
+            client.invest(&project_id, &investor, &1_000, &None);
+            client.submit_evidence(&project_id, &0, &evidence);
+        })
+        .open(&verifier, Action::VerifyMilestone(0))
+        .closed(&anyone, Action::EscalateMilestone(0))
+        .at(Phase::VerificationWindowEnd(0))
+        .open(&anyone, Action::EscalateMilestone(0))
+        .then(|client| client.escalate_milestone(&project_id, &0))
+        .closed(&verifier, Action::VerifyMilestone(0))
+        .open(&investor, Action::VoteOnMilestone(0))
+        .closed(&investor, Action::TransferPosition)
+        .closed(&anyone, Action::FinalizeMilestoneVote(0))
+        .then(|client| client.vote_on_milestone(&project_id, &0, &investor, &true))
+        .closed(&investor, Action::VoteOnMilestone(0))
+        .at(Phase::VoteEnd(0))
+        .open(&anyone, Action::FinalizeMilestoneVote(0))
+        .then(|client| client.finalize_milestone_vote(&project_id, &0))
+        .open(&investor, Action::TransferPosition)
+        .open(&owner, Action::SubmitEvidence(1));
+}
+
+#[test]
+#[should_panic(expected = "timeline cannot move back")]
+fn timeline_never_runs_backwards() {
+    let setup = Setup::new();
+    let (project_id, _, _) = funded_project(&setup);
+
+    Timeline::new(&setup.client, project_id)
+        .at(Phase::Deadline(1))
+        .at(Phase::Deadline(0));
+}
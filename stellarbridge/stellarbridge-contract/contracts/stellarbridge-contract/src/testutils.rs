@@ -0,0 +1,155 @@
+// Scripted project timelines for tests: a Timeline moves the ledger through
+// the phases of one project's life and asserts which actions `can_perform`
+// reports open at each. Each move closes one ledger and time never runs
+// backwards; sequence-based expiry is not simulated.
+
+use crate::{Action, StellarBridgeContractClient};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::Address;
+
+// Point in a project's life, resolved from its current state when moved to
+#[derive(Clone, Copy, Debug)]
+pub enum Phase {
+    // When the timeline was started
+    FundingOpen,
+    // A milestone's deadline
+    Deadline(u32),
+    // The end of a milestone's late-evidence grace period
+    GraceEnd(u32),
+    // When a milestone's refund can first be triggered
+    RefundOpen(u32),
+    // When evidence left unreviewed for a milestone can be escalated
+    VerificationWindowEnd(u32),
+    // The end of the investor vote open on a milestone
+    #[cfg(feature = "escalation")]
+    VoteEnd(u32),
+}
+
+pub struct Timeline<'a> {
+    client: &'a StellarBridgeContractClient<'a>,
+    project_id: u32,
+    opened_at: u64,
+    phase: Phase,
+    past: bool,
+}
+
+impl<'a> Timeline<'a> {
+    // Start a project's timeline at the current ledger time
+    pub fn new(client: &'a StellarBridgeContractClient<'a>, project_id: u32) -> Self {
+        Timeline {
+            client,
+            project_id,
+            opened_at: client.env.ledger().timestamp(),
+            phase: Phase::FundingOpen,
+            past: false,
+        }
+    }
+
+    // Move to the instant `phase` begins
+    pub fn at(&mut self, phase: Phase) -> &mut Self {
+        let timestamp = self.time_of(phase);
+        self.move_to(timestamp, phase, false)
+    }
+
+    // Move to one second after `phase` begins
+    pub fn after(&mut self, phase: Phase) -> &mut Self {
+        let timestamp = self.time_of(phase) + 1;
+        self.move_to(timestamp, phase, true)
+    }
+
+    // Assert that `actor` can take `action` at the current phase
+    pub fn open(&mut self, actor: &Address, action: Action) -> &mut Self {
+        assert!(
+            self.client.can_perform(actor, &action, &self.project_id),
+            "action expected open {}",
+            self.describe()
+        );
+        self
+    }
+
+    // Assert that `actor` cannot take `action` at the current phase
+    pub fn closed(&mut self, actor: &Address, action: Action) -> &mut Self {
+        assert!(
+            !self.client.can_perform(actor, &action, &self.project_id),
+            "action expected closed {}",
+            self.describe()
+        );
+        self
+    }
+
+    // Run `step` against the contract at the current phase, e.g. to submit
+    // evidence or open a vote before moving on
+    pub fn then(&mut self, step: impl FnOnce(&StellarBridgeContractClient)) -> &mut Self {
+        step(self.client);
+        self
+    }
+
+    fn time_of(&self, phase: Phase) -> u64 {
+        let project = self.client.get_project(&self.project_id);
+        let milestone = |index: u32| {
+            project
+                .milestones
+                .get(index)
+                .expect("Invalid milestone index")
+        };
+
+        match phase {
+            Phase::FundingOpen => self.opened_at,
+            Phase::Deadline(index) => milestone(index).deadline,
+            Phase::GraceEnd(index) => milestone(index).deadline + project.late_policy.grace,
+            Phase::RefundOpen(index) => {
+                milestone(index).deadline + project.late_policy.grace + project.refund_grace
+            }
+            Phase::VerificationWindowEnd(index) => {
+                let submitted_at = milestone(index)
+                    .submitted_at
+                    .expect("Milestone has no evidence");
+                let window = project
+                    .verification_window
+                    .expect("Project has no verification window");
+                submitted_at.saturating_add(window)
+            }
+            #[cfg(feature = "escalation")]
+            Phase::VoteEnd(index) => {
+                self.client
+                    .get_milestone_vote(&self.project_id, &index)
+                    .expect("No vote open for this milestone")
+                    .ends_at
+            }
+        }
+    }
+
+    fn move_to(&mut self, timestamp: u64, phase: Phase, past: bool) -> &mut Self {
+        let env = &self.client.env;
+        let now = env.ledger().timestamp();
+        assert!(
+            timestamp >= now,
+            "timeline cannot move back from {} to {:?} at {}",
+            now,
+            phase,
+            timestamp
+        );
+
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp = timestamp;
+            ledger.sequence_number += 1;
+        });
+        self.phase = phase;
+        self.past = past;
+        self
+    }
+
+    fn describe(&self) -> Describe {
+        Describe(self.phase, self.past, self.client.env.ledger().timestamp())
+    }
+}
+
+// Current phase for assertion messages
+struct Describe(Phase, bool, u64);
+
+impl core::fmt::Display for Describe {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let when = if self.1 { "after" } else { "at" };
+        write!(f, "{} {:?} (t={})", when, self.0, self.2)
+    }
+}
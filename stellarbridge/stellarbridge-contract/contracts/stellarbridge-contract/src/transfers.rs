@@ -0,0 +1,89 @@
+// Position transfers: an investor can hand all or part of a recorded
+// position to another address, with its payouts and shares following it
+
+use soroban_sdk::{log, Address, Env, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::escalation;
+use crate::escrow::{checkpoint_payouts, settle_payouts};
+#[cfg(feature = "listings")]
+use crate::investments::index_position;
+use crate::math;
+use crate::revenue::mark_revenue_activity;
+use crate::shares;
+use crate::storage;
+use crate::types::{DataKey, Investment};
+use crate::whitelist;
+
+pub fn transfer_position(env: Env, project_id: u32, from: Address, to: Address, amount: i128) -> Result<(), ContractError> {
+    from.require_auth();
+    
+    let project = storage::project(&env, project_id);
+    
+    if !project.active {
+        panic!("{}", errors::PROJECT_NOT_ACTIVE);
+    }
+    
+    if amount <= 0 {
+        panic!("Transfer amount must be positive");
+    }
+    
+    if from == to {
+        panic!("Cannot transfer to self");
+    }
+    
+    if !whitelist::allows(&env, &project, &to) {
+        panic!("Recipient not whitelisted");
+    }
+    
+    // Positions are vote weights, so they stay put while a vote is open
+    if escalation::votes_open(&env, project_id) {
+        panic!("Positions are locked during an investor vote");
+    }
+    
+    let from_key = DataKey::InvestorAmount(project_id, from.clone());
+    let from_amount: i128 = storage::get(&env, &from_key).unwrap_or(0);
+    
+    if from_amount < amount {
+        panic!("Insufficient position");
+    }
+    
+    let to_key = DataKey::InvestorAmount(project_id, to.clone());
+    let to_amount: i128 = storage::get(&env, &to_key).unwrap_or(0);
+    
+    if let Some(max_per_investor) = project.max_per_investor {
+        if math::add(to_amount, amount)? > max_per_investor {
+            panic!("Transfer exceeds per-investor maximum");
+        }
+    }
+    
+    settle_payouts(&env, project_id, &from)?;
+    settle_payouts(&env, project_id, &to)?;
+    mark_revenue_activity(&env, project_id, &from);
+    mark_revenue_activity(&env, project_id, &to);
+    storage::set(&env, &from_key, &(from_amount - amount));
+    storage::set(&env, &to_key, &math::add(to_amount, amount)?);
+    checkpoint_payouts(&env, project_id, &from)?;
+    checkpoint_payouts(&env, project_id, &to)?;
+    #[cfg(feature = "listings")]
+    index_position(&env, &to, project_id);
+    
+    shares::move_shares(&env, &project, &from, &to, amount);
+    
+    // Record the transfer in the investments history
+    let investments_key = DataKey::ProjectInvestments(project_id);
+    let mut investments: Vec<Investment> = storage::get(&env, &investments_key).unwrap_or(Vec::new(&env));
+    
+    investments.push_back(Investment {
+        investor: to.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+        from: Some(from.clone()),
+    });
+    
+    storage::set(&env, &investments_key, &investments);
+    
+    log!(&env, "Position of {} in project {} transferred from {} to {}", amount, project_id, from, to);
+    
+    Ok(())
+}
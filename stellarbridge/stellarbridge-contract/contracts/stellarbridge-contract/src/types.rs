@@ -0,0 +1,1244 @@
+// Contract types: every stored struct and enum, the storage keys and the
+// oracle interface milestones can be verified against
+
+// Feature keys keep their `DataKey` variant names even where they all share
+// a prefix, since those names are what their entries are stored under
+#![allow(clippy::enum_variant_names)]
+
+#[cfg(feature = "evidence-access")]
+use soroban_sdk::Bytes;
+use soroban_sdk::{contractclient, contracttype, Address, BytesN, Env, String, Symbol, Vec};
+
+// Stored enums keep their on-chain encoding across upgrades. Enums without
+// data are stored as their u32 tag: a tag is never renumbered or reused,
+// new variants take the next free tag in their enum's open range, and the
+// range from 1000 up is reserved for variants added by forks. Enums with
+// data are stored by variant name, so their variants can be reordered but
+// never renamed.
+
+// Milestone status; tags 0-999 open
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum MilestoneStatus {
+    Pending = 0,
+    EvidenceSubmitted = 1,
+    Verified = 2,
+    Rejected = 3,
+    RolledOver = 4,
+}
+
+// How a milestone is verified: by the verifier reviewing submitted
+// evidence, or automatically once an oracle reports the expected value
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum MilestoneKind {
+    Evidence,
+    OracleVerified(OracleCondition),
+}
+
+// Oracle condition: holds once `oracle` reports at least `expected`
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub struct OracleCondition {
+    pub oracle: Address,
+    pub expected: i128,
+}
+
+// Interface oracle milestones read: the current value of whatever the
+// oracle tracks, e.g. a protocol's TVL
+#[contractclient(name = "OracleClient")]
+pub trait Oracle {
+    fn value(env: Env) -> i128;
+}
+
+// Interface of the lending pools idle escrow is deposited in, after
+// Blend-style pools that mint shares against deposits and let them
+// appreciate as interest accrues
+#[contractclient(name = "LendingPoolClient")]
+pub trait LendingPool {
+    // Take `amount` of the pool's token from `from`; returns the shares minted
+    fn deposit(env: Env, from: Address, amount: i128) -> i128;
+    // Burn `shares` and pay what they are worth to `to`; returns the amount paid
+    fn withdraw(env: Env, to: Address, shares: i128) -> i128;
+}
+
+// Asset a price oracle quotes, as in Reflector feeds
+#[cfg(feature = "usd-goals")]
+#[contracttype]
+#[derive(Clone)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+// A quoted price with `decimals()` decimal places and when it was recorded
+#[cfg(feature = "usd-goals")]
+#[contracttype]
+#[derive(Clone)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+// Interface of the USD price oracles goals can be denominated against,
+// after Reflector feeds
+#[cfg(feature = "usd-goals")]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn decimals(env: Env) -> u32;
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+// Interface of the observer hooks told of investments, milestone releases
+// and refunds as they happen
+#[contractclient(name = "HookClient")]
+pub trait Hook {
+    fn on_invest(env: Env, project_id: u32, investor: Address, amount: i128);
+    fn on_release(env: Env, project_id: u32, amount: i128);
+    fn on_refund(env: Env, project_id: u32, investor: Address, amount: i128);
+}
+
+// Interface of the registry contracts verifiers can be resolved through,
+// which keep their own verifier sets per domain
+#[contractclient(name = "VerifierRegistryClient")]
+pub trait VerifierRegistry {
+    fn is_registered(env: Env, address: Address, domain: Symbol) -> bool;
+}
+
+// Individual milestone; `resubmissions` counts evidence resubmitted after
+// a rejection, `rejection_reason` is the hash of the latest rejection's
+// off-chain explanation, `submitted_at` is when evidence was last submitted,
+// `appealable_until` is when a rejection held open for appeal settles
+#[contracttype]
+#[derive(Clone)]
+pub struct Milestone {
+    pub amount: i128,
+    pub deadline: u64,
+    pub kind: MilestoneKind,
+    pub status: MilestoneStatus,
+    pub evidence_hash: Option<BytesN<32>>,
+    pub submitted_at: Option<u64>,
+    pub resubmissions: u32,
+    pub rejection_reason: Option<BytesN<32>>,
+    pub appealable_until: Option<u64>,
+}
+
+// Project structure; `owner` is the lead who signs owner actions, while
+// milestone releases are split across `recipients` by basis points (all to
+// the owner when empty); `verifier` overrides the global verifier;
+// `escrow_balance` is what the contract still holds of `raised` for
+// milestones not yet released or made refundable; `version` counts changes
+// to the milestones, so a decision can be pinned to the state it reviewed
+#[contracttype]
+#[derive(Clone)]
+pub struct Project {
+    pub id: u32,
+    pub owner: Address,
+    pub goal_amount: i128,
+    pub raised: i128,
+    pub escrow_balance: i128,
+    pub milestones: Vec<Milestone>,
+    pub active: bool,
+    pub share_token: Option<Address>,
+    pub token: Address,
+    pub whitelist_only: bool,
+    pub compliance: Option<Address>,
+    pub min_investment: Option<i128>,
+    pub max_per_investor: Option<i128>,
+    pub max_resubmissions: u32,
+    pub recipients: Vec<(Address, u32)>,
+    pub vesting_days: Option<u32>,
+    pub sequential: bool,
+    pub pending_approval: bool,
+    pub verification_window: Option<u64>,
+    pub escalation: Escalation,
+    pub revenue_expiry: Option<u64>,
+    pub escheatment: Escheatment,
+    pub verification_fee: VerificationFee,
+    pub late_policy: LatePolicy,
+    pub verifier: Option<Address>,
+    pub archived: bool,
+    pub platform_fee_bps: u32,
+    pub status: ProjectStatus,
+    pub refund_grace: u64,
+    pub version: u32,
+    pub refundable: bool,
+    pub beneficiary: Option<Address>,
+}
+
+// Where a project is in its life: raising until the goal is met or a
+// milestone is verified, then in progress until every milestone is verified
+// or finally rejected. Cancelled and refunded projects are wound down.
+// Tags 0-999 open.
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum ProjectStatus {
+    Funding = 0,
+    InProgress = 1,
+    Completed = 2,
+    Refunded = 3,
+    Cancelled = 4,
+}
+
+// How an archived project ended; tags 0-999 open
+#[cfg(feature = "archive")]
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum ProjectOutcome {
+    Completed = 0,
+    WoundDown = 1,
+}
+
+// Compact record of an archived project, kept after its history is cleared.
+// `history_root` is the Merkle root over the SHA-256 of the XDR of each
+// investment, then each verification decision, then each update, in that
+// order; `history_len` is the number of leaves.
+#[cfg(feature = "archive")]
+#[contracttype]
+#[derive(Clone)]
+pub struct ProjectArchive {
+    pub owner: Address,
+    pub token: Address,
+    pub goal_amount: i128,
+    pub raised: i128,
+    pub released: i128,
+    pub milestones: u32,
+    pub milestones_verified: u32,
+    pub outcome: ProjectOutcome,
+    pub history_root: BytesN<32>,
+    pub history_len: u32,
+    pub archived_at: u64,
+}
+
+// Escrowed offer to invest `amount` once the project has raised at least
+// `min_raised`, if it does by `expires_at`
+#[cfg(feature = "commitments")]
+#[contracttype]
+#[derive(Clone)]
+pub struct Commitment {
+    pub investor: Address,
+    pub amount: i128,
+    pub min_raised: i128,
+    pub expires_at: u64,
+}
+
+// Backer reward for investors whose total in the project reaches
+// `threshold`, identified by the hash of its off-chain description, with
+// `max_slots` backers at most; `claimed` is kept by the contract
+#[cfg(feature = "rewards")]
+#[contracttype]
+#[derive(Clone)]
+pub struct RewardTier {
+    pub threshold: i128,
+    pub reward: BytesN<32>,
+    pub max_slots: Option<u32>,
+    pub claimed: u32,
+}
+
+// Soulbound record that an investor backed a project: level 0 for any
+// contribution, or one more than the highest reward tier they have held,
+// and when that level was reached. It stays with the investor whatever
+// happens to their position.
+#[cfg(feature = "rewards")]
+#[contracttype]
+#[derive(Clone)]
+pub struct Badge {
+    pub level: u32,
+    pub awarded_at: u64,
+}
+
+// Standing order to invest `amount` every `interval` seconds out of the
+// allowance the investor gave the contract, next pulled at `next_due`
+#[cfg(feature = "commitments")]
+#[contracttype]
+#[derive(Clone)]
+pub struct RecurringCommitment {
+    pub investor: Address,
+    pub amount: i128,
+    pub interval: u64,
+    pub next_due: u64,
+}
+
+// An investment the investor signed off-chain for any relayer to submit,
+// valid until `expiry` and only with the investor's next `nonce`
+#[cfg(feature = "intents")]
+#[contracttype]
+#[derive(Clone)]
+pub struct InvestmentIntent {
+    pub investor: Address,
+    pub project_id: u32,
+    pub amount: i128,
+    pub nonce: u64,
+    pub expiry: u64,
+}
+
+// Global settings of the deployment; per-token, per-category and
+// per-verifier settings have their own getters
+#[contracttype]
+#[derive(Clone)]
+pub struct Config {
+    pub admin: Address,
+    pub verifier: Address,
+    pub token: Address,
+    pub allowed_tokens: Vec<Address>,
+    pub share_token_wasm: Option<BytesN<32>>,
+    pub max_resubmissions: u32,
+    pub verification_window: Option<u64>,
+    pub required_stake: i128,
+    pub treasury: Option<Address>,
+    pub verification_fee: VerificationFee,
+    pub late_policy: LatePolicy,
+    pub archive_ttl: u32,
+    pub refund_grace: u64,
+    pub arbiter: Option<Address>,
+    pub dispute_window: Option<u64>,
+    pub appeal_window: Option<u64>,
+    pub insurance: InsurancePolicy,
+    pub co_verification_threshold: Option<i128>,
+    pub auto_approve_threshold: Option<i128>,
+    pub auto_approve_window: u64,
+    pub verifier_registry: Option<Address>,
+    pub verifier_domain: Option<Symbol>,
+    pub verifier_pool: Vec<Address>,
+    pub evm_attester: Option<BytesN<65>>,
+    pub bls_committee: BlsCommittee,
+    pub hooks: Vec<Address>,
+}
+
+// Running totals across all projects, kept current on every write. Amounts
+// add up units of whichever tokens projects raise in; `value_locked` is
+// what is still escrowed for unverified milestones. Only exported in builds
+// with `stats`, the only ones that keep the totals.
+#[cfg_attr(feature = "stats", contracttype)]
+#[cfg_attr(not(feature = "stats"), contracttype(export = false))]
+#[derive(Clone)]
+pub struct ContractStats {
+    pub value_locked: i128,
+    pub total_raised: i128,
+    pub total_released: i128,
+    pub total_refunded: i128,
+    pub active_projects: u32,
+    pub investors: u32,
+}
+
+// An owner's track record across every project they have created. Counts
+// only grow: a milestone fails when its rejection is final or its deadline
+// passes and it rolls over, and a refund counts once per project. Exported
+// only with `reputation`, like the record itself
+#[cfg_attr(feature = "reputation", contracttype)]
+#[cfg_attr(not(feature = "reputation"), contracttype(export = false))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerReputation {
+    pub projects_created: u32,
+    pub milestones_verified: u32,
+    pub milestones_failed: u32,
+    pub refunds_triggered: u32,
+}
+
+// A quadratic funding round. The sponsor's `pool` is split between the
+// projects that opted in by the weight (Σ√c)² − Σc of the contributions
+// their unique investors make before `ends_at`
+#[cfg(feature = "matching")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchingRound {
+    pub sponsor: Address,
+    pub token: Address,
+    pub pool: i128,
+    pub ends_at: u64,
+    pub projects: Vec<u32>,
+    pub closed: bool,
+}
+
+// A project's contributions within a matching round: their total, the sum
+// of the square root of each investor's total, and the match it received
+// once the round closed
+#[cfg(feature = "matching")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundTally {
+    pub contributed: i128,
+    pub root_sum: i128,
+    pub matched: i128,
+}
+
+// A sponsor's pledge to match investments in one project at `ratio_bps`
+// of each, until `matched` reaches `cap`. The cap is escrowed up front and
+// what is left of it goes back to the sponsor once `closed`
+#[cfg(feature = "matching")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SponsorMatch {
+    pub sponsor: Address,
+    pub ratio_bps: u32,
+    pub cap: i128,
+    pub matched: i128,
+    pub closed: bool,
+}
+
+// Where a project's referral rewards are paid from: the platform fee its
+// releases are charged, or a budget the owner escrows up front
+#[cfg(feature = "referrals")]
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReferralFunding {
+    PlatformFee,
+    Budget(i128),
+}
+
+// A project's referral rewards: `bps` of each referred investment accrues
+// to the referrer while the program is `active`. `funded` is what has been
+// set aside to pay the `accrued` rewards so far, and `paid` what referrers
+// have claimed of it.
+#[cfg(feature = "referrals")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferralProgram {
+    pub bps: u32,
+    pub funding: ReferralFunding,
+    pub accrued: i128,
+    pub funded: i128,
+    pub paid: i128,
+    pub active: bool,
+}
+
+// An approved milestone's release held back for the dispute window; fees
+// are paid on approval, `amount` is what the owner is still due
+#[cfg(feature = "disputes")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRelease {
+    pub amount: i128,
+    pub releasable_at: u64,
+}
+
+// An investor's challenge to a held release, backed by a bond in the
+// project's token that the arbiter's ruling returns or slashes
+#[cfg(feature = "disputes")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dispute {
+    pub challenger: Address,
+    pub bond: i128,
+    pub opened_at: u64,
+}
+
+// Platform insurance: `premium_bps` of every investment goes to the pool
+// for the project's token, and investors in a project that ends refunded
+// are compensated for `coverage_bps` of what it released before failing,
+// at most `max_coverage` per project
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InsurancePolicy {
+    pub premium_bps: u32,
+    pub coverage_bps: u32,
+    pub max_coverage: i128,
+}
+
+// A refunded project's compensation, reserved from the pool when its first
+// claim is made and paid out pro-rata to investors' positions
+#[cfg(feature = "insurance")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InsuranceClaim {
+    pub reserved: i128,
+    pub paid: i128,
+}
+
+// Sign-offs collected on approving a milestone above the co-verification
+// threshold, pinned to the project version they were given at; `verifier`
+// is whoever approved for the verifier, `delegated` when that was a delegate
+#[cfg(feature = "co-verification")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoVerification {
+    pub verifier: Option<Address>,
+    pub delegated: bool,
+    pub admin_approved: bool,
+    pub version: u32,
+}
+
+// A verifier's decision signed off-chain for anyone to submit; `nonce` must
+// be the verifier's next one, so each attestation is used at most once, and
+// `version` the project's current one, so it only decides the evidence it
+// was signed over
+#[cfg(feature = "verifier-signatures")]
+#[contracttype]
+#[derive(Clone)]
+pub struct VerificationAttestation {
+    pub project_id: u32,
+    pub milestone_index: u32,
+    pub approved: bool,
+    pub nonce: u64,
+    pub version: u32,
+}
+
+// An EVM oracle's finding on a milestone, signed with its secp256k1 key;
+// `evidence_hash` stands in for the owner's evidence and `nonce` must be
+// the attester's next one
+#[cfg(feature = "evm-attestation")]
+#[contracttype]
+#[derive(Clone)]
+pub struct EvmAttestation {
+    pub project_id: u32,
+    pub milestone_index: u32,
+    pub evidence_hash: BytesN<32>,
+    pub approved: bool,
+    pub nonce: u64,
+}
+
+// Committee approving milestones with aggregate BLS signatures: members'
+// BLS12-381 public keys in G1, uncompressed, and how many must sign
+#[contracttype]
+#[derive(Clone)]
+pub struct BlsCommittee {
+    pub keys: Vec<BytesN<96>>,
+    pub threshold: u32,
+}
+
+// An auditor's overall finding on what they reviewed; tags 0-999 open
+#[cfg(feature = "audits")]
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u32)]
+pub enum AuditVerdict {
+    Pass = 0,
+    Qualified = 1,
+    Fail = 2,
+}
+
+// An approved auditor's attestation on a project, or on one of its
+// milestones when `milestone_index` is set; `report_hash` anchors the
+// off-chain audit report
+#[cfg(feature = "audits")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Audit {
+    pub auditor: Address,
+    pub milestone_index: Option<u32>,
+    pub report_hash: BytesN<32>,
+    pub verdict: AuditVerdict,
+    pub timestamp: u64,
+}
+
+// An owner's appeal against a final rejection, on the evidence it was
+// filed with; `upheld` is the arbiter's ruling once made
+#[cfg(feature = "disputes")]
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Appeal {
+    pub evidence_hash: BytesN<32>,
+    pub filed_at: u64,
+    pub upheld: Option<bool>,
+}
+
+// One row of a project listing; `name` comes from the project's metadata
+#[cfg(feature = "listings")]
+#[contracttype]
+#[derive(Clone)]
+pub struct ProjectSummary {
+    pub id: u32,
+    pub owner: Address,
+    pub name: Option<String>,
+    pub token: Address,
+    pub goal_amount: i128,
+    pub raised: i128,
+    pub milestones: u32,
+    pub milestones_verified: u32,
+    pub active: bool,
+    pub pending_approval: bool,
+    pub archived: bool,
+    pub status: ProjectStatus,
+    pub refundable: bool,
+}
+
+// Tolerance for evidence submitted after a milestone's deadline: up to
+// `grace` seconds late is accepted for a fee rising to `max_fee_bps` of the
+// milestone amount; no grace keeps late submissions free and unrestricted
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+pub struct LatePolicy {
+    pub grace: u64,
+    pub max_fee_bps: u32,
+}
+
+// Fee paid to whoever verifies a milestone, out of the released amount
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+pub enum VerificationFee {
+    Free,
+    Flat(i128),
+    BasisPoints(u32),
+}
+
+// Platform fee an admin sets for a project category, paid to the treasury
+// out of each milestone release; see the fee module for how each resolves.
+// This and its rates are only exported in builds with `fees`
+#[cfg_attr(feature = "fees", contracttype)]
+#[cfg_attr(not(feature = "fees"), contracttype(export = false))]
+#[derive(Clone, PartialEq)]
+pub enum FeeStrategy {
+    Free,
+    Flat(u32),
+    Tiered(Vec<FeeTier>),
+    Dynamic(DynamicFee),
+}
+
+// Rate for projects whose goal is at least `min_goal`
+#[cfg_attr(feature = "fees", contracttype)]
+#[cfg_attr(not(feature = "fees"), contracttype(export = false))]
+#[derive(Clone, PartialEq)]
+pub struct FeeTier {
+    pub min_goal: i128,
+    pub bps: u32,
+}
+
+// Rate rising by `step_bps` for every project already listed in the
+// category, from `base_bps` up to `max_bps`
+#[cfg_attr(feature = "fees", contracttype)]
+#[cfg_attr(not(feature = "fees"), contracttype(export = false))]
+#[derive(Clone, PartialEq)]
+pub struct DynamicFee {
+    pub base_bps: u32,
+    pub step_bps: u32,
+    pub max_bps: u32,
+}
+
+// Where revenue left unclaimed past a project's revenue expiry goes; tags
+// 0-999 open
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum Escheatment {
+    NextRound = 0,
+    Treasury = 1,
+}
+
+// What happens to evidence the verifier leaves unreviewed for longer than
+// the verification window; tags 0-999 open
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum Escalation {
+    AutoApprove = 0,
+    InvestorVote = 1,
+    Refund = 2,
+}
+
+// Investor vote on an escalated milestone; tallies are weighted by position
+#[cfg(feature = "escalation")]
+#[contracttype]
+#[derive(Clone)]
+pub struct MilestoneVote {
+    pub ends_at: u64,
+    pub approve: i128,
+    pub reject: i128,
+}
+
+// Descriptive project data for listing frontends; `description_uri` is
+// typically an IPFS CID or URL, `image_hash` the hash of the cover image
+// and `tags` free-form labels next to the one category
+#[contracttype]
+#[derive(Clone)]
+pub struct ProjectMetadata {
+    pub name: String,
+    pub description_uri: String,
+    pub category: Symbol,
+    pub image_hash: Option<BytesN<32>>,
+    pub tags: Vec<Symbol>,
+}
+
+// Investment record; `from` is set when the position was transferred in
+#[contracttype]
+#[derive(Clone)]
+pub struct Investment {
+    pub investor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub from: Option<Address>,
+}
+
+// Verified milestone amount vesting linearly from `start`
+#[cfg(feature = "vesting")]
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingTranche {
+    pub amount: i128,
+    pub start: u64,
+}
+
+// Released funds held back for a vesting project; `claimed` is the total
+// already sent out across all tranches
+#[cfg(feature = "vesting")]
+#[contracttype]
+#[derive(Clone)]
+pub struct VestingSchedule {
+    pub tranches: Vec<VestingTranche>,
+    pub claimed: i128,
+}
+
+// Verification rights handed to a sub-verifier by the primary verifier
+#[cfg(feature = "delegation")]
+#[contracttype]
+#[derive(Clone)]
+pub struct Delegation {
+    pub delegate: Address,
+    pub expires_at: u64,
+}
+
+// Entry in a project's append-only verification audit trail; `delegated`
+// marks decisions taken by a delegate, `evidence_hash` is the evidence the
+// decision was taken on and `reason_hash` the hash of the verifier's
+// off-chain comments
+#[cfg(feature = "decision-log")]
+#[contracttype]
+#[derive(Clone)]
+pub struct VerificationDecision {
+    pub milestone_index: u32,
+    pub verifier: Address,
+    pub delegated: bool,
+    pub approved: bool,
+    pub evidence_hash: Option<BytesN<32>>,
+    pub reason_hash: Option<BytesN<32>>,
+    pub timestamp: u64,
+}
+
+// Time-limited access to confidential evidence: `key_envelope` is the
+// evidence decryption key encrypted to the grantee
+#[cfg(feature = "evidence-access")]
+#[contracttype]
+#[derive(Clone)]
+pub struct EvidenceGrant {
+    pub key_envelope: Bytes,
+    pub expires_at: u64,
+}
+
+// Progress update posted by a project's owner; `content_hash` is the hash
+// of the off-chain update body
+#[cfg(feature = "updates")]
+#[contracttype]
+#[derive(Clone)]
+pub struct ProjectUpdate {
+    pub content_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+// Pro-rata payout streams to a project's investors; part of storage keys,
+// tags 0-999 open
+#[contracttype]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(u32)]
+pub enum Payout {
+    Revenue = 0,
+    Refund = 1,
+    Surplus = 2,
+    Yield = 3,
+}
+
+// Revenue distribution round; `per_unit` is the cumulative revenue per
+// invested unit (scaled by PAYOUT_SCALE) after this deposit
+#[cfg(feature = "revenue")]
+#[contracttype]
+#[derive(Clone)]
+pub struct RevenueRound {
+    pub amount: i128,
+    pub per_unit: i128,
+    pub timestamp: u64,
+}
+
+// Admin-configured range a project's goal must fall in for a given token;
+// without `goal-bounds` it is internal to the contract
+#[cfg_attr(feature = "goal-bounds", contracttype)]
+#[cfg_attr(not(feature = "goal-bounds"), contracttype(export = false))]
+#[derive(Clone)]
+pub struct GoalBounds {
+    pub min: Option<i128>,
+    pub max: Option<i128>,
+}
+
+// Admin-configured lending pool for idle escrow in a given token;
+// `investor_bps` of the yield is paid out to investors and the rest goes
+// to the treasury. Without `yield-pool` it stays internal to the contract
+#[cfg_attr(feature = "yield-pool", contracttype)]
+#[cfg_attr(not(feature = "yield-pool"), contracttype(export = false))]
+#[derive(Clone)]
+pub struct YieldPool {
+    pub pool: Address,
+    pub investor_bps: u32,
+}
+
+// USD funding goal of a project and the oracle its token is priced with.
+// USD figures carry the project token's decimals
+#[cfg(feature = "usd-goals")]
+#[contracttype]
+#[derive(Clone)]
+pub struct UsdGoal {
+    pub oracle: Address,
+    pub goal: i128,
+}
+
+// How far a project's funding has come, in its token and, for a USD goal,
+// in USD at the prices investments were made at
+#[cfg(feature = "usd-goals")]
+#[contracttype]
+#[derive(Clone)]
+pub struct FundingProgress {
+    pub raised: i128,
+    pub goal_amount: i128,
+    pub raised_usd: Option<i128>,
+    pub goal_usd: Option<i128>,
+}
+
+// A project's escrow deposited in a lending pool: the pool shares held and
+// the escrow they were bought with
+#[cfg(feature = "yield-pool")]
+#[contracttype]
+#[derive(Clone)]
+pub struct PoolPosition {
+    pub pool: Address,
+    pub shares: i128,
+    pub principal: i128,
+}
+
+// Admin setting tracked in the config history. This and ConfigValue are only
+// contract types in builds that keep the history
+#[cfg_attr(feature = "config-history", contracttype)]
+#[derive(Clone)]
+pub enum ConfigSetting {
+    Admin,
+    ShareTokenWasm,
+    AllowedToken(Address),
+    GoalBounds(Address),
+    CategoryReview(Symbol),
+    MaxResubmissions,
+    VerificationWindow,
+    RequiredStake,
+    Treasury,
+    VerificationFee,
+    LatePolicy,
+    ApprovedVerifier(Address),
+    ArchiveTtl,
+    CategoryFee(Symbol),
+    RefundGrace,
+    YieldPool(Address),
+    Arbiter,
+    DisputeWindow,
+    AppealWindow,
+    InsurancePolicy,
+    ApprovedAuditor(Address),
+    ApprovedOracle(Address),
+    CoVerificationThreshold,
+    AutoApproveThreshold,
+    AutoApproveWindow,
+    VerifierRegistry,
+    VerifierDomain,
+    VerifierPool,
+    EvmAttester,
+    BlsCommittee,
+    Hooks,
+}
+
+// Value a setting held before or after a change
+#[cfg_attr(feature = "config-history", contracttype)]
+#[derive(Clone)]
+pub enum ConfigValue {
+    Unset,
+    Address(Address),
+    Hash(BytesN<32>),
+    Flag(bool),
+    Count(u32),
+    Seconds(u64),
+    Amount(i128),
+    GoalBounds(GoalBounds),
+    Fee(VerificationFee),
+    LatePolicy(LatePolicy),
+    FeeStrategy(FeeStrategy),
+    YieldPool(YieldPool),
+    Insurance(InsurancePolicy),
+    Symbol(Symbol),
+    Addresses(Vec<Address>),
+    PublicKey(BytesN<65>),
+    Committee(BlsCommittee),
+}
+
+// Entry in the append-only config history
+#[cfg(feature = "config-history")]
+#[contracttype]
+#[derive(Clone)]
+pub struct ConfigChange {
+    pub setting: ConfigSetting,
+    pub old: ConfigValue,
+    pub new: ConfigValue,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+// Outcome of a bounded refund pass; `ResumeRequired` carries the position
+// in the investment history the next trigger_refund call continues from
+#[contracttype]
+#[derive(Clone, PartialEq)]
+pub enum RefundProgress {
+    Complete,
+    ResumeRequired(u32),
+}
+
+// Refund being paid out over several calls
+#[contracttype]
+#[derive(Clone)]
+pub struct RefundState {
+    pub unverified_amount: i128,
+    pub cursor: u32,
+}
+
+// Project-scoped actions that can be checked ahead of time with
+// can_perform; milestone actions carry the milestone index
+#[cfg(feature = "preflight")]
+#[contracttype]
+#[derive(Clone)]
+pub enum Action {
+    Invest,
+    TransferPosition,
+    SubmitEvidence(u32),
+    ResubmitEvidence(u32),
+    VerifyMilestone(u32),
+    TriggerRefund(u32),
+    RollOverMilestone(u32),
+    UpdateMilestones,
+    CancelProject,
+    DepositRevenue,
+    ClaimRevenue,
+    ClaimRefund,
+    ClaimSurplus,
+    ClaimYield,
+    ClaimVested,
+    ExpireRevenue(Address),
+    EscalateMilestone(u32),
+    VoteOnMilestone(u32),
+    FinalizeMilestoneVote(u32),
+    CheckOracleMilestone(u32),
+    ArchiveProject,
+    SettleCommitments,
+    ManageProject,
+    ManageWhitelist,
+}
+
+// Storage keys. Keys are stored by variant name, so a name in one key enum
+// never repeats one in another. They are not part of the interface, so the
+// spec leaves them out
+#[contracttype(export = false)]
+pub enum DataKey {
+    ProjectCounter,
+    Project(u32),
+    ProjectInvestments(u32),
+    InvestorAmount(u32, Address),
+    Verifier,
+    Token,
+    Admin,
+    AllowedTokens,
+    PayoutPerUnit(Payout, u32),
+    PayoutDebt(Payout, u32, Address),
+    PayoutOwed(Payout, u32, Address),
+    ProjectMetadata(u32),
+    RefundProgress(u32),
+    RefundPaid(u32, Address),
+}
+
+// Storage keys of lists that grow without bound, kept one item per entry so
+// adding to a list writes only the new item and its length, and reads take
+// a page at a time. Item `n` of a list is under the key with index `n` and
+// its length under the matching `...Count` key
+#[cfg(feature = "decision-log")]
+#[contracttype(export = false)]
+pub enum ListKey {
+    DecisionCount(u32),
+    Decision(u32, u32),
+}
+
+// Storage keys of the optional subsystems, one enum for each feature so a
+// build without it carries none of its keys
+#[cfg(feature = "revenue")]
+#[contracttype(export = false)]
+pub enum RevenueKey {
+    RevenueRounds(u32),
+    RevenueActivity(u32, Address),
+    RevenueCarry(u32),
+}
+
+#[cfg(feature = "whitelist")]
+#[contracttype(export = false)]
+pub enum WhitelistKey {
+    Whitelisted(u32, Address),
+}
+
+#[cfg(feature = "evidence-access")]
+#[contracttype(export = false)]
+pub enum EvidenceAccessKey {
+    EvidenceGrant(u32, u32, Address),
+}
+
+#[cfg(feature = "vesting")]
+#[contracttype(export = false)]
+pub enum VestingKey {
+    Vesting(u32),
+}
+
+#[cfg(feature = "delegation")]
+#[contracttype(export = false)]
+pub enum DelegationKey {
+    Delegation(u32, Option<u32>),
+}
+
+#[cfg(feature = "listing-review")]
+#[contracttype(export = false)]
+pub enum ReviewKey {
+    CategoryReview(Symbol),
+}
+
+#[cfg(feature = "escalation")]
+#[contracttype(export = false)]
+pub enum EscalationKey {
+    VerificationWindow,
+    MilestoneVote(u32, u32),
+    Voted(u32, u32, Address),
+    OpenVotes(u32),
+    AutoApproveThreshold,
+    AutoApproveWindow,
+}
+
+#[cfg(feature = "staking")]
+#[contracttype(export = false)]
+pub enum StakingKey {
+    RequiredStake,
+    VerifierStake(Address),
+}
+
+#[cfg(feature = "verifier-pool")]
+#[contracttype(export = false)]
+pub enum VerifierPoolKey {
+    ApprovedVerifier(Address),
+    VerifierRegistry,
+    VerifierDomain,
+    VerifierPool,
+}
+
+#[cfg(feature = "archive")]
+#[contracttype(export = false)]
+pub enum ArchiveKey {
+    ArchiveTtl,
+    ProjectArchive(u32),
+}
+
+#[cfg(feature = "auto-reinvest")]
+#[contracttype(export = false)]
+pub enum ReinvestKey {
+    AutoReinvest(Address),
+}
+
+#[cfg(feature = "yield-pool")]
+#[contracttype(export = false)]
+pub enum PoolKey {
+    YieldPool(Address),
+    PoolPosition(u32),
+}
+
+#[cfg(feature = "usd-goals")]
+#[contracttype(export = false)]
+pub enum UsdGoalKey {
+    UsdGoal(u32),
+    UsdRaised(u32),
+}
+
+#[cfg(feature = "commitments")]
+#[contracttype(export = false)]
+pub enum CommitmentKey {
+    Commitments(u32),
+    RecurringCommitments(u32),
+}
+
+#[cfg(feature = "rewards")]
+#[contracttype(export = false)]
+pub enum RewardKey {
+    RewardTiers(u32),
+    InvestorTier(u32, Address),
+    Contributed(u32, Address),
+    Badge(u32, Address),
+}
+
+#[cfg(feature = "matching")]
+#[contracttype(export = false)]
+pub enum MatchingKey {
+    MatchingRound(u32),
+    MatchingRoundCount,
+    RoundTally(u32, u32),
+    RoundContribution(u32, u32, Address),
+    ProjectRound(u32),
+    SponsorMatch(u32),
+}
+
+#[cfg(feature = "referrals")]
+#[contracttype(export = false)]
+pub enum ReferralKey {
+    ReferralProgram(u32),
+    ReferralReward(u32, Address),
+}
+
+#[cfg(feature = "disputes")]
+#[contracttype(export = false)]
+pub enum DisputeKey {
+    Arbiter,
+    DisputeWindow,
+    PendingRelease(u32, u32),
+    Dispute(u32, u32),
+    AppealWindow,
+    Appeal(u32, u32),
+}
+
+#[cfg(feature = "insurance")]
+#[contracttype(export = false)]
+pub enum InsuranceKey {
+    InsurancePolicy,
+    InsurancePool(Address),
+    InsuranceClaim(u32),
+    InsurancePaid(u32, Address),
+}
+
+#[cfg(feature = "audits")]
+#[contracttype(export = false)]
+pub enum AuditKey {
+    Auditor(Address),
+    Audits(u32),
+}
+
+#[cfg(feature = "oracle-milestones")]
+#[contracttype(export = false)]
+pub enum OracleKey {
+    Oracle(Address),
+}
+
+#[cfg(feature = "co-verification")]
+#[contracttype(export = false)]
+pub enum CoVerificationKey {
+    CoVerificationThreshold,
+    CoVerification(u32, u32),
+}
+
+#[cfg(feature = "verifier-signatures")]
+#[contracttype(export = false)]
+pub enum SignatureKey {
+    VerifierKey(Address),
+    VerifierNonce(Address),
+}
+
+#[cfg(feature = "evm-attestation")]
+#[contracttype(export = false)]
+pub enum AttestationKey {
+    EvmAttester,
+    EvmAttesterNonce,
+}
+
+#[cfg(feature = "committee")]
+#[contracttype(export = false)]
+pub enum CommitteeKey {
+    BlsCommittee,
+}
+
+#[cfg(feature = "intents")]
+#[contracttype(export = false)]
+pub enum IntentsKey {
+    IntentKey(Address),
+    IntentNonce(Address),
+}
+
+#[cfg(feature = "hooks")]
+#[contracttype(export = false)]
+pub enum HookKey {
+    Hooks,
+}
+
+#[cfg(feature = "config-history")]
+#[contracttype(export = false)]
+pub enum ConfigHistoryKey {
+    ConfigChangeCount,
+    ConfigChange(u32),
+}
+
+#[cfg(feature = "updates")]
+#[contracttype(export = false)]
+pub enum UpdateKey {
+    UpdateCount(u32),
+    Update(u32, u32),
+}
+
+#[cfg(feature = "goal-bounds")]
+#[contracttype(export = false)]
+pub enum GoalBoundsKey {
+    GoalBounds(Address),
+}
+
+#[cfg(feature = "share-token")]
+#[contracttype(export = false)]
+pub enum ShareTokenKey {
+    ShareTokenWasm,
+}
+
+#[cfg(feature = "stats")]
+#[contracttype(export = false)]
+pub enum StatsKey {
+    Stats,
+}
+
+#[cfg(feature = "reputation")]
+#[contracttype(export = false)]
+pub enum ReputationKey {
+    OwnerReputation(Address),
+}
+
+#[cfg(feature = "listings")]
+#[contracttype(export = false)]
+pub enum ListingKey {
+    CategoryCount(Symbol),
+    CategoryProject(Symbol, u32),
+    // Where a project sits in its category's list
+    CategorySlot(u32),
+    OwnerProjectCount(Address),
+    OwnerProject(Address, u32),
+    InvestorProjectCount(Address),
+    InvestorProject(Address, u32),
+    // Whether a project is already in the investor's list
+    InvestorProjectListed(Address, u32),
+}
+
+#[cfg(feature = "fees")]
+#[contracttype(export = false)]
+pub enum FeeKey {
+    VerificationFee,
+    CategoryFee(Symbol),
+    CategoryListings(Symbol),
+}
+
+#[cfg(feature = "treasury")]
+#[contracttype(export = false)]
+pub enum TreasuryKey {
+    Treasury,
+}
+
+#[cfg(feature = "late-policy")]
+#[contracttype(export = false)]
+pub enum LatePolicyKey {
+    LatePolicy,
+}
+
+#[cfg(feature = "resubmissions")]
+#[contracttype(export = false)]
+pub enum ResubmissionKey {
+    MaxResubmissions,
+}
+
+#[cfg(feature = "refund-grace")]
+#[contracttype(export = false)]
+pub enum RefundGraceKey {
+    RefundGrace,
+}
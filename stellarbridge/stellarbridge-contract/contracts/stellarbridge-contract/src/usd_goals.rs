@@ -0,0 +1,88 @@
+// Goals denominated in USD. A project can price its goal through an oracle;
+// each investment then adds its USD value at the price it was made at, and
+// the project is funded once the USD raise reaches the goal.
+
+use soroban_sdk::{log, Address, Env};
+
+use crate::errors::{self, ContractError};
+use crate::storage;
+use crate::types::{Asset, FundingProgress, PriceOracleClient, Project, UsdGoal, UsdGoalKey};
+use crate::{math, MAX_PRICE_AGE};
+
+// Value of `amount` of `token` in USD at the oracle's latest price, in the
+// token's decimals
+pub fn usd_value(env: &Env, oracle: &Address, token: &Address, amount: i128) -> Result<i128, ContractError> {
+    let oracle = PriceOracleClient::new(env, oracle);
+    let price = oracle
+        .lastprice(&Asset::Stellar(token.clone()))
+        .expect("No oracle price for token");
+    
+    if price.price <= 0 {
+        panic!("Invalid oracle price");
+    }
+    
+    if env.ledger().timestamp() > price.timestamp.saturating_add(MAX_PRICE_AGE) {
+        panic!("Oracle price is stale");
+    }
+    
+    let scale = 10i128.checked_pow(oracle.decimals()).ok_or(ContractError::Overflow)?;
+    Ok(math::mul_div(amount, price.price, scale)?)
+}
+
+// Add an investment's USD value to a project's USD raise; returns whether
+// the project has a USD goal and has reached it
+pub fn record_usd(env: &Env, project: &Project, amount: i128) -> Result<bool, ContractError> {
+    let usd_goal: UsdGoal = match storage::get(env, &UsdGoalKey::UsdGoal(project.id)) {
+        Some(usd_goal) => usd_goal,
+        None => return Ok(false),
+    };
+    
+    let raised_key = UsdGoalKey::UsdRaised(project.id);
+    let raised_usd: i128 = storage::get(env, &raised_key).unwrap_or(0);
+    let raised_usd = math::add(raised_usd, usd_value(env, &usd_goal.oracle, &project.token, amount)?)?;
+    storage::set(env, &raised_key, &raised_usd);
+    
+    Ok(raised_usd >= usd_goal.goal)
+}
+
+pub fn set_usd_goal(env: Env, project_id: u32, usd_goal: Option<UsdGoal>) {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    let key = UsdGoalKey::UsdGoal(project_id);
+    match usd_goal {
+        Some(usd_goal) => {
+            if usd_goal.goal <= 0 {
+                panic!("USD goal must be positive");
+            }
+            storage::set(&env, &key, &usd_goal);
+        }
+        None => storage::remove(&env, &key),
+    }
+    
+    log!(&env, "USD goal for project {} updated", project_id);
+}
+
+pub fn get_usd_goal(env: Env, project_id: u32) -> Option<UsdGoal> {
+    storage::get(&env, &UsdGoalKey::UsdGoal(project_id))
+}
+
+pub fn get_funding_progress(env: Env, project_id: u32) -> FundingProgress {
+    let project = storage::project(&env, project_id);
+    let usd_goal: Option<UsdGoal> = storage::get(&env, &UsdGoalKey::UsdGoal(project_id));
+    let raised_usd = usd_goal.as_ref().map(|_| {
+        storage::get(&env, &UsdGoalKey::UsdRaised(project_id)).unwrap_or(0)
+    });
+    
+    FundingProgress {
+        raised: project.raised,
+        goal_amount: project.goal_amount,
+        raised_usd,
+        goal_usd: usd_goal.map(|usd_goal| usd_goal.goal),
+    }
+}
@@ -0,0 +1,77 @@
+// Verifiers beyond the global one. The admin can approve verifiers, or defer
+// to an external registry, and either let owners pick one of them for their
+// project or have new projects drawn from a pool.
+
+use soroban_sdk::{log, Address, Env, Symbol, Vec};
+
+use crate::errors;
+use crate::storage;
+use crate::types::{VerifierPoolKey, VerifierRegistryClient};
+
+// An address can verify individual projects if the admin approved it here
+// or the configured registry lists it under the configured domain
+pub fn is_approved_verifier(env: &Env, verifier: &Address) -> bool {
+    if storage::has(env, &VerifierPoolKey::ApprovedVerifier(verifier.clone())) {
+        return true;
+    }
+    
+    let registry: Option<Address> = env.storage().instance().get(&VerifierPoolKey::VerifierRegistry);
+    match registry {
+        Some(registry) => {
+            let domain: Symbol = env.storage()
+                .instance()
+                .get(&VerifierPoolKey::VerifierDomain)
+                .unwrap();
+            VerifierRegistryClient::new(env, &registry).is_registered(verifier, &domain)
+        }
+        None => false,
+    }
+}
+
+// Draw a verifier for a new project from the admin's pool, leaving out the
+// owner so no one verifies their own project; None without a pool keeps the
+// global verifier
+pub fn assign_verifier(env: &Env, owner: &Address) -> Option<Address> {
+    let pool: Vec<Address> = env.storage()
+        .instance()
+        .get(&VerifierPoolKey::VerifierPool)
+        .unwrap_or(Vec::new(env));
+    let mut candidates = Vec::new(env);
+    for verifier in pool.iter() {
+        if verifier != *owner {
+            candidates.push_back(verifier);
+        }
+    }
+    
+    if candidates.is_empty() {
+        return None;
+    }
+    
+    let index: u64 = env.prng().gen_range(0..candidates.len() as u64);
+    candidates.get(index as u32)
+}
+
+pub fn set_project_verifier(env: Env, project_id: u32, verifier: Option<Address>) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if env.storage().instance().has(&VerifierPoolKey::VerifierPool) {
+        panic!("Verifier is assigned from the pool");
+    }
+    
+    if let Some(verifier) = &verifier {
+        if !is_approved_verifier(&env, verifier) {
+            panic!("Verifier not approved");
+        }
+    }
+    
+    project.verifier = verifier;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Verifier for project {} updated", project_id);
+}
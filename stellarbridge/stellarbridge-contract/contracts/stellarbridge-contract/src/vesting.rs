@@ -0,0 +1,100 @@
+// Vesting of milestone releases. A project can have each verified release
+// vest linearly over a number of days instead of being paid out at once;
+// the owner claims whatever has vested so far.
+
+use soroban_sdk::{log, Env, Vec};
+
+use crate::errors::{self, ContractError};
+use crate::escrow::release_funds;
+use crate::storage;
+use crate::types::{Project, VestingKey, VestingSchedule, VestingTranche};
+use crate::{math, SECONDS_PER_DAY};
+
+// Start vesting a milestone release when the project is configured to;
+// returns whether it did
+pub fn vest(env: &Env, project: &Project, release: i128) -> Result<bool, ContractError> {
+    if project.vesting_days.is_none() {
+        return Ok(false);
+    }
+    
+    let vesting_key = VestingKey::Vesting(project.id);
+    let mut schedule: VestingSchedule = storage::get(env, &vesting_key)
+        .unwrap_or(VestingSchedule { tranches: Vec::new(env), claimed: 0 });
+    schedule.tranches.push_back(VestingTranche {
+        amount: release,
+        start: env.ledger().timestamp(),
+    });
+    storage::set(env, &vesting_key, &schedule);
+    
+    Ok(true)
+}
+
+// Amount of a vesting schedule that has vested but not been claimed yet
+pub fn claimable_vested(env: &Env, project: &Project) -> Result<i128, ContractError> {
+    let schedule: VestingSchedule = match storage::get(env, &VestingKey::Vesting(project.id)) {
+        Some(schedule) => schedule,
+        None => return Ok(0),
+    };
+    let period = project.vesting_days.unwrap_or(0) as u64 * SECONDS_PER_DAY;
+    let now = env.ledger().timestamp();
+    
+    let mut vested: i128 = 0;
+    for tranche in schedule.tranches.iter() {
+        let elapsed = now.saturating_sub(tranche.start);
+        let amount = if period == 0 || elapsed >= period {
+            tranche.amount
+        } else {
+            math::mul_div(tranche.amount, elapsed as i128, period as i128)?
+        };
+        vested = math::add(vested, amount)?;
+    }
+    
+    Ok(math::sub(vested, schedule.claimed)?)
+}
+
+pub fn claim_vested(env: Env, project_id: u32) -> Result<i128, ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    let amount = claimable_vested(&env, &project)?;
+    if amount == 0 {
+        panic!("Nothing vested to claim");
+    }
+    
+    let vesting_key = VestingKey::Vesting(project_id);
+    let mut schedule: VestingSchedule = storage::get(&env, &vesting_key).unwrap();
+    schedule.claimed = math::add(schedule.claimed, amount)?;
+    storage::set(&env, &vesting_key, &schedule);
+    
+    release_funds(&env, &project, amount)?;
+    
+    log!(&env, "Vested {} claimed for project {}", amount, project_id);
+    
+    Ok(amount)
+}
+
+pub fn get_claimable_vested(env: Env, project_id: u32) -> Result<i128, ContractError> {
+    let project = storage::project(&env, project_id);
+    
+    claimable_vested(&env, &project)
+}
+
+pub fn set_vesting(env: Env, project_id: u32, days: Option<u32>) {
+    let mut project = storage::project(&env, project_id);
+    
+    project.owner.require_auth();
+    
+    if project.raised != 0 {
+        panic!("{}", errors::HAS_INVESTMENTS);
+    }
+    
+    if days == Some(0) {
+        panic!("Vesting period must be positive");
+    }
+    
+    project.vesting_days = days;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Vesting for project {} set to {} days", project_id, days.unwrap_or(0));
+}
@@ -0,0 +1,60 @@
+// Whitelisted projects. An owner can restrict who may invest in or receive
+// positions of a project, and hand the list to a compliance account that
+// the owner can then no longer overrule.
+
+use soroban_sdk::{log, Address, Env};
+
+use crate::storage;
+use crate::types::{DataKey, Project, WhitelistKey};
+
+// Whether the project's whitelist, if it keeps one, admits `investor`
+pub fn allows(env: &Env, project: &Project, investor: &Address) -> bool {
+    !project.whitelist_only || is_whitelisted(env.clone(), project.id, investor.clone())
+}
+
+pub fn set_whitelist_mode(env: Env, caller: Address, project_id: u32, enabled: bool, compliance: Option<Address>) {
+    caller.require_auth();
+    
+    let mut project = storage::project(&env, project_id);
+    
+    // Once a compliance account is set the owner can no longer lift or
+    // replace it; only that account or the admin can
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    let allowed = match &project.compliance {
+        Some(current) => caller == *current || admin.as_ref() == Some(&caller),
+        None => caller == project.owner,
+    };
+    if !allowed {
+        panic!("Only the owner, or the compliance account once set, can change whitelist mode");
+    }
+    
+    project.whitelist_only = enabled;
+    project.compliance = compliance;
+    storage::set_project(&env, &project);
+    
+    log!(&env, "Whitelist mode for project {} set to {}", project_id, enabled);
+}
+
+pub fn add_to_whitelist(env: Env, project_id: u32, investor: Address) {
+    let project = storage::project(&env, project_id);
+    
+    project.compliance.unwrap_or(project.owner).require_auth();
+    
+    storage::set(&env, &WhitelistKey::Whitelisted(project_id, investor.clone()), &true);
+    
+    log!(&env, "Investor {} whitelisted for project {}", investor, project_id);
+}
+
+pub fn remove_from_whitelist(env: Env, project_id: u32, investor: Address) {
+    let project = storage::project(&env, project_id);
+    
+    project.compliance.unwrap_or(project.owner).require_auth();
+    
+    storage::remove(&env, &WhitelistKey::Whitelisted(project_id, investor.clone()));
+    
+    log!(&env, "Investor {} removed from whitelist of project {}", investor, project_id);
+}
+
+pub fn is_whitelisted(env: Env, project_id: u32, investor: Address) -> bool {
+    storage::get(&env, &WhitelistKey::Whitelisted(project_id, investor)).unwrap_or(false)
+}
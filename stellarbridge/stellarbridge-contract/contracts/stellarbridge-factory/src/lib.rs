@@ -0,0 +1,259 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractimpl, contracttype, log, Address, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+// Deploys a dedicated StellarBridge escrow contract per project instead of
+// keeping every project in one instance, so a fault or an upgrade stays
+// contained to one project's funds. Each escrow is initialized with the
+// factory's verifier, who therefore authorizes every deployment, and is
+// recorded in an on-chain registry. Like the watchdog, the factory calls
+// the escrow by name rather than linking the bridge crate, which would
+// export its entrypoints from this contract too.
+//
+// Settings and the escrow count live in the instance. The registry grows
+// with every deployment, so each escrow, and each escrow's place in its
+// owner's list, gets a persistent entry of its own whose TTL is extended
+// whenever it is read or written.
+
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Ledgers an entry is kept alive for once it has fewer than `TTL_THRESHOLD`
+// left, about 30 and 7 days at 5 second ledgers
+const TTL_EXTEND: u32 = 518_400;
+const TTL_THRESHOLD: u32 = 120_960;
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Verifier,
+    EscrowWasm,
+    EscrowCount,
+    Escrow(u32),
+    OwnerEscrowCount(Address),
+    OwnerEscrow(Address, u32),
+}
+
+// A deployed escrow and the wasm it was deployed from
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowEntry {
+    pub id: u32,
+    pub address: Address,
+    pub owner: Address,
+    pub token: Address,
+    pub wasm_hash: BytesN<32>,
+    pub created_at: u64,
+}
+
+fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap()
+}
+
+fn read<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage().persistent().extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND);
+    }
+    value
+}
+
+fn write<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage().persistent().extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND);
+}
+
+// Salt for escrow `id`, making its address predictable before deployment
+fn salt(env: &Env, id: u32) -> BytesN<32> {
+    let mut salt = [0u8; 32];
+    salt[28..].copy_from_slice(&id.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+#[contract]
+pub struct StellarBridgeFactory;
+
+#[contractimpl]
+impl StellarBridgeFactory {
+    /// Set the factory admin, the verifier every escrow is initialized with
+    /// and the escrow wasm to deploy
+    pub fn __constructor(env: Env, admin: Address, verifier: Address, escrow_wasm: BytesN<32>) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        env.storage().instance().set(&DataKey::EscrowWasm, &escrow_wasm);
+        env.storage().instance().set(&DataKey::EscrowCount, &0u32);
+    }
+    
+    /// Hand over admin rights (admin only)
+    pub fn set_admin(env: Env, new_admin: Address) {
+        admin(&env).require_auth();
+        
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        
+        log!(&env, "Factory admin changed to {}", new_admin);
+    }
+    
+    /// Set the verifier new escrows are initialized with (admin only);
+    /// escrows already deployed keep theirs
+    pub fn set_verifier(env: Env, verifier: Address) {
+        admin(&env).require_auth();
+        
+        env.storage().instance().set(&DataKey::Verifier, &verifier);
+        
+        log!(&env, "Factory verifier changed to {}", verifier);
+    }
+    
+    /// Set the escrow wasm deployed for new projects (admin only); escrows
+    /// already deployed keep running the wasm they were deployed from
+    pub fn set_escrow_wasm(env: Env, wasm_hash: BytesN<32>) {
+        admin(&env).require_auth();
+        
+        env.storage().instance().set(&DataKey::EscrowWasm, &wasm_hash);
+        
+        log!(&env, "Escrow wasm updated");
+    }
+    
+    /// Deploy an escrow contract for a new project, initialize it with the
+    /// factory's verifier and `token`, create the project on it and record
+    /// it in the registry. Needs the owner's and the verifier's
+    /// authorization; returns the escrow's registry ID
+    pub fn deploy_project(
+        env: Env,
+        owner: Address,
+        token: Address,
+        goal_amount: i128,
+        milestone_amounts: Vec<i128>,
+        milestone_deadlines: Vec<u64>,
+    ) -> u32 {
+        let verifier: Address = env.storage()
+            .instance()
+            .get(&DataKey::Verifier)
+            .unwrap();
+        
+        owner.require_auth();
+        verifier.require_auth();
+        
+        let wasm_hash: BytesN<32> = env.storage()
+            .instance()
+            .get(&DataKey::EscrowWasm)
+            .unwrap();
+        let id: u32 = env.storage().instance().get(&DataKey::EscrowCount).unwrap_or(0) + 1;
+        
+        let address = env
+            .deployer()
+            .with_current_contract(salt(&env, id))
+            .deploy_v2(wasm_hash.clone(), ());
+        
+        let _: Val = env.invoke_contract(
+            &address,
+            &Symbol::new(&env, "initialize"),
+            (verifier, token.clone()).into_val(&env),
+        );
+        let _: u32 = env.invoke_contract(
+            &address,
+            &Symbol::new(&env, "create_project"),
+            (
+                owner.clone(),
+                token.clone(),
+                goal_amount,
+                milestone_amounts,
+                milestone_deadlines,
+                (),
+            )
+                .into_val(&env),
+        );
+        
+        let entry = EscrowEntry {
+            id,
+            address: address.clone(),
+            owner: owner.clone(),
+            token,
+            wasm_hash,
+            created_at: env.ledger().timestamp(),
+        };
+        write(&env, &DataKey::Escrow(id), &entry);
+        env.storage().instance().set(&DataKey::EscrowCount, &id);
+        env.storage().instance().extend_ttl(TTL_THRESHOLD, TTL_EXTEND);
+        
+        let count_key = DataKey::OwnerEscrowCount(owner.clone());
+        let owned: u32 = read(&env, &count_key).unwrap_or(0);
+        write(&env, &DataKey::OwnerEscrow(owner.clone(), owned), &id);
+        write(&env, &count_key, &(owned + 1));
+        
+        env.events().publish((Symbol::new(&env, "escrow_deployed"), id), (owner, address));
+        
+        id
+    }
+    
+    /// Get the factory admin
+    pub fn get_admin(env: Env) -> Address {
+        admin(&env)
+    }
+    
+    /// Get the verifier new escrows are initialized with
+    pub fn get_verifier(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Verifier)
+            .unwrap()
+    }
+    
+    /// Get the escrow wasm deployed for new projects
+    pub fn get_escrow_wasm(env: Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowWasm)
+            .unwrap()
+    }
+    
+    /// Get the number of escrows deployed so far
+    pub fn get_escrow_count(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::EscrowCount).unwrap_or(0)
+    }
+    
+    /// Get a deployed escrow's registry entry
+    pub fn get_escrow(env: Env, id: u32) -> EscrowEntry {
+        read(&env, &DataKey::Escrow(id)).expect("Escrow not found")
+    }
+    
+    /// Get up to `limit` registry entries in deployment order, skipping the
+    /// first `start`
+    pub fn list_escrows(env: Env, start: u32, limit: u32) -> Vec<EscrowEntry> {
+        let count = Self::get_escrow_count(env.clone());
+        let mut entries: Vec<EscrowEntry> = Vec::new(&env);
+        let first = start.saturating_add(1);
+        let last = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        
+        for id in first..=last {
+            entries.push_back(Self::get_escrow(env.clone(), id));
+        }
+        
+        entries
+    }
+    
+    /// Get up to `limit` registry IDs of the escrows deployed for an owner,
+    /// oldest first, skipping the first `start`
+    pub fn get_escrows_by_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        let count: u32 = read(&env, &DataKey::OwnerEscrowCount(owner.clone())).unwrap_or(0);
+        let mut ids: Vec<u32> = Vec::new(&env);
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+        
+        for index in start..end {
+            ids.push_back(read(&env, &DataKey::OwnerEscrow(owner.clone(), index)).unwrap());
+        }
+        
+        ids
+    }
+    
+    /// Get the address escrow `id` is (or will be) deployed at
+    pub fn escrow_address(env: Env, id: u32) -> Address {
+        env.deployer()
+            .with_current_contract(salt(&env, id))
+            .deployed_address()
+    }
+}
+
+mod test;
@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+// Deployments use the bridge's release wasm; build it first with
+//   cargo build --target wasm32v1-none --release -p stellarbridge-contract
+
+use crate::{DataKey, StellarBridgeFactory, StellarBridgeFactoryClient, TTL_EXTEND};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env};
+
+mod escrow {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/stellarbridge_contract.wasm"
+    );
+}
+
+// A factory whose escrow wasm was never uploaded, so no deployment can go
+// through; the escrow contract itself is exercised in its own crate
+struct Setup<'a> {
+    env: Env,
+    factory: StellarBridgeFactoryClient<'a>,
+    admin: Address,
+    verifier: Address,
+}
+
+impl Setup<'_> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let verifier = Address::generate(&env);
+        let wasm_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let factory_id = env.register(
+            StellarBridgeFactory,
+            (admin.clone(), verifier.clone(), wasm_hash),
+        );
+        let factory = StellarBridgeFactoryClient::new(&env, &factory_id);
+
+        Setup {
+            env,
+            factory,
+            admin,
+            verifier,
+        }
+    }
+
+    // A factory deploying the bridge's uploaded wasm
+    fn deploying() -> Self {
+        let setup = Self::new();
+        let wasm_hash = setup.env.deployer().upload_contract_wasm(escrow::WASM);
+        setup.factory.set_escrow_wasm(&wasm_hash);
+        setup
+    }
+
+    fn deploy(&self, owner: &Address) -> u32 {
+        let env = &self.env;
+        let token = env.register_stellar_asset_contract_v2(self.admin.clone());
+        self.factory.deploy_project(
+            owner,
+            &token.address(),
+            &1_000,
+            &vec![env, 400, 600],
+            &vec![env, 1_000u64, 2_000u64],
+        )
+    }
+}
+
+#[test]
+fn stores_its_configuration() {
+    let setup = Setup::new();
+    let factory = &setup.factory;
+
+    assert_eq!(factory.get_admin(), setup.admin);
+    assert_eq!(factory.get_verifier(), setup.verifier);
+    assert_eq!(
+        factory.get_escrow_wasm(),
+        BytesN::from_array(&setup.env, &[1u8; 32])
+    );
+    assert_eq!(factory.get_escrow_count(), 0);
+    assert_eq!(factory.list_escrows(&0, &10), vec![&setup.env]);
+}
+
+#[test]
+fn settings_require_the_admin() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let factory = &setup.factory;
+    let verifier = Address::generate(env);
+
+    factory.set_verifier(&verifier);
+    assert_eq!(env.auths()[0].0, setup.admin);
+    assert_eq!(factory.get_verifier(), verifier);
+
+    let wasm_hash = BytesN::from_array(env, &[2u8; 32]);
+    factory.set_escrow_wasm(&wasm_hash);
+    assert_eq!(env.auths()[0].0, setup.admin);
+    assert_eq!(factory.get_escrow_wasm(), wasm_hash);
+
+    let admin = Address::generate(env);
+    factory.set_admin(&admin);
+    assert_eq!(env.auths()[0].0, setup.admin);
+    assert_eq!(factory.get_admin(), admin);
+}
+
+#[test]
+fn escrow_addresses_are_predictable_and_distinct() {
+    let setup = Setup::new();
+    let factory = &setup.factory;
+
+    let first = factory.escrow_address(&1);
+    assert_eq!(factory.escrow_address(&1), first);
+    assert_ne!(factory.escrow_address(&2), first);
+    assert_ne!(first, factory.address);
+}
+
+#[test]
+fn failed_deployment_leaves_the_registry_untouched() {
+    let setup = Setup::new();
+    let env = &setup.env;
+    let owner = Address::generate(env);
+
+    let result = setup.factory.try_deploy_project(
+        &owner,
+        &Address::generate(env),
+        &1_000,
+        &vec![env, 1_000],
+        &vec![env, 100u64],
+    );
+
+    assert!(result.is_err());
+    assert_eq!(setup.factory.get_escrow_count(), 0);
+    assert_eq!(setup.factory.get_escrows_by_owner(&owner, &0, &10), vec![env]);
+}
+
+#[test]
+#[should_panic(expected = "Escrow not found")]
+fn unknown_escrow_is_rejected() {
+    let setup = Setup::new();
+    setup.factory.get_escrow(&1);
+}
+
+#[test]
+fn deploys_and_registers_an_escrow() {
+    let setup = Setup::deploying();
+    let env = &setup.env;
+    let factory = &setup.factory;
+    let owner = Address::generate(env);
+
+    let id = setup.deploy(&owner);
+    assert_eq!(id, 1);
+    assert_eq!(factory.get_escrow_count(), 1);
+
+    let entry = factory.get_escrow(&id);
+    assert_eq!(entry.address, factory.escrow_address(&id));
+    assert_eq!(entry.owner, owner);
+    assert_eq!(entry.wasm_hash, factory.get_escrow_wasm());
+
+    let escrow = escrow::Client::new(env, &entry.address);
+    assert_eq!(escrow.get_config().verifier, setup.verifier);
+    let project = escrow.get_project(&1);
+    assert_eq!(project.owner, owner);
+    assert_eq!(project.token, entry.token);
+    assert_eq!(project.goal_amount, 1_000);
+}
+
+#[test]
+fn registry_entries_are_persistent_and_kept_alive() {
+    let setup = Setup::deploying();
+    let env = &setup.env;
+    let owner = Address::generate(env);
+    let id = setup.deploy(&owner);
+
+    env.as_contract(&setup.factory.address, || {
+        let storage = env.storage().persistent();
+        assert_eq!(storage.get_ttl(&DataKey::Escrow(id)), TTL_EXTEND);
+        assert_eq!(storage.get_ttl(&DataKey::OwnerEscrow(owner.clone(), 0)), TTL_EXTEND);
+    });
+}
+
+#[test]
+fn owner_escrows_page_by_index() {
+    let setup = Setup::deploying();
+    let env = &setup.env;
+    let factory = &setup.factory;
+    let owner = Address::generate(env);
+    let first = setup.deploy(&owner);
+    setup.deploy(&Address::generate(env));
+    let second = setup.deploy(&owner);
+    let third = setup.deploy(&owner);
+
+    assert_eq!(factory.get_escrows_by_owner(&owner, &0, &2), vec![env, first, second]);
+    assert_eq!(factory.get_escrows_by_owner(&owner, &2, &2), vec![env, third]);
+    assert_eq!(factory.list_escrows(&0, &10).len(), 4);
+}
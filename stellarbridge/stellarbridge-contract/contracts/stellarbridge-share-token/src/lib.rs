@@ -0,0 +1,149 @@
+#![no_std]
+use soroban_sdk::token::TokenInterface;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+
+// Per-project share token the bridge deploys and issues. It speaks SEP-41
+// so wallets can show the shares, but shares only prove a position and
+// move with it: holders cannot transfer, approve or burn them, and only
+// the issuing bridge mints, claws back and so moves them between accounts.
+// The bridge pays revenue, refunds and votes by position, so a share that
+// changed hands outside it would be worth nothing to its new holder.
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    Balance(Address),
+}
+
+// Balances live about 30 days past their last use before needing a bump
+const BALANCE_TTL_THRESHOLD: u32 = 518_400;
+const BALANCE_TTL_EXTEND: u32 = 1_036_800;
+
+fn admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap()
+}
+
+fn balance_of(env: &Env, holder: &Address) -> i128 {
+    let key = DataKey::Balance(holder.clone());
+    let balance: Option<i128> = env.storage().persistent().get(&key);
+    if balance.is_some() {
+        env.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND);
+    }
+    balance.unwrap_or(0)
+}
+
+fn set_balance(env: &Env, holder: &Address, balance: i128) {
+    let key = DataKey::Balance(holder.clone());
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    
+    env.storage().persistent().set(&key, &balance);
+    env.storage().persistent().extend_ttl(&key, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND);
+}
+
+fn check_amount(amount: i128) {
+    if amount <= 0 {
+        panic!("Amount must be positive");
+    }
+}
+
+#[contract]
+pub struct StellarBridgeShareToken;
+
+#[contractimpl]
+impl StellarBridgeShareToken {
+    /// Create the token for the bridge `admin` that issues it
+    pub fn __constructor(env: Env, admin: Address, decimals: u32, name: String, symbol: String) {
+        let instance = env.storage().instance();
+        instance.set(&DataKey::Admin, &admin);
+        instance.set(&DataKey::Decimals, &decimals);
+        instance.set(&DataKey::Name, &name);
+        instance.set(&DataKey::Symbol, &symbol);
+    }
+    
+    /// Issue shares for a position (issuer only)
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let admin = admin(&env);
+        admin.require_auth();
+        check_amount(amount);
+        
+        let balance = balance_of(&env, &to).checked_add(amount).expect("Balance overflow");
+        set_balance(&env, &to, balance);
+        env.storage().instance().extend_ttl(BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND);
+        
+        env.events().publish((symbol_short!("mint"), admin, to), amount);
+    }
+    
+    /// Retire shares of a position that moved or was paid out (issuer only)
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        let admin = admin(&env);
+        admin.require_auth();
+        check_amount(amount);
+        
+        let balance = balance_of(&env, &from);
+        if balance < amount {
+            panic!("Insufficient balance");
+        }
+        set_balance(&env, &from, balance - amount);
+        
+        env.events().publish((symbol_short!("clawback"), admin, from), amount);
+    }
+    
+    /// Get the bridge that issues the token
+    pub fn admin(env: Env) -> Address {
+        admin(&env)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for StellarBridgeShareToken {
+    fn allowance(_env: Env, _from: Address, _spender: Address) -> i128 {
+        0
+    }
+    
+    fn approve(_env: Env, _from: Address, _spender: Address, _amount: i128, _expiration_ledger: u32) {
+        panic!("Shares are not transferable");
+    }
+    
+    fn balance(env: Env, id: Address) -> i128 {
+        balance_of(&env, &id)
+    }
+    
+    fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("Shares are not transferable");
+    }
+    
+    fn transfer_from(_env: Env, _spender: Address, _from: Address, _to: Address, _amount: i128) {
+        panic!("Shares are not transferable");
+    }
+    
+    fn burn(_env: Env, _from: Address, _amount: i128) {
+        panic!("Shares are not transferable");
+    }
+    
+    fn burn_from(_env: Env, _spender: Address, _from: Address, _amount: i128) {
+        panic!("Shares are not transferable");
+    }
+    
+    fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Decimals).unwrap()
+    }
+    
+    fn name(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Name).unwrap()
+    }
+    
+    fn symbol(env: Env) -> String {
+        env.storage().instance().get(&DataKey::Symbol).unwrap()
+    }
+}
+
+mod test;
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use crate::{StellarBridgeShareToken, StellarBridgeShareTokenClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, String};
+
+fn deploy(env: &Env, admin: &Address) -> StellarBridgeShareTokenClient<'static> {
+    let contract_id = env.register(
+        StellarBridgeShareToken,
+        (
+            admin.clone(),
+            7u32,
+            String::from_str(env, "StellarBridge Project #1"),
+            String::from_str(env, "SBP1"),
+        ),
+    );
+    StellarBridgeShareTokenClient::new(env, &contract_id)
+}
+
+#[test]
+fn issuer_mints_and_claws_back() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let shares = deploy(&env, &admin);
+
+    shares.mint(&holder, &1_000);
+    shares.clawback(&holder, &400);
+
+    assert_eq!(shares.balance(&holder), 600);
+    assert_eq!(shares.decimals(), 7);
+    assert_eq!(shares.admin(), admin);
+}
+
+#[test]
+fn holder_cannot_move_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let holder = Address::generate(&env);
+    let other = Address::generate(&env);
+    let shares = deploy(&env, &Address::generate(&env));
+    shares.mint(&holder, &1_000);
+
+    assert!(shares.try_transfer(&holder, &other, &1).is_err());
+    assert!(shares.try_approve(&holder, &other, &1, &100).is_err());
+    assert!(shares.try_transfer_from(&other, &holder, &other, &1).is_err());
+    assert!(shares.try_burn(&holder, &1).is_err());
+    assert_eq!(shares.balance(&holder), 1_000);
+    assert_eq!(shares.allowance(&holder, &other), 0);
+}
+
+#[test]
+fn only_the_issuer_mints() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let holder = Address::generate(&env);
+    let shares = deploy(&env, &admin);
+
+    assert!(shares.try_mint(&holder, &1_000).is_err());
+}
@@ -0,0 +1,263 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+// Read-only mirror of a StellarBridge contract for integrators such as
+// exchanges: it is bound to one bridge at deployment and exposes only its
+// view surface, forwarding each call, so nothing reached through it can
+// change state. Structured results are passed through as the bridge
+// encodes them and decode with the bridge's own contract spec. The bridge
+// crate is not linked in, since that would export its mutating entrypoints
+// from this contract too. Views of the bridge's optional subsystems are only
+// mirrored in builds with the matching feature, for bridges built with it.
+
+#[contracttype]
+pub enum DataKey {
+    Bridge,
+}
+
+// Forward a view call to the bound bridge
+fn view<T>(env: &Env, function: &str, args: Vec<Val>) -> T
+where
+    T: TryFromVal<Env, Val>,
+{
+    let bridge: Address = env.storage()
+        .instance()
+        .get(&DataKey::Bridge)
+        .unwrap();
+    env.invoke_contract(&bridge, &Symbol::new(env, function), args)
+}
+
+#[contract]
+pub struct StellarBridgeWatchdog;
+
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Bind the mirror to a bridge contract; fixed for the mirror's lifetime
+    pub fn __constructor(env: Env, bridge: Address) {
+        env.storage().instance().set(&DataKey::Bridge, &bridge);
+    }
+    
+    /// Get the bridge contract this mirror reads from
+    pub fn bridge(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Bridge)
+            .unwrap()
+    }
+    
+    /// Get total number of projects
+    pub fn get_project_count(env: Env) -> u32 {
+        view(&env, "get_project_count", Vec::new(&env))
+    }
+    
+    /// Get project details, as a `Project`
+    pub fn get_project(env: Env, project_id: u32) -> Val {
+        view(&env, "get_project", (project_id,).into_val(&env))
+    }
+    
+    /// Get project metadata, if any was set, as an `Option<ProjectMetadata>`
+    pub fn get_project_metadata(env: Env, project_id: u32) -> Val {
+        view(&env, "get_project_metadata", (project_id,).into_val(&env))
+    }
+    
+    /// Get a single milestone of a project, as a `Milestone`
+    pub fn get_milestone(env: Env, project_id: u32, milestone_index: u32) -> Val {
+        view(&env, "get_milestone", (project_id, milestone_index).into_val(&env))
+    }
+    
+    /// Get the number of milestones a project has
+    pub fn get_milestone_count(env: Env, project_id: u32) -> u32 {
+        view(&env, "get_milestone_count", (project_id,).into_val(&env))
+    }
+    
+    /// Get investor amount for a project
+    pub fn get_investor_amount(env: Env, project_id: u32, investor: Address) -> i128 {
+        view(&env, "get_investor_amount", (project_id, investor).into_val(&env))
+    }
+    
+    /// Get the refund an investor can currently claim
+    pub fn get_claimable_refund(env: Env, project_id: u32, investor: Address) -> i128 {
+        view(&env, "get_claimable_refund", (project_id, investor).into_val(&env))
+    }
+    
+    /// Get the share of a finished project's surplus an investor can
+    /// currently claim
+    pub fn get_claimable_surplus(env: Env, project_id: u32, investor: Address) -> i128 {
+        view(&env, "get_claimable_surplus", (project_id, investor).into_val(&env))
+    }
+    
+    /// Check whether trigger_refund has already paid an investor their
+    /// refund from a project
+    pub fn is_refund_paid(env: Env, project_id: u32, investor: Address) -> bool {
+        view(&env, "is_refund_paid", (project_id, investor).into_val(&env))
+    }
+    
+    /// Check whether new projects may raise in a token
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        view(&env, "is_token_allowed", (token,).into_val(&env))
+    }
+    
+    /// Check whether an address is the global verifier or approved to verify
+    /// individual projects
+    pub fn is_verifier(env: Env, verifier: Address) -> bool {
+        view(&env, "is_verifier", (verifier,).into_val(&env))
+    }
+    
+    /// Get the deployment's global settings, as a `Config`
+    pub fn get_config(env: Env) -> Val {
+        view(&env, "get_config", Vec::new(&env))
+    }
+}
+
+// Archival of finished projects
+#[cfg(feature = "archive")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get an archived project's summary while it is still retained, as an
+    /// `Option<ProjectArchive>`
+    pub fn get_project_archive(env: Env, project_id: u32) -> Val {
+        view(&env, "get_project_archive", (project_id,).into_val(&env))
+    }
+}
+
+// Log of verification decisions
+#[cfg(feature = "decision-log")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get up to `limit` of a project's verification decisions, oldest first,
+    /// starting at `start`, as a `Vec<VerificationDecision>`
+    pub fn get_verification_decisions(env: Env, project_id: u32, start: u32, limit: u32) -> Val {
+        view(&env, "get_verification_decisions", (project_id, start, limit).into_val(&env))
+    }
+}
+
+// Project summaries, and indexes of projects by owner and investor
+#[cfg(feature = "listings")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get a project's summary without its full milestone list, as a
+    /// `ProjectSummary`
+    pub fn get_project_summary(env: Env, project_id: u32) -> Val {
+        view(&env, "get_project_summary", (project_id,).into_val(&env))
+    }
+    
+    /// Get summaries of up to `limit` projects in creation order, skipping
+    /// the first `start`, as a `Vec<ProjectSummary>`
+    pub fn list_projects(env: Env, start: u32, limit: u32) -> Val {
+        view(&env, "list_projects", (start, limit).into_val(&env))
+    }
+    
+    /// Get the projects open for investment within the same window as
+    /// `list_projects`, as a `Vec<ProjectSummary>`
+    pub fn list_active_projects(env: Env, start: u32, limit: u32) -> Val {
+        view(&env, "list_active_projects", (start, limit).into_val(&env))
+    }
+    
+    /// Get up to `limit` IDs of the projects an owner has created, oldest
+    /// first, starting at `start`
+    pub fn get_projects_by_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        view(&env, "get_projects_by_owner", (owner, start, limit).into_val(&env))
+    }
+    
+    /// Get the projects an investor holds a position in, with amounts, paged
+    /// like the bridge's view
+    pub fn get_investor_portfolio(env: Env, investor: Address, start: u32, limit: u32) -> Vec<(u32, i128)> {
+        view(&env, "get_investor_portfolio", (investor, start, limit).into_val(&env))
+    }
+}
+
+// Track records of project owners
+#[cfg(feature = "reputation")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get an owner's track record, as an `OwnerReputation`
+    pub fn get_owner_reputation(env: Env, owner: Address) -> Val {
+        view(&env, "get_owner_reputation", (owner,).into_val(&env))
+    }
+}
+
+// Revenue sharing with investors
+#[cfg(feature = "revenue")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get all revenue rounds deposited for a project, as a
+    /// `Vec<RevenueRound>`
+    pub fn get_revenue_rounds(env: Env, project_id: u32) -> Val {
+        view(&env, "get_revenue_rounds", (project_id,).into_val(&env))
+    }
+    
+    /// Get the revenue an investor can currently claim
+    pub fn get_claimable_revenue(env: Env, project_id: u32, investor: Address) -> i128 {
+        view(&env, "get_claimable_revenue", (project_id, investor).into_val(&env))
+    }
+}
+
+// Backer reward tiers and badges
+#[cfg(feature = "rewards")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Check whether an investor holds a project's badge at `level` or above
+    pub fn has_badge(env: Env, investor: Address, project_id: u32, level: u32) -> bool {
+        view(&env, "has_badge", (investor, project_id, level).into_val(&env))
+    }
+}
+
+// Running totals across all projects
+#[cfg(feature = "stats")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get running totals across all projects, as a `ContractStats`
+    pub fn get_stats(env: Env) -> Val {
+        view(&env, "get_stats", Vec::new(&env))
+    }
+}
+
+// Goals priced in USD
+#[cfg(feature = "usd-goals")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get a project's USD goal, as an `Option<UsdGoal>`
+    pub fn get_usd_goal(env: Env, project_id: u32) -> Val {
+        view(&env, "get_usd_goal", (project_id,).into_val(&env))
+    }
+    
+    /// Get how much a project has raised against its goal, in its token and,
+    /// for a USD goal, in USD, as a `FundingProgress`
+    pub fn get_funding_progress(env: Env, project_id: u32) -> Val {
+        view(&env, "get_funding_progress", (project_id,).into_val(&env))
+    }
+}
+
+// Investor whitelists
+#[cfg(feature = "whitelist")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Check whether an investor is on a project's whitelist
+    pub fn is_whitelisted(env: Env, project_id: u32, investor: Address) -> bool {
+        view(&env, "is_whitelisted", (project_id, investor).into_val(&env))
+    }
+}
+
+// Lending pool yield on idle escrow
+#[cfg(feature = "yield-pool")]
+#[contractimpl]
+impl StellarBridgeWatchdog {
+    /// Get the lending pool idle escrow in a token is deposited in, as an
+    /// `Option<YieldPool>`
+    pub fn get_yield_pool(env: Env, token: Address) -> Val {
+        view(&env, "get_yield_pool", (token,).into_val(&env))
+    }
+    
+    /// Get the yield an investor can currently claim
+    pub fn get_claimable_yield(env: Env, project_id: u32, investor: Address) -> i128 {
+        view(&env, "get_claimable_yield", (project_id, investor).into_val(&env))
+    }
+    
+    /// Get a project's current lending pool position, as an
+    /// `Option<PoolPosition>`
+    pub fn get_pool_position(env: Env, project_id: u32) -> Val {
+        view(&env, "get_pool_position", (project_id,).into_val(&env))
+    }
+}
+
+mod test;
@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::{StellarBridgeWatchdog, StellarBridgeWatchdogClient};
+#[cfg(feature = "stats")]
+use stellarbridge_contract::ContractStats;
+#[cfg(feature = "usd-goals")]
+use stellarbridge_contract::FundingProgress;
+#[cfg(feature = "reputation")]
+use stellarbridge_contract::OwnerReputation;
+#[cfg(feature = "listings")]
+use stellarbridge_contract::ProjectSummary;
+use stellarbridge_contract::{
+    Config, Project, ProjectMetadata, StellarBridgeContract, StellarBridgeContractClient,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token, vec, Address, BytesN, Env, String, Symbol, TryFromVal, Vec};
+
+// A bridge with one funded project whose first milestone is verified, and a
+// mirror bound to it
+struct Setup<'a> {
+    env: Env,
+    bridge: StellarBridgeContractClient<'a>,
+    watchdog: StellarBridgeWatchdogClient<'a>,
+    owner: Address,
+    investor: Address,
+    project_id: u32,
+}
+
+impl Setup<'_> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let verifier = Address::generate(&env);
+        let sac = env.register_stellar_asset_contract_v2(Address::generate(&env));
+        let bridge_id = env.register(StellarBridgeContract, ());
+        let bridge = StellarBridgeContractClient::new(&env, &bridge_id);
+        bridge.initialize(&verifier, &sac.address());
+
+        let owner = Address::generate(&env);
+        let investor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &sac.address()).mint(&investor, &1_000);
+        let project_id = bridge.create_project(
+            &owner,
+            &sac.address(),
+            &1_000,
+            &vec![&env, 400, 600],
+            &vec![&env, 100u64, 200u64],
+            &Some(ProjectMetadata {
+                name: String::from_str(&env, "Solar Co-op"),
+                description_uri: String::from_str(&env, "ipfs://solar"),
+                category: Symbol::new(&env, "energy"),
+                image_hash: None,
+                tags: Vec::new(&env),
+            }),
+        );
+        bridge.invest(&project_id, &investor, &1_000, &None);
+        bridge.submit_evidence(&project_id, &0, &BytesN::from_array(&env, &[7u8; 32]));
+        bridge.verify_milestone(&project_id, &0, &true, &None, &1);
+
+        let watchdog_id = env.register(StellarBridgeWatchdog, (bridge_id,));
+        let watchdog = StellarBridgeWatchdogClient::new(&env, &watchdog_id);
+
+        Setup {
+            env,
+            bridge,
+            watchdog,
+            owner,
+            investor,
+            project_id,
+        }
+    }
+}
+
+#[test]
+fn is_bound_to_the_bridge() {
+    let setup = Setup::new();
+    assert_eq!(setup.watchdog.bridge(), setup.bridge.address);
+}
+
+#[test]
+fn mirrors_typed_views() {
+    let setup = Setup::new();
+    let (watchdog, bridge) = (&setup.watchdog, &setup.bridge);
+    let project_id = setup.project_id;
+
+    assert_eq!(watchdog.get_project_count(), 1);
+    assert_eq!(watchdog.get_milestone_count(&project_id), 2);
+    #[cfg(feature = "listings")]
+    assert_eq!(
+        watchdog.get_projects_by_owner(&setup.owner, &0, &10),
+        vec![&setup.env, project_id]
+    );
+    assert_eq!(
+        watchdog.get_investor_amount(&project_id, &setup.investor),
+        bridge.get_investor_amount(&project_id, &setup.investor)
+    );
+    #[cfg(feature = "listings")]
+    assert_eq!(
+        watchdog.get_investor_portfolio(&setup.investor, &0, &10),
+        bridge.get_investor_portfolio(&setup.investor, &0, &10)
+    );
+    assert_eq!(
+        watchdog.get_claimable_refund(&project_id, &setup.investor),
+        0
+    );
+    #[cfg(feature = "yield-pool")]
+    assert_eq!(
+        watchdog.get_claimable_yield(&project_id, &setup.investor),
+        0
+    );
+    #[cfg(feature = "whitelist")]
+    assert!(!watchdog.is_whitelisted(&project_id, &setup.investor));
+    assert!(!watchdog.is_verifier(&setup.owner));
+    assert!(!watchdog.is_token_allowed(&setup.owner));
+    #[cfg(feature = "rewards")]
+    assert!(watchdog.has_badge(&setup.investor, &project_id, &0));
+    #[cfg(feature = "rewards")]
+    assert!(!watchdog.has_badge(&setup.owner, &project_id, &0));
+}
+
+#[test]
+fn passes_structured_views_through_in_the_bridge_encoding() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let project =
+        Project::try_from_val(env, &setup.watchdog.get_project(&setup.project_id)).unwrap();
+    assert_eq!(project.raised, 1_000);
+    assert_eq!(project.owner, setup.owner);
+
+    #[cfg(feature = "listings")]
+    {
+        let summary = setup.watchdog.get_project_summary(&setup.project_id);
+        let summary = ProjectSummary::try_from_val(env, &summary).unwrap();
+        assert_eq!(summary.milestones, 2);
+        assert_eq!(summary.milestones_verified, 1);
+    }
+
+    #[cfg(feature = "usd-goals")]
+    {
+        let progress = setup.watchdog.get_funding_progress(&setup.project_id);
+        let progress = FundingProgress::try_from_val(env, &progress).unwrap();
+        assert_eq!(progress.raised, 1_000);
+        assert_eq!(progress.raised_usd, None);
+    }
+
+    #[cfg(feature = "stats")]
+    {
+        let stats = ContractStats::try_from_val(env, &setup.watchdog.get_stats()).unwrap();
+        assert_eq!(
+            stats.total_released,
+            setup.bridge.get_stats().total_released
+        );
+        assert_eq!(stats.value_locked, 600);
+    }
+
+    #[cfg(feature = "reputation")]
+    {
+        let reputation = setup.watchdog.get_owner_reputation(&setup.owner);
+        let reputation = OwnerReputation::try_from_val(env, &reputation).unwrap();
+        assert_eq!(reputation, setup.bridge.get_owner_reputation(&setup.owner));
+        assert_eq!(reputation.milestones_verified, 1);
+    }
+
+    let config = Config::try_from_val(env, &setup.watchdog.get_config()).unwrap();
+    assert_eq!(config.admin, setup.bridge.get_config().admin);
+}
+
+#[test]
+#[should_panic]
+fn forwards_bridge_failures() {
+    let setup = Setup::new();
+    setup.watchdog.get_project(&99);
+}
@@ -0,0 +1,9 @@
+#![no_std]
+
+// Contract-independent rules shared by the StellarBridge contracts: checked
+// amount arithmetic, payment splits and milestone schedule validation. Kept
+// free of contract storage so every contract applies the same checks.
+
+pub mod math;
+pub mod schedule;
+pub mod splitter;
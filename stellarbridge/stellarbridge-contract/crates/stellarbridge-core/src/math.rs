@@ -0,0 +1,59 @@
+// Checked arithmetic for token amounts: every sum, difference and scaled
+// share on a money path goes through these so that an overflow fails the
+// call with a typed error instead of trapping in the host.
+
+// Why an amount calculation failed
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MathError {
+    Overflow,
+    DivisionByZero,
+}
+
+// `a + b`
+pub fn add(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_add(b).ok_or(MathError::Overflow)
+}
+
+// `a - b`
+pub fn sub(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_sub(b).ok_or(MathError::Overflow)
+}
+
+// `a * b`
+pub fn mul(a: i128, b: i128) -> Result<i128, MathError> {
+    a.checked_mul(b).ok_or(MathError::Overflow)
+}
+
+// `amount * numerator / denominator`, rounded towards zero. The amount is
+// divided first so that shares of amounts near the i128 limit do not
+// overflow on the way.
+pub fn mul_div(amount: i128, numerator: i128, denominator: i128) -> Result<i128, MathError> {
+    if denominator == 0 {
+        return Err(MathError::DivisionByZero);
+    }
+
+    let whole = mul(amount / denominator, numerator)?;
+    let part = mul(amount % denominator, numerator)? / denominator;
+    add(whole, part)
+}
+
+// Largest integer whose square is at most `amount`; negative amounts have
+// no square root
+pub fn sqrt(amount: i128) -> i128 {
+    if amount < 0 {
+        panic!("Square root of negative amount");
+    }
+
+    if amount < 2 {
+        return amount;
+    }
+
+    // Newton's method from above, which settles on the floor of the root
+    let mut root = amount;
+    let mut next = amount / 2;
+    while next < root {
+        root = next;
+        next = (root + amount / root) / 2;
+    }
+    root
+}
@@ -0,0 +1,46 @@
+// Milestone schedule rules: one amount per deadline, amounts positive and
+// adding up to the goal, deadlines in the future and strictly increasing.
+
+use soroban_sdk::Vec;
+
+use crate::math::{self, MathError};
+
+// Panic unless `amounts` and `deadlines` describe a valid schedule of at
+// most `max_milestones` milestones for `goal_amount`, as of `now`. Amounts
+// adding up past the i128 limit are an overflow error instead
+pub fn validate(
+    goal_amount: i128,
+    amounts: &Vec<i128>,
+    deadlines: &Vec<u64>,
+    now: u64,
+    max_milestones: u32,
+) -> Result<(), MathError> {
+    if amounts.len() != deadlines.len() {
+        panic!("Milestone amounts and deadlines must match");
+    }
+
+    if amounts.len() > max_milestones {
+        panic!("Too many milestones");
+    }
+
+    let mut total: i128 = 0;
+    let mut previous_deadline = now;
+    for (amount, deadline) in amounts.iter().zip(deadlines.iter()) {
+        if amount <= 0 {
+            panic!("Milestone amount must be positive");
+        }
+
+        if deadline <= previous_deadline {
+            panic!("Milestone deadlines must be in the future and increasing");
+        }
+        previous_deadline = deadline;
+
+        total = math::add(total, amount)?;
+    }
+
+    if total != goal_amount {
+        panic!("Milestone amounts must add up to the goal");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Payment splitter shared by every payout that goes to several wallets:
+// a split is a list of recipients with basis-point shares summing to
+// BASIS_POINTS, and the rounding remainder goes to the last recipient.
+
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::math::{self, MathError};
+
+// Shares are expressed in basis points of each payment
+pub const BASIS_POINTS: u32 = 10_000;
+
+// Most recipients a split may have, which bounds every payout loop
+pub const MAX_RECIPIENTS: u32 = 10;
+
+// Panic unless `split` is a usable split; an empty split is not
+pub fn validate(split: &Vec<(Address, u32)>) {
+    if split.is_empty() {
+        panic!("Split has no recipients");
+    }
+
+    if split.len() > MAX_RECIPIENTS {
+        panic!("Too many recipients");
+    }
+
+    let mut total: u32 = 0;
+    for (_, share) in split.iter() {
+        if share == 0 {
+            panic!("Recipient share must be positive");
+        }
+        total = match total.checked_add(share) {
+            Some(total) => total,
+            None => panic!("Recipient shares must sum to 10000"),
+        };
+    }
+
+    if total != BASIS_POINTS {
+        panic!("Recipient shares must sum to 10000");
+    }
+}
+
+// Amount each recipient receives out of `amount`, in split order; the
+// amounts always add up to `amount` exactly
+pub fn allocate(
+    env: &Env,
+    split: &Vec<(Address, u32)>,
+    amount: i128,
+) -> Result<Vec<(Address, i128)>, MathError> {
+    let mut allocations: Vec<(Address, i128)> = Vec::new(env);
+    let mut remaining = amount;
+
+    for i in 0..split.len() {
+        let (recipient, share) = split.get(i).unwrap();
+        let payout = if i == split.len() - 1 {
+            remaining
+        } else {
+            math::mul_div(amount, share as i128, BASIS_POINTS as i128)?
+        };
+        remaining -= payout;
+        allocations.push_back((recipient, payout));
+    }
+
+    Ok(allocations)
+}
+
+// Pay `amount` of `token` out of the contract according to `split`
+pub fn distribute(
+    env: &Env,
+    token: &Address,
+    split: &Vec<(Address, u32)>,
+    amount: i128,
+) -> Result<(), MathError> {
+    let token_client = token::Client::new(env, token);
+
+    for (recipient, payout) in allocate(env, split, amount)?.iter() {
+        if payout > 0 {
+            token_client.transfer(&env.current_contract_address(), &recipient, &payout);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+# Fails if a release contract wasm is over its size budget. Build first with
+#   cargo build --target wasm32v1-none --release --workspace
+#
+# The network refuses contract uploads over 65536 bytes, so every contract is
+# held to that; the default build of stellarbridge-contract only fits with the
+# optional subsystems left out.
+set -euo pipefail
+
+NETWORK_LIMIT=65536
+WASM_DIR="${WASM_DIR:-target/wasm32v1-none/release}"
+
+declare -A BUDGETS=(
+  [stellarbridge_contract]=$NETWORK_LIMIT
+  [stellarbridge_factory]=$NETWORK_LIMIT
+  [stellarbridge_share_token]=$NETWORK_LIMIT
+  [stellarbridge_watchdog]=$NETWORK_LIMIT
+)
+
+status=0
+for contract in "${!BUDGETS[@]}"; do
+  wasm="$WASM_DIR/$contract.wasm"
+  [[ -f "$wasm" ]] || { echo "$wasm not found"; exit 1; }
+
+  size=$(wc -c < "$wasm")
+  budget=${BUDGETS[$contract]}
+  if (( size > budget )); then
+    echo "FAIL $contract: $size bytes, budget $budget"
+    status=1
+  else
+    echo "ok   $contract: $size bytes, budget $budget"
+  fi
+done
+
+exit $status